@@ -0,0 +1,68 @@
+//! Benchmarks of the translation lookup path.
+//!
+//! Run with `cargo bench --bench lookup`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use i18nrs::{I18n, I18nConfig, StorageType};
+use std::collections::HashMap;
+use std::hint::black_box;
+
+/// Builds a catalog of `count` keys spread over nested sections, e.g. `"section7.key42"`.
+fn catalog(count: usize, label: &str) -> String {
+    let mut sections = serde_json::Map::new();
+    for index in 0..count {
+        let section = sections
+            .entry(format!("section{}", index % 100))
+            .or_insert_with(|| serde_json::Value::Object(Default::default()));
+        section[format!("key{}", index)] = format!("{} {}", label, index).into();
+    }
+    serde_json::Value::Object(sections).to_string()
+}
+
+/// Builds an instance with English and French catalogs of `count` keys each.
+fn i18n(count: usize) -> I18n {
+    let en = catalog(count, "Label");
+    let fr = catalog(count, "Libellé");
+    let translations = HashMap::from([("en", en.as_str()), ("fr", fr.as_str())]);
+    let config = I18nConfig {
+        translations: HashMap::new(),
+    };
+    I18n::new(config, translations).unwrap()
+}
+
+fn lookup(c: &mut Criterion) {
+    let small = i18n(100);
+    let large = i18n(10_000);
+
+    c.bench_function("t nested key", |b| {
+        b.iter(|| small.t(black_box("section42.key42")))
+    });
+    c.bench_function("t nested key, 10k keys", |b| {
+        b.iter(|| large.t(black_box("section42.key4242")))
+    });
+    c.bench_function("t missing key", |b| {
+        b.iter(|| small.t(black_box("section42.missing")))
+    });
+    c.bench_function("t_with", |b| {
+        b.iter(|| small.t_with(black_box("section42.key42"), &[("name", &"Ada")]))
+    });
+}
+
+fn switching(c: &mut Criterion) {
+    let large = i18n(10_000);
+
+    c.bench_function("set_translation_language, 10k keys", |b| {
+        b.iter(|| {
+            let mut i18n = large.clone();
+            i18n.set_translation_language(black_box("fr"), &StorageType::LocalStorage, "i18nrs")
+                .unwrap();
+            i18n
+        })
+    });
+    c.bench_function("load catalog, 10k keys", |b| {
+        b.iter(|| i18n(black_box(10_000)))
+    });
+}
+
+criterion_group!(benches, lookup, switching);
+criterion_main!(benches);
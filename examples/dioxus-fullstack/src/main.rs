@@ -2,7 +2,6 @@ use dioxus::prelude::*;
 use dioxus_logger::tracing;
 use i18nrs::dioxus::I18nContext;
 use i18nrs::dioxus::I18nProvider;
-use std::collections::HashMap;
 
 #[derive(Debug, Clone, Routable, PartialEq)]
 #[rustfmt::skip]
@@ -35,12 +34,7 @@ fn App() -> Element {
 
 #[component]
 fn Home() -> Element {
-    let translations = HashMap::from([
-        ("en", include_str!("../i18n/en/base.json")),
-        ("es", include_str!("../i18n/es/base.json")),
-        ("fr", include_str!("../i18n/fr/base.json")),
-        ("ar", include_str!("../i18n/ar/base.json")),
-    ]);
+    let translations = i18nrs::include_i18n!("i18n");
 
     rsx! {
         div {
@@ -2,7 +2,6 @@ use dioxus::prelude::*;
 use dioxus_logger::tracing;
 use i18nrs::dioxus::I18nContext;
 use i18nrs::dioxus::I18nProvider;
-use std::collections::HashMap;
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
 const HEADER_SVG: Asset = asset!("/assets/header.svg");
@@ -16,12 +15,7 @@ fn main() {
 
 #[component]
 fn app() -> Element {
-    let translations = HashMap::from([
-        ("en", include_str!("../i18n/en/base.json")),
-        ("es", include_str!("../i18n/es/base.json")),
-        ("fr", include_str!("../i18n/fr/base.json")),
-        ("ar", include_str!("../i18n/ar/base.json")),
-    ]);
+    let translations = i18nrs::include_i18n!("i18n");
 
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
@@ -1,17 +1,11 @@
 use crate::components::landing::Examples;
 use i18nrs::yew::I18nProvider;
 use i18nrs::StorageType;
-use std::collections::HashMap;
 use yew::prelude::*;
 
 #[function_component(LandingPage)]
 pub fn landing_page() -> Html {
-    let translations = HashMap::from([
-        ("en", include_str!("../../i18n/en/base.json")),
-        ("es", include_str!("../../i18n/es/base.json")),
-        ("fr", include_str!("../../i18n/fr/base.json")),
-        ("ar", include_str!("../../i18n/ar/base.json")),
-    ]);
+    let translations = i18nrs::include_i18n!("i18n");
 
     let onchange = Callback::from(|language: String| {
         log::info!("Language changed to: {}", language);
@@ -0,0 +1,561 @@
+//! Build script helper for [i18nrs](https://docs.rs/i18nrs).
+//!
+//! Reads a locale directory at build time, validates every translation file, and emits a
+//! Rust module with the translations pre-parsed into a static structure. Loading that
+//! structure with `I18n::from_static` skips runtime JSON parsing entirely.
+//!
+//! # Directory Layout
+//! Both layouts are supported and can be mixed:
+//! - `<dir>/<lang>.json`: one file per language.
+//! - `<dir>/<lang>/*.json`: one directory per language. `base.json` is merged at the root,
+//!   every other file is nested under its file stem (e.g. `checkout.json` → `checkout.*`).
+//!
+//! # Example
+//! ```rust,no_run
+//! // build.rs
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("i18n.rs");
+//! i18nrs_build::compile_dir("i18n", out).unwrap();
+//! ```
+//!
+//! ```rust,ignore
+//! // main.rs
+//! include!(concat!(env!("OUT_DIR"), "/i18n.rs"));
+//!
+//! let i18n = i18nrs::I18n::from_static(TRANSLATIONS).unwrap();
+//! ```
+//!
+//! # Typed Keys
+//! `compile_keys` generates a `TranslationKey` enum from a base language, resolved with
+//! `I18n::tk`:
+//! ```rust,ignore
+//! // build.rs
+//! i18nrs_build::compile_keys("i18n", "en", out_dir.join("keys.rs")).unwrap();
+//!
+//! // main.rs
+//! include!(concat!(env!("OUT_DIR"), "/keys.rs"));
+//!
+//! let title = i18n.tk(TranslationKey::MenuFileOpen);
+//! ```
+
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The name of the file merged at the root of a language directory.
+pub const BASE_FILE: &str = "base.json";
+
+/// Reads, validates, and precompiles a locale directory into a Rust module.
+///
+/// The generated module defines `TRANSLATIONS`, a
+/// `&[(&str, &[(&str, &str)])]` of language codes to flattened `(key, value)` pairs,
+/// and prints `cargo:rerun-if-changed` for the directory.
+///
+/// # Arguments
+/// - `dir`: The locale directory (e.g., `"i18n"`), relative to the crate root.
+/// - `out_file`: The file to write the generated module to, usually inside `OUT_DIR`.
+///
+/// # Returns
+/// - `Ok(())` if the module was written.
+/// - `Err(String)` if a file cannot be read, is invalid JSON, or is not a JSON object, or
+///   if a key collides with another one (see `generate`).
+pub fn compile_dir(dir: impl AsRef<Path>, out_file: impl AsRef<Path>) -> Result<(), String> {
+    let dir = dir.as_ref();
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let translations = load_dir(dir)?;
+    fs::write(out_file.as_ref(), generate(&translations)?)
+        .map_err(|err| format!("Failed to write '{}': {}", out_file.as_ref().display(), err))
+}
+
+/// Reads and validates every language of a locale directory.
+///
+/// # Arguments
+/// - `dir`: The locale directory to read.
+///
+/// # Returns
+/// - `Ok(BTreeMap<String, Value>)` mapping language codes to their merged JSON object.
+/// - `Err(String)` describing the first invalid file.
+pub fn load_dir(dir: impl AsRef<Path>) -> Result<BTreeMap<String, Value>, String> {
+    let dir = dir.as_ref();
+    let entries = fs::read_dir(dir).map_err(|err| {
+        format!(
+            "Failed to read locale directory '{}': {}",
+            dir.display(),
+            err
+        )
+    })?;
+
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| {
+            format!(
+                "Failed to read locale directory '{}': {}",
+                dir.display(),
+                err
+            )
+        })?;
+    // Sorted so `<lang>.json` is merged over `<lang>/` the same way on every platform.
+    paths.sort();
+
+    let mut translations = BTreeMap::new();
+    for path in paths {
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        let bundle = if path.is_dir() {
+            load_language_dir(&path)?
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            read_json(&path)?
+        } else {
+            continue;
+        };
+
+        let language: &mut Value = translations
+            .entry(stem.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        merge(language, bundle);
+    }
+
+    if translations.is_empty() {
+        return Err(format!("No translations found in '{}'", dir.display()));
+    }
+
+    Ok(translations)
+}
+
+/// Lists every translation file of a locale directory, in both supported layouts.
+///
+/// Useful for change tracking, e.g. to emit `cargo:rerun-if-changed` per file.
+///
+/// # Arguments
+/// - `dir`: The locale directory to scan.
+pub fn locale_files(dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, String> {
+    let dir = dir.as_ref();
+    let mut files = Vec::new();
+    let entries = fs::read_dir(dir).map_err(|err| {
+        format!(
+            "Failed to read locale directory '{}': {}",
+            dir.display(),
+            err
+        )
+    })?;
+
+    for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+        if path.is_dir() {
+            let nested = fs::read_dir(&path)
+                .map_err(|err| format!("Failed to read '{}': {}", path.display(), err))?;
+            files.extend(
+                nested
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "json")),
+            );
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Generates a typed `TranslationKey` enum from the keys of a base language.
+///
+/// Writes a module defining `TranslationKey`, with one variant per key of `language`
+/// (e.g., `"menu.file.open"` → `TranslationKey::MenuFileOpen`), to be resolved with
+/// `I18n::tk`. The enum is `pub(crate)`, so keys that are never used are reported by the
+/// compiler's `dead_code` lint.
+///
+/// # Arguments
+/// - `dir`: The locale directory (e.g., `"i18n"`), relative to the crate root.
+/// - `language`: The base language whose keys define the enum (e.g., `"en"`).
+/// - `out_file`: The file to write the generated module to, usually inside `OUT_DIR`.
+///
+/// # Returns
+/// - `Ok(())` if the module was written.
+/// - `Err(String)` if the directory is invalid, the language is missing, or two keys map
+///   to the same variant name.
+pub fn compile_keys(
+    dir: impl AsRef<Path>,
+    language: &str,
+    out_file: impl AsRef<Path>,
+) -> Result<(), String> {
+    let dir = dir.as_ref();
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let translations = load_dir(dir)?;
+    let json = translations
+        .get(language)
+        .ok_or_else(|| format!("Language '{}' not found in '{}'", language, dir.display()))?;
+    fs::write(out_file.as_ref(), generate_keys(json)?)
+        .map_err(|err| format!("Failed to write '{}': {}", out_file.as_ref().display(), err))
+}
+
+/// Generates the Rust source of the typed `TranslationKey` enum.
+///
+/// # Arguments
+/// - `json`: The JSON object of the base language.
+///
+/// # Returns
+/// - `Ok(String)` containing the enum and its `i18nrs::I18nKey` implementation.
+/// - `Err(String)` if two keys map to the same variant name.
+pub fn generate_keys(json: &Value) -> Result<String, String> {
+    let keys = flatten(json);
+    let mut variants: BTreeMap<String, &str> = BTreeMap::new();
+    for key in keys.keys() {
+        let variant = variant_name(key);
+        if let Some(existing) = variants.insert(variant.clone(), key) {
+            return Err(format!(
+                "Keys '{}' and '{}' both map to variant '{}'",
+                existing, key, variant
+            ));
+        }
+    }
+
+    let mut out = String::from(
+        "// @generated by i18nrs-build. Do not edit.\n\n\
+         /// Translation keys of the base language.\n\
+         #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]\n\
+         pub(crate) enum TranslationKey {\n",
+    );
+    for (variant, key) in &variants {
+        let _ = writeln!(out, "    /// `{}`: {:?}", key, keys[*key]);
+        let _ = writeln!(out, "    {},", variant);
+    }
+    out.push_str(
+        "}\n\n\
+         impl TranslationKey {\n\
+         \x20   /// Returns the dot-separated key of this variant.\n\
+         \x20   pub const fn as_str(&self) -> &'static str {\n\
+         \x20       match self {\n",
+    );
+    for (variant, key) in &variants {
+        let _ = writeln!(out, "            Self::{} => {:?},", variant, key);
+    }
+    out.push_str(
+        "        }\n\
+         \x20   }\n\
+         }\n\n\
+         impl ::i18nrs::I18nKey for TranslationKey {\n\
+         \x20   fn key(&self) -> &'static str {\n\
+         \x20       self.as_str()\n\
+         \x20   }\n\
+         }\n",
+    );
+    Ok(out)
+}
+
+/// Generates the Rust source of the precompiled translations module.
+///
+/// # Arguments
+/// - `translations`: Language codes mapped to their JSON objects, as returned by `load_dir`.
+///
+/// # Returns
+/// - `Ok(String)` containing the module.
+/// - `Err(String)` if a flattened key is defined twice (e.g. `"a.b"` next to `{"a": {"b"}}`)
+///   or is both a translation and the prefix of other keys (e.g. `"menu"` and
+///   `"menu.open"`), since `I18n::from_static` could only keep one of them.
+pub fn generate(translations: &BTreeMap<String, Value>) -> Result<String, String> {
+    let mut out = String::from(
+        "// @generated by i18nrs-build. Do not edit.\n\n\
+         /// Precompiled translations: language codes mapped to flattened `(key, value)` pairs.\n\
+         pub static TRANSLATIONS: &[(&str, &[(&str, &str)])] = &[\n",
+    );
+
+    for (language, json) in translations {
+        let _ = writeln!(out, "    ({:?}, &[", language);
+        let keys =
+            flatten_strict(json).map_err(|err| format!("{} in language '{}'", err, language))?;
+        for (key, value) in keys {
+            let _ = writeln!(out, "        ({:?}, {:?}),", key, value);
+        }
+        out.push_str("    ]),\n");
+    }
+
+    out.push_str("];\n");
+    Ok(out)
+}
+
+/// Flattens a JSON object into sorted dot-separated `(key, value)` pairs.
+///
+/// String leaves are kept as-is; other leaves (numbers, booleans, arrays) are stored as
+/// their JSON text, matching what `I18n::t` returns for them.
+pub fn flatten(json: &Value) -> BTreeMap<String, String> {
+    fn walk(prefix: &str, json: &Value, out: &mut BTreeMap<String, String>) {
+        match json {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    walk(&path, value, out);
+                }
+            }
+            Value::String(value) => {
+                out.insert(prefix.to_string(), value.clone());
+            }
+            value => {
+                out.insert(prefix.to_string(), value.to_string());
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    walk("", json, &mut out);
+    out
+}
+
+/// Flattens a JSON object like `flatten`, rejecting keys that would collide.
+fn flatten_strict(json: &Value) -> Result<BTreeMap<String, String>, String> {
+    fn walk(prefix: &str, json: &Value, out: &mut BTreeMap<String, String>) -> Result<(), String> {
+        match json {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    walk(&path, value, out)?;
+                }
+                Ok(())
+            }
+            value => {
+                let value = match value {
+                    Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                };
+                match out.insert(prefix.to_string(), value) {
+                    Some(_) => Err(format!("Key '{}' is defined twice", prefix)),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    walk("", json, &mut out)?;
+    for key in out.keys() {
+        if let Some(prefix) = key
+            .match_indices('.')
+            .map(|(end, _)| &key[..end])
+            .find(|prefix| out.contains_key(*prefix))
+        {
+            return Err(format!(
+                "Key '{}' is both a translation and a prefix of '{}'",
+                prefix, key
+            ));
+        }
+    }
+    Ok(out)
+}
+
+/// Converts a dot-separated key into an UpperCamelCase variant name.
+fn variant_name(key: &str) -> String {
+    let mut name = String::new();
+    for word in key.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars);
+        }
+    }
+
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert_str(0, "Key");
+    }
+    name
+}
+
+/// Reads every JSON file of a `<dir>/<lang>/` directory into one object.
+fn load_language_dir(dir: &Path) -> Result<Value, String> {
+    let mut files: Vec<_> = fs::read_dir(dir)
+        .map_err(|err| format!("Failed to read '{}': {}", dir.display(), err))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+
+    let mut language = Value::Object(Map::new());
+    for path in files {
+        let bundle = read_json(&path)?;
+        let is_base = path.file_name().is_some_and(|name| name == BASE_FILE);
+        match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(namespace) if !is_base => {
+                let mut nested = Map::new();
+                nested.insert(namespace.to_string(), bundle);
+                merge(&mut language, Value::Object(nested));
+            }
+            _ => merge(&mut language, bundle),
+        }
+    }
+
+    Ok(language)
+}
+
+/// Reads a JSON file and checks that its root is an object.
+fn read_json(path: &Path) -> Result<Value, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read '{}': {}", path.display(), err))?;
+    let json: Value = serde_json::from_str(&content)
+        .map_err(|err| format!("Invalid JSON in '{}': {}", path.display(), err))?;
+
+    if !json.is_object() {
+        return Err(format!(
+            "Translation file '{}' must contain a JSON object",
+            path.display()
+        ));
+    }
+
+    Ok(json)
+}
+
+/// Deep-merges `other` into `target`, with `other` winning on conflicts.
+fn merge(target: &mut Value, other: Value) {
+    match (target, other) {
+        (Value::Object(target), Value::Object(other)) => {
+            for (key, value) in other {
+                match target.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, other) => *target = other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Creates an empty scratch directory for a test.
+    fn scratch(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("i18nrs-build-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn load_dir_merges_both_layouts() {
+        let dir = scratch("layouts");
+        fs::create_dir(dir.join("en")).unwrap();
+        fs::write(
+            dir.join("en/base.json"),
+            r#"{"title": "Base", "nav": {"home": "Home"}}"#,
+        )
+        .unwrap();
+        fs::write(dir.join("en/checkout.json"), r#"{"pay": "Pay"}"#).unwrap();
+        fs::write(
+            dir.join("en.json"),
+            r#"{"title": "Flat", "nav": {"back": "Back"}}"#,
+        )
+        .unwrap();
+        fs::write(dir.join("fr.json"), r#"{"title": "Titre"}"#).unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let translations = load_dir(&dir).unwrap();
+        assert_eq!(translations.keys().collect::<Vec<_>>(), ["en", "fr"]);
+        assert_eq!(
+            translations["en"],
+            json!({
+                "title": "Flat",
+                "nav": {"home": "Home", "back": "Back"},
+                "checkout": {"pay": "Pay"},
+            })
+        );
+        assert_eq!(locale_files(&dir).unwrap().len(), 4);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn load_dir_rejects_invalid_files() {
+        let dir = scratch("invalid");
+        assert!(
+            load_dir(&dir)
+                .unwrap_err()
+                .starts_with("No translations found")
+        );
+
+        fs::write(dir.join("en.json"), r#"["not", "an", "object"]"#).unwrap();
+        assert!(
+            load_dir(&dir)
+                .unwrap_err()
+                .contains("must contain a JSON object")
+        );
+
+        fs::write(dir.join("en.json"), r#"{"title": "#).unwrap();
+        assert!(load_dir(&dir).unwrap_err().starts_with("Invalid JSON"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(
+            load_dir(&dir)
+                .unwrap_err()
+                .starts_with("Failed to read locale directory")
+        );
+    }
+
+    #[test]
+    fn flatten_joins_keys_and_stringifies_leaves() {
+        let keys = flatten(&json!({
+            "menu": {"file": {"open": "Open"}},
+            "count": 3,
+            "enabled": true,
+            "tags": ["a"],
+        }));
+        assert_eq!(
+            keys.into_iter().collect::<Vec<_>>(),
+            [
+                ("count".to_string(), "3".to_string()),
+                ("enabled".to_string(), "true".to_string()),
+                ("menu.file.open".to_string(), "Open".to_string()),
+                ("tags".to_string(), r#"["a"]"#.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn generate_rejects_colliding_keys() {
+        let generated = generate(&BTreeMap::from([(
+            "en".to_string(),
+            json!({"menu": {"open": "Open"}}),
+        )]))
+        .unwrap();
+        assert!(generated.contains(r#"("menu.open", "Open"),"#));
+
+        let error = |json| generate(&BTreeMap::from([("en".to_string(), json)])).unwrap_err();
+        assert_eq!(
+            error(json!({"menu": "Menu", "menu.open": "Open"})),
+            "Key 'menu' is both a translation and a prefix of 'menu.open' in language 'en'"
+        );
+        assert_eq!(
+            error(json!({"a": {"b": "B"}, "a.b": "AB"})),
+            "Key 'a.b' is defined twice in language 'en'"
+        );
+    }
+
+    #[test]
+    fn variant_names_are_upper_camel_case() {
+        assert_eq!(variant_name("menu.file.open"), "MenuFileOpen");
+        assert_eq!(variant_name("errors.not_found"), "ErrorsNotFound");
+        assert_eq!(variant_name("nav.home-page"), "NavHomePage");
+        assert_eq!(variant_name("404.title"), "Key404Title");
+        assert_eq!(variant_name(""), "Key");
+
+        assert!(
+            generate_keys(&json!({"a_b": "x", "a": {"b": "y"}}))
+                .unwrap_err()
+                .contains("both map to variant 'AB'")
+        );
+    }
+}
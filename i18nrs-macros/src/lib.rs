@@ -0,0 +1,234 @@
+//! Procedural macros for [i18nrs](https://docs.rs/i18nrs).
+//!
+//! These macros are re-exported by `i18nrs` behind the `macros` feature; depend on that
+//! feature instead of this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use std::path::PathBuf;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, LitStr, Token, parse_macro_input};
+
+/// Embeds a locale directory as the translations map expected by the providers.
+///
+/// Walks `<dir>/<lang>.json` and `<dir>/<lang>/*.json` at compile time (relative to the
+/// crate's `Cargo.toml`), validates every file, and expands to a
+/// `HashMap<&'static str, &'static str>` mapping language codes to their merged JSON.
+/// In the per-language directory layout, `base.json` is merged at the root and every other
+/// file is nested under its file stem.
+///
+/// # Example
+/// ```rust,ignore
+/// use i18nrs::include_i18n;
+///
+/// let translations = include_i18n!("i18n");
+/// ```
+#[proc_macro]
+pub fn include_i18n(input: TokenStream) -> TokenStream {
+    let dir = parse_macro_input!(input as LitStr);
+    let path = manifest_path(&dir.value());
+
+    let expanded = i18nrs_build::load_dir(&path)
+        .and_then(|translations| {
+            let files = i18nrs_build::locale_files(&path)?;
+            Ok((translations, files))
+        })
+        .map(|(translations, files)| {
+            let tracked = files.iter().map(|file| {
+                let file = file.display().to_string();
+                quote! { const _: &[u8] = include_bytes!(#file); }
+            });
+            let entries = translations.iter().map(|(language, json)| {
+                let json = json.to_string();
+                quote! { (#language, #json) }
+            });
+
+            quote! {
+                {
+                    #(#tracked)*
+                    ::std::collections::HashMap::<&'static str, &'static str>::from([
+                        #(#entries),*
+                    ])
+                }
+            }
+        });
+
+    match expanded {
+        Ok(tokens) => tokens.into(),
+        Err(err) => syn::Error::new(dir.span(), err).to_compile_error().into(),
+    }
+}
+
+/// Generates a typed `TranslationKey` enum from the keys of a base language.
+///
+/// Takes the locale directory (relative to the crate's `Cargo.toml`) and the base language
+/// code, and expands to the same enum as `i18nrs_build::compile_keys`: one variant per key
+/// (e.g., `"menu.file.open"` → `TranslationKey::MenuFileOpen`), resolvable with `I18n::tk`.
+/// Lints are not reported inside macro expansions; use `compile_keys` from a build script
+/// to have unused keys flagged by `dead_code`.
+///
+/// # Example
+/// ```rust,ignore
+/// i18nrs::translation_keys!("i18n", "en");
+///
+/// let title = i18n.tk(TranslationKey::MenuFileOpen);
+/// ```
+#[proc_macro]
+pub fn translation_keys(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input with Punctuated::<LitStr, Token![,]>::parse_terminated);
+    let (dir, language) = match (args.first(), args.get(1), args.len()) {
+        (Some(dir), Some(language), 2) => (dir, language.value()),
+        _ => {
+            return syn::Error::new(
+                args.span(),
+                "expected a locale directory and a base language, e.g. (\"i18n\", \"en\")",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+    let path = manifest_path(&dir.value());
+
+    let expanded = i18nrs_build::load_dir(&path)
+        .and_then(|translations| {
+            let json = translations
+                .get(&language)
+                .ok_or_else(|| format!("Language '{}' not found in '{}'", language, dir.value()))?;
+            let keys: proc_macro2::TokenStream = i18nrs_build::generate_keys(json)?
+                .parse()
+                .map_err(|err| format!("Failed to generate keys: {}", err))?;
+            let files = i18nrs_build::locale_files(&path)?;
+            Ok((keys, files))
+        })
+        .map(|(keys, files)| {
+            let tracked = files.iter().map(|file| {
+                let file = file.display().to_string();
+                quote! { const _: &[u8] = include_bytes!(#file); }
+            });
+
+            quote! {
+                #(#tracked)*
+                #keys
+            }
+        });
+
+    match expanded {
+        Ok(tokens) => tokens.into(),
+        Err(err) => syn::Error::new(dir.span(), err).to_compile_error().into(),
+    }
+}
+
+/// Derives `i18nrs::LocalizedDisplay` (and `i18nrs::I18nKey`) for an enum.
+///
+/// Each variant maps to `<enum>.<variant>` in snake case, e.g. `Status::Pending` →
+/// `"status.pending"`. Use `#[i18n(prefix = "...")]` on the enum to change the prefix, and
+/// `#[i18n(key = "...")]` on a variant to set its full key. Variant fields are ignored.
+///
+/// # Example
+/// ```rust,ignore
+/// use i18nrs::LocalizedDisplay;
+///
+/// #[derive(LocalizedDisplay)]
+/// #[i18n(prefix = "orders.status")]
+/// enum Status {
+///     Pending,
+///     #[i18n(key = "orders.shipped")]
+///     Shipped { carrier: String },
+/// }
+///
+/// let label = Status::Pending.localize(&i18n); // "orders.status.pending"
+/// ```
+#[proc_macro_derive(LocalizedDisplay, attributes(i18n))]
+pub fn derive_localized_display(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match localized_display(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Expands `#[derive(LocalizedDisplay)]`.
+fn localized_display(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "LocalizedDisplay can only be derived for enums",
+        ));
+    };
+
+    let prefix = i18n_attribute(&input.attrs, "prefix")?
+        .unwrap_or_else(|| snake_case(&input.ident.to_string()));
+    let arms = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let key = i18n_attribute(&variant.attrs, "key")?.unwrap_or_else(|| {
+                format!("{}.{}", prefix, snake_case(&variant.ident.to_string()))
+            });
+            let ident = &variant.ident;
+            let pattern = match &variant.fields {
+                Fields::Named(_) => quote! { Self::#ident { .. } },
+                Fields::Unnamed(_) => quote! { Self::#ident(..) },
+                Fields::Unit => quote! { Self::#ident },
+            };
+            Ok(quote! { #pattern => #key, })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::i18nrs::I18nKey for #name #ty_generics #where_clause {
+            fn key(&self) -> &'static str {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::i18nrs::LocalizedDisplay for #name #ty_generics #where_clause {}
+    })
+}
+
+/// Reads `#[i18n(<name> = "...")]` from a list of attributes.
+fn i18n_attribute(attrs: &[syn::Attribute], name: &str) -> syn::Result<Option<String>> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("i18n")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                value = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error(format!("expected `{} = \"...\"`", name)))
+            }
+        })?;
+    }
+    Ok(value)
+}
+
+/// Converts a `CamelCase` identifier to `snake_case` (e.g., `HttpError` → `http_error`).
+fn snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut snake = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// Resolves a path relative to the invoking crate's manifest directory.
+fn manifest_path(dir: &str) -> PathBuf {
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(root).join(dir)
+}
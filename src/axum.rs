@@ -0,0 +1,126 @@
+//! Axum integration.
+//!
+//! The `Locale` extractor negotiates the language of a request, so handlers can localize
+//! API responses and server-rendered pages with the same rules as the client providers.
+
+use crate::config::I18n;
+use crate::cookie::Cookie;
+use crate::negotiate::{RequestLocale, negotiate};
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::header::{ACCEPT_LANGUAGE, COOKIE};
+use axum::http::request::Parts;
+use std::convert::Infallible;
+
+/// The negotiated locale of a request.
+///
+/// Extracting a `Locale` requires an `I18n` instance in the router state (via `FromRef`).
+/// The language resolved by a `LocaleLayer` is used when present. Otherwise it is taken
+/// from the cookie written by the providers (named by `I18n::cookie_name`, `"i18nrs"` by
+/// default), then the `Accept-Language` header, and falls back to the current language of
+/// the state's instance.
+///
+/// # Example
+/// ```rust
+/// use axum::{Router, routing::get};
+/// use i18nrs::axum::Locale;
+/// use i18nrs::{I18n, I18nConfig};
+/// use std::collections::HashMap;
+///
+/// async fn greeting(locale: Locale) -> String {
+///     locale.i18n.t("greeting")
+/// }
+///
+/// let translations = HashMap::from([
+///     ("en", r#"{"greeting": "Hello"}"#),
+///     ("fr", r#"{"greeting": "Bonjour"}"#),
+/// ]);
+/// let mut i18n = I18n::new(I18nConfig { translations: translations.clone() }, translations).unwrap();
+/// i18n.set_cookie_name("lang");
+///
+/// let app: Router = Router::new().route("/", get(greeting)).with_state(i18n);
+/// ```
+#[derive(Clone)]
+pub struct Locale {
+    /// The negotiated language code.
+    pub language: String,
+    /// The state's i18n instance, switched to the negotiated language.
+    pub i18n: I18n,
+}
+
+impl<S> FromRequestParts<S> for Locale
+where
+    I18n: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let mut i18n = I18n::from_ref(state);
+        if let Some(RequestLocale(language)) = parts.extensions.get::<RequestLocale>() {
+            let _ = i18n.select_language(language);
+            return Ok(Self {
+                language: i18n.get_current_language().to_string(),
+                i18n,
+            });
+        }
+
+        let header = |name| {
+            parts
+                .headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+        let cookie = header(COOKIE).and_then(|raw| Cookie::get(raw, i18n.cookie_name()));
+
+        let languages = i18n.languages();
+        if let Some(language) =
+            negotiate(cookie, header(ACCEPT_LANGUAGE), &languages).map(str::to_owned)
+        {
+            let _ = i18n.select_language(&language);
+        }
+
+        Ok(Self {
+            language: i18n.get_current_language().to_string(),
+            i18n,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::I18nConfig;
+    use axum::http::Request;
+    use std::collections::HashMap;
+
+    fn extract(i18n: &I18n, cookie: &str, accept_language: &str) -> String {
+        let (mut parts, ()) = Request::builder()
+            .header(COOKIE, cookie)
+            .header(ACCEPT_LANGUAGE, accept_language)
+            .body(())
+            .unwrap()
+            .into_parts();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let Ok(locale) = runtime.block_on(Locale::from_request_parts(&mut parts, i18n));
+        locale.language
+    }
+
+    #[test]
+    fn cookie_name_comes_from_the_state() {
+        let translations = HashMap::from([("en", "{}"), ("fr", "{}"), ("de", "{}")]);
+        let mut i18n = I18n::new(
+            I18nConfig {
+                translations: translations.clone(),
+            },
+            translations,
+        )
+        .unwrap();
+        assert_eq!(extract(&i18n, "i18nrs=fr", "de"), "fr");
+
+        i18n.set_cookie_name("lang");
+        assert_eq!(extract(&i18n, "i18nrs=fr", "de"), "de");
+        assert_eq!(extract(&i18n, "i18nrs=fr; lang=fr", "de"), "fr");
+    }
+}
@@ -0,0 +1,149 @@
+//! Command-line tools for i18nrs locale files.
+//!
+//! ```text
+//! i18nrs extract [--src <dir>]... [--locale <file>]
+//! i18nrs check [--dir <dir>] [--base <lang>]
+//! ```
+//!
+//! `extract` scans Rust sources (default: `src`) for referenced translation keys. Without
+//! `--locale` it prints them, one per line; with `--locale` it merges new keys into that
+//! base locale file with empty placeholders.
+//!
+//! `check` compares every language of a locale directory (default: `i18n`) against the
+//! base language (default: `en`), reporting missing keys, extra keys, and placeholder
+//! mismatches. It exits with a non-zero code if any are found.
+
+use i18nrs::check::check;
+use i18nrs::extract::{merge_keys, scan_dir};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+    i18nrs extract [--src <dir>]... [--locale <file>]
+    i18nrs check [--dir <dir>] [--base <lang>]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("extract") => extract(&args[1..]),
+        Some("check") => run_check(&args[1..]),
+        Some("-h" | "--help") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the `extract` command.
+fn extract(args: &[String]) -> Result<(), String> {
+    let mut sources = Vec::new();
+    let mut locale = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--src" => sources.push(args.next().ok_or(USAGE)?.clone()),
+            "--locale" => locale = Some(args.next().ok_or(USAGE)?.clone()),
+            _ => return Err(format!("Unknown argument '{}'\n{}", arg, USAGE)),
+        }
+    }
+    if sources.is_empty() {
+        sources.push("src".to_string());
+    }
+
+    let mut keys = BTreeSet::new();
+    for source in &sources {
+        keys.extend(scan_dir(source)?);
+    }
+
+    let Some(locale) = locale else {
+        for key in &keys {
+            println!("{}", key);
+        }
+        return Ok(());
+    };
+
+    let mut json: Value = match fs::read_to_string(&locale) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|err| format!("Invalid JSON in '{}': {}", locale, err))?,
+        // A locale that doesn't exist yet starts empty; any other error must not lead to
+        // overwriting the existing translations.
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Value::Object(Default::default()),
+        Err(err) => return Err(format!("Failed to read '{}': {}", locale, err)),
+    };
+    let added = merge_keys(&mut json, keys.iter().map(String::as_str));
+
+    let content = serde_json::to_string_pretty(&json)
+        .map_err(|err| format!("Failed to serialize '{}': {}", locale, err))?;
+    fs::write(&locale, content + "\n")
+        .map_err(|err| format!("Failed to write '{}': {}", locale, err))?;
+
+    for key in &added {
+        println!("+ {}", key);
+    }
+    println!(
+        "{} keys referenced, {} added to '{}'",
+        keys.len(),
+        added.len(),
+        locale
+    );
+    Ok(())
+}
+
+/// Runs the `check` command.
+fn run_check(args: &[String]) -> Result<(), String> {
+    let mut dir = "i18n".to_string();
+    let mut base = "en".to_string();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dir" => dir = args.next().ok_or(USAGE)?.clone(),
+            "--base" => base = args.next().ok_or(USAGE)?.clone(),
+            _ => return Err(format!("Unknown argument '{}'\n{}", arg, USAGE)),
+        }
+    }
+
+    let translations = i18nrs_build::load_dir(&dir)?;
+    let issues = check(&translations, &base)?;
+
+    let mut failed = 0;
+    for locale in &issues {
+        if locale.is_empty() {
+            println!("{}: ok", locale.language);
+            continue;
+        }
+
+        failed += 1;
+        println!("{}:", locale.language);
+        for key in &locale.missing {
+            println!("  missing      {}", key);
+        }
+        for key in &locale.extra {
+            println!("  extra        {}", key);
+        }
+        for key in &locale.placeholder_mismatches {
+            println!("  placeholders {}", key);
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!(
+            "{} of {} languages differ from '{}'",
+            failed,
+            issues.len(),
+            base
+        ));
+    }
+    Ok(())
+}
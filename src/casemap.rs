@@ -0,0 +1,73 @@
+//! Locale-aware case transformation.
+//!
+//! Applies the full Unicode case mappings with the tailorings of a language, such as the
+//! dotted and dotless `i` of Turkish and Azeri, `ß` becoming `SS` in German or the `IJ`
+//! digraph of Dutch, for UI elements that display user content in another case. Requires the
+//! `casemap` feature.
+
+use crate::cldr::locale;
+use icu_casemap::{CaseMapper, TitlecaseMapper};
+use icu_locale_core::LanguageIdentifier;
+
+/// Resolves the language identifier to case with, using the root rules for invalid codes.
+fn language_id(language: &str) -> LanguageIdentifier {
+    locale(language)
+        .map(|locale| locale.id)
+        .unwrap_or(LanguageIdentifier::UNKNOWN)
+}
+
+/// Converts a string to uppercase using the rules of a language.
+///
+/// Invalid language codes use the language-neutral rules.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::casemap::to_upper;
+///
+/// assert_eq!(to_upper("en", "istanbul"), "ISTANBUL");
+/// assert_eq!(to_upper("tr", "istanbul"), "İSTANBUL");
+/// assert_eq!(to_upper("de", "Straße"), "STRASSE");
+/// ```
+pub fn to_upper(language: &str, s: &str) -> String {
+    CaseMapper::new()
+        .uppercase_to_string(s, &language_id(language))
+        .into_owned()
+}
+
+/// Converts a string to lowercase using the rules of a language.
+///
+/// Invalid language codes use the language-neutral rules.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::casemap::to_lower;
+///
+/// assert_eq!(to_lower("en", "DIYARBAKIR"), "diyarbakir");
+/// assert_eq!(to_lower("tr", "DIYARBAKIR"), "dıyarbakır");
+/// ```
+pub fn to_lower(language: &str, s: &str) -> String {
+    CaseMapper::new()
+        .lowercase_to_string(s, &language_id(language))
+        .into_owned()
+}
+
+/// Capitalizes every word of a string using the rules of a language.
+///
+/// Words are separated by whitespace; the first letter of each word is titlecased and the
+/// rest lowercased. Invalid language codes use the language-neutral rules.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::casemap::to_title;
+///
+/// assert_eq!(to_title("en", "hello WORLD"), "Hello World");
+/// assert_eq!(to_title("nl", "ijsselmeer"), "IJsselmeer");
+/// assert_eq!(to_title("tr", "istanbul"), "İstanbul");
+/// ```
+pub fn to_title(language: &str, s: &str) -> String {
+    let id = language_id(language);
+    let mapper = TitlecaseMapper::new();
+    s.split_inclusive(char::is_whitespace)
+        .map(|word| mapper.titlecase_segment_to_string(word, &id, Default::default()))
+        .collect()
+}
@@ -0,0 +1,177 @@
+//! Validation of locale files against a base language.
+//!
+//! Backs the `i18nrs check` command, which release scripts can use to block shipping
+//! incomplete or inconsistent translations.
+
+use crate::config::Message;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The problems found in one language compared to the base language.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocaleIssues {
+    /// The language code the issues belong to.
+    pub language: String,
+    /// Keys of the base language that are missing or empty.
+    pub missing: BTreeSet<String>,
+    /// Keys that do not exist in the base language.
+    pub extra: BTreeSet<String>,
+    /// Keys whose placeholders differ from the base language.
+    pub placeholder_mismatches: BTreeSet<String>,
+}
+
+impl LocaleIssues {
+    /// Checks whether no problems were found.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.placeholder_mismatches.is_empty()
+    }
+}
+
+/// Compares every language against the base language.
+///
+/// # Arguments
+/// - `translations`: Language codes mapped to their JSON objects, e.g. from
+///   `i18nrs_build::load_dir`.
+/// - `base`: The base language code (e.g., `"en"`).
+///
+/// # Returns
+/// - `Ok(Vec<LocaleIssues>)` with one entry per non-base language, in code order.
+/// - `Err(String)` if the base language is missing.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::check::check;
+/// use serde_json::json;
+/// use std::collections::BTreeMap;
+///
+/// let translations = BTreeMap::from([
+///     ("en".to_string(), json!({"hi": "Hi {name}", "bye": "Bye"})),
+///     ("fr".to_string(), json!({"hi": "Salut {nom}", "beta": "Bêta"})),
+/// ]);
+///
+/// let issues = check(&translations, "en").unwrap();
+/// assert!(issues[0].missing.contains("bye"));
+/// assert!(issues[0].extra.contains("beta"));
+/// assert!(issues[0].placeholder_mismatches.contains("hi"));
+/// ```
+pub fn check(
+    translations: &BTreeMap<String, Value>,
+    base: &str,
+) -> Result<Vec<LocaleIssues>, String> {
+    let base_values = translations
+        .get(base)
+        .map(i18nrs_build::flatten)
+        .ok_or_else(|| format!("Base language '{}' not found", base))?;
+
+    let issues = translations
+        .iter()
+        .filter(|(language, _)| language.as_str() != base)
+        .map(|(language, json)| {
+            let values = i18nrs_build::flatten(json);
+            LocaleIssues {
+                language: language.clone(),
+                missing: base_values
+                    .keys()
+                    .filter(|key| values.get(*key).is_none_or(String::is_empty))
+                    .cloned()
+                    .collect(),
+                extra: values
+                    .keys()
+                    .filter(|key| !base_values.contains_key(*key))
+                    .cloned()
+                    .collect(),
+                placeholder_mismatches: values
+                    .iter()
+                    .filter(|(key, value)| {
+                        base_values.get(*key).is_some_and(|base_value| {
+                            !value.is_empty()
+                                && !base_value.is_empty()
+                                && placeholders(base_value) != placeholders(value)
+                        })
+                    })
+                    .map(|(key, _)| key.clone())
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Ok(issues)
+}
+
+/// Collects the placeholder names of a translation value.
+///
+/// Values are parsed like `I18n::t_with` parses them, so typed placeholders such as
+/// `{due, date, long}` count by their name and unbalanced braces are literal text.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::check::placeholders;
+///
+/// let names = placeholders("Hi {name}, you have {count} items due {due, date, long}");
+/// assert_eq!(names.into_iter().collect::<Vec<_>>(), ["count", "due", "name"]);
+/// ```
+pub fn placeholders(value: &str) -> BTreeSet<String> {
+    Message::parse(value)
+        .placeholder_names()
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I18n, I18nConfig};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn placeholders_keep_typed_and_drop_unbalanced() {
+        assert_eq!(
+            placeholders("{due, date, long} at {time,time}"),
+            BTreeSet::from(["due".to_string(), "time".to_string()])
+        );
+        assert_eq!(placeholders("{a {b}"), BTreeSet::from(["b".to_string()]));
+        assert!(placeholders("{ unclosed").is_empty());
+        assert!(placeholders("closed }").is_empty());
+    }
+
+    #[test]
+    fn check_agrees_with_load_time_check() {
+        let en = r#"{"due": "Due {due, date, long}", "hi": "Hi {name}", "empty": ""}"#;
+        let fr = r#"{"due": "Échéance {due}", "hi": "Salut {nom}", "empty": "{x}"}"#;
+
+        let translations = BTreeMap::from([
+            ("en".to_string(), serde_json::from_str(en).unwrap()),
+            ("fr".to_string(), serde_json::from_str::<Value>(fr).unwrap()),
+        ]);
+        let issues = check(&translations, "en").unwrap();
+
+        let sources = HashMap::from([("en", en), ("fr", fr)]);
+        let i18n = I18n::new(
+            I18nConfig {
+                translations: sources.clone(),
+            },
+            sources,
+        )
+        .unwrap();
+        let mismatches: BTreeSet<String> = i18n
+            .check_placeholders("en")
+            .unwrap()
+            .into_iter()
+            .map(|mismatch| mismatch.key)
+            .collect();
+
+        assert_eq!(issues[0].placeholder_mismatches, mismatches);
+        assert_eq!(mismatches, BTreeSet::from(["hi".to_string()]));
+    }
+
+    #[test]
+    fn check_requires_the_base_language() {
+        let translations = BTreeMap::from([("fr".to_string(), json!({}))]);
+        assert_eq!(
+            check(&translations, "en").unwrap_err(),
+            "Base language 'en' not found"
+        );
+    }
+}
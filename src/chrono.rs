@@ -0,0 +1,118 @@
+//! Conversions from `chrono` types to the inputs of the `datetime` formatters.
+//!
+//! Lets `chrono::NaiveDate`, `NaiveDateTime` and `DateTime` values be passed directly to
+//! `format_date`, `format_skeleton`, `format_pattern` and `format_zoned`, and into typed
+//! placeholders such as `{due, date, long}`. Requires the `chrono` feature.
+//!
+//! # Example
+//! ```rust
+//! use chrono::{FixedOffset, NaiveDate, TimeZone};
+//! use i18nrs::datetime::{DateStyle, ZoneStyle, format_date, format_zoned};
+//! use i18nrs::{I18n, I18nConfig};
+//! use std::collections::HashMap;
+//!
+//! let date = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+//! assert_eq!(format_date("fr", &date, DateStyle::Long).unwrap(), "15 janvier 2025");
+//!
+//! let paris = FixedOffset::east_opt(3600).unwrap();
+//! let meeting = paris.with_ymd_and_hms(2025, 1, 15, 17, 5, 0).unwrap();
+//! assert_eq!(
+//!     format_zoned("en", &meeting, DateStyle::Medium, ZoneStyle::LocalizedOffsetShort).unwrap(),
+//!     "Jan 15, 2025, 5:05\u{202f}PM GMT+1"
+//! );
+//!
+//! let translations = HashMap::from([("de", r#"{"due": "Fällig am {date, date, long}"}"#)]);
+//! let i18n = I18n::new(
+//!     I18nConfig { translations: translations.clone() },
+//!     translations,
+//! )
+//! .unwrap();
+//! assert_eq!(i18n.t_with("due", &[("date", &date)]), "Fällig am 15. Januar 2025");
+//! ```
+
+use crate::datetime::{
+    Date, DateTime, Iso, Time, TimeZone, ToDate, ToDateTime, ToZonedDateTime, UtcOffset,
+    ZonedDateTime,
+};
+use ::chrono::{Datelike, NaiveDate, NaiveDateTime, Offset, Timelike};
+
+/// Converts the date part of a `chrono` value.
+fn date(date: &impl Datelike) -> Result<Date<Iso>, String> {
+    Date::try_new_iso(date.year(), date.month() as u8, date.day() as u8)
+        .map_err(|err| format!("Unsupported date: {}", err))
+}
+
+/// Converts the date and time of a `chrono` value.
+///
+/// Leap seconds, which `chrono` stores as an overflowing fraction, are clamped to the end
+/// of the second.
+fn datetime(datetime: &NaiveDateTime) -> Result<DateTime<Iso>, String> {
+    let time = Time::try_new(
+        datetime.hour() as u8,
+        datetime.minute() as u8,
+        datetime.second() as u8,
+        datetime.nanosecond().min(999_999_999),
+    )
+    .map_err(|err| format!("Unsupported time: {}", err))?;
+    Ok(DateTime {
+        date: date(datetime)?,
+        time,
+    })
+}
+
+impl ToDate for NaiveDate {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        date(self)
+    }
+}
+
+impl ToDateTime for NaiveDate {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        Ok(DateTime {
+            date: date(self)?,
+            time: Time::start_of_day(),
+        })
+    }
+}
+
+impl ToDate for NaiveDateTime {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        date(self)
+    }
+}
+
+impl ToDateTime for NaiveDateTime {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        datetime(self)
+    }
+}
+
+impl<Tz: ::chrono::TimeZone> ToDate for ::chrono::DateTime<Tz> {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        date(&self.naive_local())
+    }
+}
+
+impl<Tz: ::chrono::TimeZone> ToDateTime for ::chrono::DateTime<Tz> {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        datetime(&self.naive_local())
+    }
+}
+
+/// The value is formatted in its own offset. `chrono` offsets carry no IANA identifier, so
+/// zone names fall back to the GMT offset; use `datetime::zoned` to attach one.
+impl<Tz: ::chrono::TimeZone> ToZonedDateTime for ::chrono::DateTime<Tz> {
+    fn to_zoned_datetime(&self) -> Result<ZonedDateTime, String> {
+        let local = datetime(&self.naive_local())?;
+        let offset = UtcOffset::try_from_seconds(self.offset().fix().local_minus_utc())
+            .map_err(|err| format!("Unsupported offset: {}", err))?;
+        let zone = TimeZone::UNKNOWN
+            .with_offset(Some(offset))
+            .at_date_time(local);
+        Ok(ZonedDateTime {
+            date: local.date,
+            time: local.time,
+            zone,
+        })
+    }
+}
@@ -0,0 +1,23 @@
+//! Shared helpers for the ICU4X-backed formatting modules.
+
+use icu_locale_core::Locale;
+
+/// Parses a language code of the translations into an ICU locale.
+///
+/// Underscores are accepted as subtag separators, so `"pt_BR"` and `"pt-BR"` are equivalent.
+pub(crate) fn locale(language: &str) -> Result<Locale, String> {
+    Locale::try_from_str(&language.replace('_', "-"))
+        .map_err(|err| format!("Invalid locale '{}': {}", language, err))
+}
+
+/// Resolves the region of a locale, inferring the likely one when it has none.
+///
+/// `"en"` resolves to `"US"` and `"de"` to `"DE"`, following the CLDR likely subtags.
+#[cfg(feature = "measurement")]
+pub(crate) fn region(locale: &Locale) -> Option<String> {
+    let mut id = locale.id.clone();
+    if id.region.is_none() {
+        icu_locale::LocaleExpander::new_common().maximize(&mut id);
+    }
+    id.region.map(|region| region.to_string())
+}
@@ -0,0 +1,86 @@
+//! Locale-aware string comparison and sorting.
+//!
+//! Compares strings with the CLDR collation rules of a locale, so user-visible lists sort
+//! the way readers expect (`"ä"` next to `"a"` in German but after `"z"` in Swedish)
+//! instead of by code point. Requires the `collation` feature.
+
+use crate::cldr::locale;
+use icu_collator::CollatorBorrowed;
+use std::cmp::Ordering;
+
+/// A collator for the sorting rules of a language.
+///
+/// Building a collator loads the locale's tailoring, so create one per sort rather than one
+/// per comparison.
+#[derive(Debug)]
+pub struct Collator {
+    inner: CollatorBorrowed<'static>,
+}
+
+impl Collator {
+    /// Creates a collator for a language.
+    ///
+    /// # Arguments
+    /// - `language`: The language code whose sorting rules to use (e.g., `"sv"`).
+    ///
+    /// # Returns
+    /// - `Ok(Collator)` for the language, or for the root collation if CLDR has no
+    ///   tailoring for it.
+    /// - `Err(String)` if the language code is not a valid locale.
+    pub fn new(language: &str) -> Result<Self, String> {
+        let inner = CollatorBorrowed::try_new(locale(language)?.into(), Default::default())
+            .map_err(|err| format!("No collation data for '{}': {}", language, err))?;
+        Ok(Self { inner })
+    }
+
+    /// Compares two strings.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::collation::Collator;
+    /// use std::cmp::Ordering;
+    ///
+    /// let german = Collator::new("de").unwrap();
+    /// let swedish = Collator::new("sv").unwrap();
+    ///
+    /// assert_eq!(german.compare("Äpfel", "Zucker"), Ordering::Less);
+    /// assert_eq!(swedish.compare("Äpfel", "Zucker"), Ordering::Greater);
+    /// ```
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.inner.compare(a, b)
+    }
+
+    /// Computes the sort key of a string.
+    ///
+    /// Comparing the keys of two strings byte-wise gives the same result as `compare`, which
+    /// makes them suitable for sorting the same strings many times or for storing in an index.
+    /// Keys depend on the CLDR and Unicode versions, so don't persist them across upgrades.
+    pub fn sort_key(&self, s: &str) -> Vec<u8> {
+        let mut key = Vec::new();
+        let Ok(()) = self.inner.write_sort_key_to(s, &mut key);
+        key
+    }
+
+    /// Sorts strings in place.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::collation::Collator;
+    ///
+    /// let mut names = vec!["zebra", "Émile", "apple", "Zoe"];
+    /// Collator::new("fr").unwrap().sort(&mut names);
+    /// assert_eq!(names, ["apple", "Émile", "zebra", "Zoe"]);
+    /// ```
+    pub fn sort<S: AsRef<str>>(&self, items: &mut [S]) {
+        items.sort_by(|a, b| self.compare(a.as_ref(), b.as_ref()));
+    }
+
+    /// Sorts values in place by a string extracted from each of them.
+    ///
+    /// # Arguments
+    /// - `items`: The values to sort.
+    /// - `key`: Returns the user-visible label of a value (e.g., a country's name).
+    pub fn sort_by_key<T>(&self, items: &mut [T], key: impl Fn(&T) -> &str) {
+        items.sort_by(|a, b| self.compare(key(a), key(b)));
+    }
+}
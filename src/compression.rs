@@ -0,0 +1,139 @@
+#[cfg(feature = "compression")]
+use std::io::Read;
+
+/// Compression formats supported for translation payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Gzip (`.json.gz`) payloads.
+    Gzip,
+    /// Brotli (`.json.br`) payloads.
+    Brotli,
+}
+
+impl Compression {
+    /// Detects the compression format of a payload.
+    ///
+    /// Gzip payloads are recognized by their magic bytes; Brotli has no magic bytes,
+    /// so anything else is treated as Brotli.
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            Compression::Gzip
+        } else {
+            Compression::Brotli
+        }
+    }
+
+    /// Infers the compression format from a file name or URL extension.
+    ///
+    /// # Returns
+    /// - `Some(Compression)` for `.gz` and `.br` paths.
+    /// - `None` for uncompressed paths.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = path.split(['?', '#']).next().unwrap_or(path);
+        if path.ends_with(".gz") {
+            Some(Compression::Gzip)
+        } else if path.ends_with(".br") {
+            Some(Compression::Brotli)
+        } else {
+            None
+        }
+    }
+}
+
+/// Decompresses a Gzip or Brotli translation payload into its JSON text.
+///
+/// Requires the `compression` feature; without it every call returns an error.
+///
+/// # Arguments
+/// - `bytes`: The compressed payload (e.g., `include_bytes!("../i18n/en.json.gz")`).
+///
+/// # Returns
+/// - `Ok(String)` containing the decompressed JSON.
+/// - `Err(String)` if the payload is corrupt or not valid UTF-8.
+pub fn decompress(bytes: &[u8]) -> Result<String, String> {
+    #[cfg(feature = "compression")]
+    {
+        let mut json = String::new();
+        let result = match Compression::detect(bytes) {
+            Compression::Gzip => flate2::read::GzDecoder::new(bytes).read_to_string(&mut json),
+            Compression::Brotli => {
+                brotli_decompressor::Decompressor::new(bytes, 4096).read_to_string(&mut json)
+            }
+        };
+        result.map_err(|err| format!("Failed to decompress translations: {}", err))?;
+        Ok(json)
+    }
+
+    #[cfg(not(feature = "compression"))]
+    {
+        let _ = bytes;
+        Err("Compressed translations require the `compression` feature".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Brotli encoding of `"X"`.
+    const BROTLI_X: [u8; 5] = [0x0b, 0x00, 0x80, 0x58, 0x03];
+
+    #[test]
+    fn format_is_inferred_from_paths_and_bytes() {
+        assert_eq!(
+            Compression::from_path("en.json.gz"),
+            Some(Compression::Gzip)
+        );
+        assert_eq!(
+            Compression::from_path("/i18n/en.json.br?v=2#top"),
+            Some(Compression::Brotli)
+        );
+        assert_eq!(Compression::from_path("/i18n/en.json?file=a.gz"), None);
+        assert_eq!(Compression::detect(&[0x1f, 0x8b, 0x08]), Compression::Gzip);
+        assert_eq!(Compression::detect(&[0x1f]), Compression::Brotli);
+    }
+
+    #[cfg(feature = "compression")]
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn decompresses_gzip_and_brotli() {
+        let json = r#"{"greeting": "Hello"}"#;
+        assert_eq!(decompress(&gzip(json.as_bytes())).unwrap(), json);
+        assert_eq!(decompress(&BROTLI_X).unwrap(), "X");
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn rejects_truncated_and_invalid_payloads() {
+        let payload = gzip(br#"{"greeting": "Hello"}"#);
+        for len in [2, 10, payload.len() - 1] {
+            let err = decompress(&payload[..len]).unwrap_err();
+            assert!(
+                err.starts_with("Failed to decompress translations"),
+                "{}",
+                err
+            );
+        }
+        assert!(decompress(&BROTLI_X[..3]).is_err());
+        assert!(decompress(b"{}").is_err());
+        assert!(decompress(&gzip(&[0xff, 0xfe])).is_err());
+    }
+
+    #[cfg(not(feature = "compression"))]
+    #[test]
+    fn requires_the_compression_feature() {
+        assert!(
+            decompress(&BROTLI_X)
+                .unwrap_err()
+                .contains("`compression` feature")
+        );
+    }
+}
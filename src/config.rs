@@ -1,8 +1,679 @@
+#[cfg(target_arch = "wasm32")]
+use crate::cookie::{Cookie, write_document_cookie};
+use crate::languages::LanguageInfo;
 use serde_json::{self, Value};
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::sync::{Arc, Mutex};
 #[cfg(target_arch = "wasm32")]
 use web_sys::window;
 
+/// Adds empty placeholder bundles for languages whose content is loaded separately.
+///
+/// The placeholders make such languages selectable before their bundle is inserted;
+/// lookups fall back to the default language until then.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn with_placeholders<'a>(
+    translations: &HashMap<&'static str, &'static str>,
+    languages: impl IntoIterator<Item = &'a &'static str>,
+) -> HashMap<&'static str, &'static str> {
+    let mut translations = translations.clone();
+    for language in languages {
+        translations.entry(language).or_insert("{}");
+    }
+    translations
+}
+
+/// Named arguments substituted into `{name}` placeholders by `I18n::t_with`.
+pub type I18nArgs<'a> = &'a [(&'a str, &'a dyn Display)];
+
+/// Substitutes `{name}` placeholders in a template in a single pass.
+///
+/// Placeholders without a matching argument are left untouched, and substituted values
+/// are never scanned again, so they may safely contain braces. Typed placeholders such as
+/// `{due, date, long}` are formatted for `language` with the `datetime` feature, and fall
+/// back to the plain value otherwise.
+pub(crate) fn interpolate(language: &str, template: &str, args: I18nArgs<'_>) -> String {
+    Message::parse(template).format(language, args)
+}
+
+/// A part of a parsed message.
+#[derive(Debug)]
+enum Segment {
+    /// Literal text.
+    Text(String),
+    /// A `{name}` or `{name, format}` placeholder.
+    Placeholder {
+        name: String,
+        format: Option<String>,
+        /// The placeholder as written, kept when no argument matches.
+        source: String,
+    },
+}
+
+/// A message template parsed into text and placeholders, so it can be rendered repeatedly
+/// without scanning the template again.
+#[derive(Debug)]
+pub(crate) struct Message(Vec<Segment>);
+
+impl Message {
+    /// Parses a template. A `{` not closed before the next `{` is literal text.
+    pub(crate) fn parse(template: &str) -> Self {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            text.push_str(&rest[..start]);
+            let tail = &rest[start..];
+            let end = tail.find('}').filter(|end| !tail[1..*end].contains('{'));
+            let Some(end) = end else {
+                text.push('{');
+                rest = &tail[1..];
+                continue;
+            };
+
+            let (name, format) = match tail[1..end].split_once(',') {
+                Some((name, format)) => (name.trim(), Some(format.to_string())),
+                None => (&tail[1..end], None),
+            };
+            if !text.is_empty() {
+                segments.push(Segment::Text(std::mem::take(&mut text)));
+            }
+            segments.push(Segment::Placeholder {
+                name: name.to_string(),
+                format,
+                source: tail[..=end].to_string(),
+            });
+            rest = &tail[end + 1..];
+        }
+
+        text.push_str(rest);
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+        Message(segments)
+    }
+
+    /// The names of the placeholders of the message.
+    pub(crate) fn placeholder_names(&self) -> BTreeSet<&str> {
+        self.0
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Placeholder { name, .. } => Some(name.as_str()),
+                Segment::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// Renders the message with the given arguments.
+    fn format(&self, language: &str, args: I18nArgs<'_>) -> String {
+        let mut out = String::new();
+        for segment in &self.0 {
+            match segment {
+                Segment::Text(text) => out.push_str(text),
+                Segment::Placeholder {
+                    name,
+                    format,
+                    source,
+                } => match args.iter().find(|(arg, _)| arg == name) {
+                    Some((_, value)) => match format {
+                        Some(format) => out.push_str(&format_argument(language, *value, format)),
+                        None => out.push_str(&value.to_string()),
+                    },
+                    None => out.push_str(source),
+                },
+            }
+        }
+        out
+    }
+}
+
+/// The most parsed messages an `I18n` instance keeps before its cache is cleared.
+const MAX_MESSAGES: usize = 1024;
+
+/// A parsed message with the template it was parsed from.
+struct CachedMessage {
+    template: String,
+    message: Arc<Message>,
+}
+
+/// Parsed messages of an `I18n` instance, keyed by language and key.
+///
+/// Each entry keeps its template and is parsed again when the key resolves to another
+/// one, e.g. after a reload, so clones of an instance can share the cache. Only keys that
+/// resolve are cached, and the cache is cleared once it holds `MAX_MESSAGES` entries.
+#[derive(Clone, Default)]
+struct MessageCache(Arc<Mutex<HashMap<(String, String), CachedMessage>>>);
+
+impl MessageCache {
+    /// Returns the parsed template of a key, parsing it on first use.
+    fn get(&self, language: &str, key: &str, template: &str) -> Arc<Message> {
+        let mut messages = self.0.lock().unwrap_or_else(|err| err.into_inner());
+        let cache_key = (language.to_string(), key.to_string());
+        if let Some(cached) = messages.get(&cache_key)
+            && cached.template == template
+        {
+            return cached.message.clone();
+        }
+        if messages.len() >= MAX_MESSAGES {
+            messages.clear();
+        }
+        let message = Arc::new(Message::parse(template));
+        messages.insert(
+            cache_key,
+            CachedMessage {
+                template: template.to_string(),
+                message: message.clone(),
+            },
+        );
+        message
+    }
+
+    /// Drops the messages of a language, or every message with `None`.
+    fn forget(&self, language: Option<&str>) {
+        let mut messages = self.0.lock().unwrap_or_else(|err| err.into_inner());
+        match language {
+            Some(language) => messages.retain(|(other, _), _| other != language),
+            None => messages.clear(),
+        }
+    }
+}
+
+impl PartialEq for MessageCache {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Formats the value of a typed placeholder, e.g. `date, long` in `{due, date, long}`.
+fn format_argument(language: &str, value: &dyn Display, format: &str) -> String {
+    let value = value.to_string();
+    #[cfg(feature = "datetime")]
+    if let Some(formatted) = crate::datetime::format_argument(language, &value, format) {
+        return formatted;
+    }
+    #[cfg(not(feature = "datetime"))]
+    let _ = (language, format);
+    value
+}
+
+/// Leaf translations of every language, keyed by their dot-separated path.
+///
+/// Keys and values are interned, so a key present in every language and a label repeated
+/// across the catalog (e.g., `"OK"`) are allocated once.
+#[derive(Clone, Default, PartialEq, Eq)]
+struct FlatIndex {
+    /// Translations of each language by key.
+    languages: HashMap<String, HashMap<Arc<str>, Arc<str>>>,
+    /// Every interned key and value, with the number of entries of `languages` using it.
+    ///
+    /// Counted here rather than with `Arc::strong_count`, since clones of the index (and
+    /// strings handed out by `t_shared`) hold the same `Arc`s.
+    strings: HashMap<Arc<str>, usize>,
+}
+
+impl FlatIndex {
+    /// Drops the index of a language.
+    fn remove(&mut self, language: &str) {
+        if let Some(keys) = self.languages.remove(language) {
+            self.release(keys);
+        }
+    }
+
+    /// Releases the interned strings of a dropped index that no other language uses.
+    fn release(&mut self, keys: HashMap<Arc<str>, Arc<str>>) {
+        for (key, value) in keys {
+            for s in [key, value] {
+                if let Some(count) = self.strings.get_mut(&s) {
+                    *count -= 1;
+                    if *count == 0 {
+                        self.strings.remove(&s);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Looks up the translation of a key in a language.
+    fn get(&self, language: &str, key: &str) -> Option<&Arc<str>> {
+        self.languages.get(language)?.get(key)
+    }
+
+    /// Indexes the translations of a language, replacing its previous index.
+    fn insert(&mut self, language: &str, json: &Value) {
+        fn walk(
+            strings: &mut HashMap<Arc<str>, usize>,
+            prefix: &str,
+            json: &Value,
+            out: &mut HashMap<Arc<str>, Arc<str>>,
+        ) {
+            let value = match json {
+                Value::Object(map) => {
+                    for (key, value) in map {
+                        let path = if prefix.is_empty() {
+                            key.clone()
+                        } else {
+                            format!("{}.{}", prefix, key)
+                        };
+                        walk(strings, &path, value, out);
+                    }
+                    return;
+                }
+                Value::String(s) => intern(strings, s),
+                _ => intern(strings, &json.to_string()),
+            };
+            out.insert(intern(strings, prefix), value);
+        }
+
+        fn intern(strings: &mut HashMap<Arc<str>, usize>, s: &str) -> Arc<str> {
+            let interned = match strings.get_key_value(s) {
+                Some((interned, _)) => interned.clone(),
+                None => Arc::from(s),
+            };
+            *strings.entry(interned.clone()).or_default() += 1;
+            interned
+        }
+
+        let mut keys = HashMap::new();
+        walk(&mut self.strings, "", json, &mut keys);
+        if let Some(previous) = self.languages.insert(language.to_string(), keys) {
+            self.release(previous);
+        }
+    }
+}
+
+/// A named set of translations stacked over the catalog by `I18n::set_layer`.
+#[derive(Clone, PartialEq, Eq)]
+struct Layer {
+    /// The name the layer is replaced and removed by (e.g., `"tenant"`).
+    name: String,
+    /// The translations of the layer for each of its languages.
+    translations: HashMap<String, Value>,
+    /// Leaf translations of the layer for each of its languages.
+    index: FlatIndex,
+}
+
+impl Layer {
+    /// Parses the JSON content of a layer by language code.
+    fn parse(name: &str, translations: HashMap<&str, &str>) -> Result<Self, String> {
+        let mut index = FlatIndex::default();
+        let translations = translations
+            .into_iter()
+            .map(|(language, json)| {
+                let json = parse_bundle(language, json)?;
+                index.insert(language, &json);
+                Ok((language.to_string(), json))
+            })
+            .collect::<Result<HashMap<_, _>, String>>()?;
+
+        Ok(Self {
+            name: name.to_string(),
+            translations,
+            index,
+        })
+    }
+}
+
+/// Precompiled translations: language codes mapped to flattened `(key, value)` pairs.
+///
+/// This is the shape of the `TRANSLATIONS` static generated by `i18nrs-build`.
+pub type StaticTranslations = &'static [(&'static str, &'static [(&'static str, &'static str)])];
+
+/// A typed translation key, resolvable with `I18n::tk`.
+///
+/// Implemented by the `TranslationKey` enum generated by `i18nrs-build` or the
+/// `translation_keys!` macro, so renamed or removed keys become compile errors.
+pub trait I18nKey {
+    /// Returns the dot-separated key (e.g., `"menu.file.open"`).
+    fn key(&self) -> &'static str;
+}
+
+/// A value displayed through its translation, such as a status or category enum.
+///
+/// Usually derived with `#[derive(LocalizedDisplay)]` (requires the `macros` feature),
+/// which maps each variant to `<enum>.<variant>` in snake case, e.g.
+/// `Status::Pending` → `"status.pending"`. The prefix can be changed with
+/// `#[i18n(prefix = "...")]` on the enum, and a variant's full key with
+/// `#[i18n(key = "...")]`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::{I18n, I18nConfig, I18nKey, LocalizedDisplay};
+/// use std::collections::HashMap;
+///
+/// enum Status {
+///     Pending,
+///     Shipped,
+/// }
+///
+/// impl I18nKey for Status {
+///     fn key(&self) -> &'static str {
+///         match self {
+///             Status::Pending => "status.pending",
+///             Status::Shipped => "status.shipped",
+///         }
+///     }
+/// }
+///
+/// impl LocalizedDisplay for Status {}
+///
+/// let translations = HashMap::from([("en", r#"{"status": {"pending": "Pending", "shipped": "Shipped"}}"#)]);
+/// let i18n = I18n::new(I18nConfig { translations: translations.clone() }, translations).unwrap();
+///
+/// assert_eq!(Status::Shipped.localize(&i18n), "Shipped");
+/// ```
+pub trait LocalizedDisplay: I18nKey {
+    /// Translates the value with an i18n instance.
+    fn localize(&self, i18n: &I18n) -> String {
+        i18n.t(self.key())
+    }
+}
+
+/// A callback invoked with `(key, language)` when a key is missing for the current language.
+///
+/// Installed with `I18n::set_missing_key_handler`, e.g. from a `MissingKeyReporter`.
+#[derive(Clone)]
+pub struct MissingKeyHandler(Arc<MissingKeyFn>);
+
+/// The function behind a `MissingKeyHandler`.
+type MissingKeyFn = dyn Fn(&str, &str) + Send + Sync;
+
+impl MissingKeyHandler {
+    /// Creates a handler from a function taking the missing key and the language code.
+    pub fn new(handler: impl Fn(&str, &str) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(handler))
+    }
+
+    /// Invokes the handler.
+    pub fn call(&self, key: &str, language: &str) {
+        (self.0)(key, language)
+    }
+}
+
+impl PartialEq for MissingKeyHandler {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for MissingKeyHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MissingKeyHandler").finish_non_exhaustive()
+    }
+}
+
+/// Resolution counts of the keys looked up during a session.
+///
+/// Shared between clones of an `I18n` instance, so every component feeds the same counts.
+#[derive(Clone, Default)]
+struct KeyUsage(Arc<Mutex<HashMap<String, usize>>>);
+
+impl KeyUsage {
+    /// Increments the resolution count of a key.
+    fn record(&self, key: &str) {
+        let mut counts = self.0.lock().unwrap_or_else(|err| err.into_inner());
+        match counts.get_mut(key) {
+            Some(count) => *count += 1,
+            None => {
+                counts.insert(key.to_string(), 1);
+            }
+        }
+    }
+
+    /// Returns a snapshot of the resolution counts.
+    fn snapshot(&self) -> BTreeMap<String, usize> {
+        let counts = self.0.lock().unwrap_or_else(|err| err.into_inner());
+        counts
+            .iter()
+            .map(|(key, count)| (key.clone(), *count))
+            .collect()
+    }
+}
+
+impl PartialEq for KeyUsage {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Which keys were resolved during a session, as returned by `I18n::usage_report`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UsageReport {
+    /// Keys that were resolved, with the number of times each was resolved.
+    pub used: BTreeMap<String, usize>,
+    /// Keys present in the loaded translations that were never resolved.
+    pub unused: BTreeSet<String>,
+}
+
+/// Translation coverage of a language, as returned by `I18n::coverage`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// The number of keys translated in the language.
+    pub translated: usize,
+    /// The number of keys across every loaded language.
+    pub total: usize,
+    /// Keys present in another language but missing or empty in this one.
+    pub missing: BTreeSet<String>,
+}
+
+impl Coverage {
+    /// Returns the translated share of keys as a percentage, `100.0` when there are no keys.
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.translated as f64 * 100.0 / self.total as f64
+        }
+    }
+}
+
+/// Key differences between two languages, as returned by `I18n::diff`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocaleDiff {
+    /// Keys translated in the first language but missing or empty in the second.
+    pub missing: BTreeSet<String>,
+    /// Keys translated in the second language but missing or empty in the first.
+    pub extra: BTreeSet<String>,
+}
+
+/// A key whose placeholders differ from the base language, as returned by
+/// `I18n::check_placeholders`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlaceholderMismatch {
+    /// The language code of the mismatching translation.
+    pub language: String,
+    /// The translation key.
+    pub key: String,
+    /// The placeholder names used by the base language.
+    pub expected: BTreeSet<String>,
+    /// The placeholder names used by the language.
+    pub found: BTreeSet<String>,
+}
+
+impl Display for PlaceholderMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = |names: &BTreeSet<String>| {
+            names
+                .iter()
+                .map(|name| format!("{{{}}}", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        write!(
+            f,
+            "Placeholders of '{}' in '{}' ({}) differ from the base language ({})",
+            self.key,
+            self.language,
+            names(&self.found),
+            names(&self.expected)
+        )
+    }
+}
+
+/// A key defined with different values by two sources being merged, as recorded when
+/// `I18n::set_detect_conflicts` is enabled.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyConflict {
+    /// The language code of the conflicting translations.
+    pub language: String,
+    /// The dot-separated translation key.
+    pub key: String,
+    /// What redefined the key (e.g., `"namespace 'checkout'"` or `"layer 'user'"`).
+    pub source: String,
+    /// The value the key had before.
+    pub previous: String,
+    /// The value the key was redefined with.
+    pub value: String,
+}
+
+impl Display for KeyConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Key '{}' in '{}' is defined as '{}' and redefined as '{}' by {}",
+            self.key, self.language, self.previous, self.value, self.source
+        )
+    }
+}
+
+/// Collects the keys of `incoming` that `existing` already defines with another value,
+/// with their previous and new values.
+fn conflicting_keys(
+    existing: &Value,
+    incoming: &Value,
+    prefix: &str,
+    conflicts: &mut Vec<(String, String, String)>,
+) {
+    let render = |value: &Value| match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    match (existing, incoming) {
+        (Value::Object(existing), Value::Object(incoming)) => {
+            for (key, value) in incoming {
+                if let Some(previous) = existing.get(key) {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    conflicting_keys(previous, value, &path, conflicts);
+                }
+            }
+        }
+        (Value::Null, _) => {}
+        (existing, incoming) if existing != incoming => {
+            conflicts.push((prefix.to_string(), render(existing), render(incoming)));
+        }
+        _ => {}
+    }
+}
+
+/// Parses the JSON bundle of a language.
+pub(crate) fn parse_bundle(language: &str, json: &str) -> Result<Value, String> {
+    serde_json::from_str(json)
+        .map_err(|err| format!("Invalid JSON for language {}: {}", language, err))
+}
+
+/// Validates bundles against a JSON Schema, reporting the violations of each invalid
+/// language on its own line, in the order of the language codes.
+fn check_schema<'a>(
+    languages: impl IntoIterator<Item = (&'a str, &'a Value)>,
+    schema: &Value,
+) -> Result<(), String> {
+    let mut invalid: Vec<(&str, Vec<crate::schema::SchemaError>)> = languages
+        .into_iter()
+        .map(|(language, json)| (language, crate::schema::validate(schema, json)))
+        .filter(|(_, errors)| !errors.is_empty())
+        .collect();
+    if invalid.is_empty() {
+        return Ok(());
+    }
+
+    invalid.sort_by(|a, b| a.0.cmp(b.0));
+    let messages: Vec<String> = invalid
+        .into_iter()
+        .map(|(language, errors)| {
+            let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            format!(
+                "Invalid structure for language {}: {}",
+                language,
+                errors.join("; ")
+            )
+        })
+        .collect();
+    Err(messages.join("\n"))
+}
+
+/// Validates a bundle loaded after initialization, e.g. a remote or compressed one, against
+/// the schema of a provider, if it has one.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn check_bundle_schema(
+    language: &str,
+    json: &str,
+    schema: Option<&Value>,
+) -> Result<(), String> {
+    match schema {
+        Some(schema) => check_schema([(language, &parse_bundle(language, json)?)], schema),
+        None => Ok(()),
+    }
+}
+
+/// Merges `source` into `target`, recursing into objects present in both.
+fn deep_merge(target: &mut Value, source: Value) {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(&key) {
+                    Some(existing) => deep_merge(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, source) => *target = source,
+    }
+}
+
+/// Collects the keys of a JSON object whose value is not an empty string.
+fn translated_keys(json: &Value) -> BTreeSet<String> {
+    collect_keys(json)
+        .into_iter()
+        .filter(|key| {
+            let path: Vec<&str> = key.split('.').collect();
+            I18n::get_nested_value(json, &path).is_some_and(|value| value != "")
+        })
+        .collect()
+}
+
+/// Collects the dot-separated paths of every leaf value of a JSON object.
+fn collect_keys(json: &Value) -> BTreeSet<String> {
+    fn walk(prefix: &str, json: &Value, out: &mut BTreeSet<String>) {
+        match json {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    walk(&path, value, out);
+                }
+            }
+            _ => {
+                out.insert(prefix.to_string());
+            }
+        }
+    }
+
+    let mut keys = BTreeSet::new();
+    walk("", json, &mut keys);
+    keys
+}
+
 /// Configuration for the I18n module, specifying supported translations.
 #[derive(Debug, Clone, PartialEq)]
 pub struct I18nConfig {
@@ -11,6 +682,87 @@ pub struct I18nConfig {
     pub translations: HashMap<&'static str, &'static str>,
 }
 
+/// The writing direction of a language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextDirection {
+    /// Left-to-right, e.g. English or French.
+    #[default]
+    Ltr,
+    /// Right-to-left, e.g. Arabic or Hebrew.
+    Rtl,
+}
+
+impl TextDirection {
+    /// Primary language subtags written right-to-left.
+    const RTL_LANGUAGES: &'static [&'static str] = &["ar", "he", "fa", "ur", "ps", "ku", "sd"];
+
+    /// Determines the writing direction of a language code.
+    ///
+    /// Only the primary subtag is considered, so `"ar-EG"` and `"ar_EG"` are both RTL.
+    ///
+    /// # Arguments
+    /// - `language`: The language code (e.g., `"ar"`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::TextDirection;
+    ///
+    /// assert_eq!(TextDirection::for_language("he-IL"), TextDirection::Rtl);
+    /// assert_eq!(TextDirection::for_language("en"), TextDirection::Ltr);
+    /// ```
+    pub fn for_language(language: &str) -> Self {
+        let primary = Self::primary_subtag(language);
+        if Self::RTL_LANGUAGES
+            .iter()
+            .any(|rtl| rtl.eq_ignore_ascii_case(primary))
+        {
+            Self::Rtl
+        } else {
+            Self::Ltr
+        }
+    }
+
+    /// Determines the writing direction of a language code, honoring overrides.
+    ///
+    /// Overrides are looked up by the full language code first, then by its primary
+    /// subtag, before falling back to `for_language`. This lets apps mark additional
+    /// locales as RTL (e.g. `"dv"`, `"yi"`) or force a built-in one to LTR.
+    ///
+    /// # Arguments
+    /// - `language`: The language code (e.g., `"dv-MV"`).
+    /// - `overrides`: Language codes mapped to the direction to use for them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::TextDirection;
+    /// use std::collections::HashMap;
+    ///
+    /// let overrides = HashMap::from([("dv", TextDirection::Rtl)]);
+    /// assert_eq!(TextDirection::resolve("dv-MV", &overrides), TextDirection::Rtl);
+    /// assert_eq!(TextDirection::resolve("ar", &overrides), TextDirection::Rtl);
+    /// ```
+    pub fn resolve(language: &str, overrides: &HashMap<&'static str, TextDirection>) -> Self {
+        overrides
+            .get(language)
+            .or_else(|| overrides.get(Self::primary_subtag(language)))
+            .copied()
+            .unwrap_or_else(|| Self::for_language(language))
+    }
+
+    /// Returns the primary subtag of a language code (e.g. `"ar"` for `"ar-EG"`).
+    fn primary_subtag(language: &str) -> &str {
+        language.split(['-', '_']).next().unwrap_or_default()
+    }
+
+    /// Returns the value of the HTML `dir` attribute (`"ltr"` or `"rtl"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+}
+
 /// Enum representing browser storage options for persisting the selected language.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum StorageType {
@@ -19,6 +771,14 @@ pub enum StorageType {
     LocalStorage,
     /// Use the browser's `SessionStorage` for persisting data.
     SessionStorage,
+    /// Use a cookie named after the storage name, which is also sent to the server so it
+    /// can render in the selected language; see `Cookie::language` for its attributes.
+    Cookie,
+    /// Keep the language only in a query parameter of the page URL, named after the
+    /// storage name (e.g., `?i18nrs=fr`), updated through the History API.
+    ///
+    /// Suits embedded widgets that must not touch cookies or web storage.
+    UrlQuery,
 }
 
 /// This struct represents the state and methods for managing internationalization.
@@ -28,9 +788,44 @@ pub struct I18n {
     pub config: I18nConfig,
     /// The current language code being used for translations.
     current_language: String,
+    /// The language that missing keys fall back to, resolved once at construction and
+    /// changed only by `set_default_language` and `set_supported_languages`.
+    default_language: Option<String>,
     /// Translations loaded for each supported language, represented as a mapping from
     /// language codes to JSON structures (`serde_json::Value`).
-    translations: HashMap<String, Value>,
+    ///
+    /// Shared between clones, so cloning an instance to switch languages doesn't copy the
+    /// catalog; modifications copy it on write.
+    translations: Arc<HashMap<String, Value>>,
+    /// Leaf translations of each language keyed by their dot-separated path (e.g.,
+    /// `"nav.home"`), so lookups don't walk the JSON tree. Shared like `translations`.
+    flat: Arc<FlatIndex>,
+    /// Languages dropped by `unload_language`, parsed again when selected.
+    unloaded: HashSet<String>,
+    /// The selectable languages in order, when restricted by `set_supported_languages`.
+    supported: Option<Vec<String>>,
+    /// Translation layers resolved before the catalog, lowest precedence first.
+    layers: Arc<Vec<Layer>>,
+    /// Override catalogs registered with `add_tenant`, by tenant name.
+    tenants: Arc<HashMap<String, Layer>>,
+    /// The tenant selected with `set_tenant`, resolved between the layers and the catalog.
+    tenant: Option<String>,
+    /// Per-language overrides of the writing direction.
+    directions: HashMap<&'static str, TextDirection>,
+    /// Whether `t` returns the keys themselves instead of their translations.
+    show_keys: bool,
+    /// Whether missing keys panic in debug builds.
+    strict: bool,
+    /// The name of the cookie holding the selected language on the server.
+    cookie_name: String,
+    /// Invoked when a key is missing for the current language.
+    missing_key_handler: Option<MissingKeyHandler>,
+    /// Conflicting keys found while merging, when conflict detection is enabled.
+    conflicts: Option<Vec<KeyConflict>>,
+    /// Resolution counts of keys, when usage tracking is enabled.
+    usage: Option<KeyUsage>,
+    /// Parsed messages, so `t_with` doesn't re-parse the same template on every render.
+    messages: MessageCache,
 }
 
 impl I18n {
@@ -45,81 +840,403 @@ impl I18n {
     /// - `Err(String)` if there is an error, such as missing translations or invalid JSON.
     pub fn new(config: I18nConfig, translations: HashMap<&str, &str>) -> Result<Self, String> {
         let translations = Self::load_translations(translations)?;
-
-        let languages: Vec<&str> = translations
-            .keys()
-            .map(|arg: &String| String::as_str(arg))
-            .collect();
-
-        let current_language = languages
-            .first()
-            .cloned()
-            .ok_or_else(|| "You must add at least one supported language".to_string())?;
-
-        Ok(I18n {
-            config,
-            current_language: current_language.to_string(),
-            translations,
-        })
+        Self::from_values(config, translations)
     }
 
-    /// Loads translations for the given languages from a `HashMap` of raw JSON strings.
+    /// Initializes an `I18n` instance like `new`, validating every language against a
+    /// JSON Schema first.
+    ///
+    /// Catches structural drift between locale files, such as a string turned into an
+    /// object or a key nested at the wrong level, with the path of each violation. The
+    /// schema can be written by hand or generated from the base locale with
+    /// `schema::from_locale`.
     ///
     /// # Arguments
+    /// - `config`: The `I18nConfig` containing supported translations map.
     /// - `translations`: A `HashMap` containing language codes as keys and JSON strings as values.
+    /// - `schema`: The JSON Schema every language must satisfy; see `schema` for the
+    ///   supported keywords.
     ///
     /// # Returns
-    /// - `Ok(HashMap<String, Value>)` if all translations are valid.
-    /// - `Err(String)` if any translation is missing or invalid.
-    fn load_translations(
+    /// - `Ok(I18n)` if every language is valid.
+    /// - `Err(String)` listing the violations of each invalid language, or any error of `new`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, schema};
+    /// use std::collections::HashMap;
+    ///
+    /// let en = r#"{"nav": {"home": "Home"}}"#;
+    /// let schema = schema::from_locale(&serde_json::from_str(en).unwrap());
+    ///
+    /// let translations = HashMap::from([("en", en), ("fr", r#"{"nav": "Accueil"}"#)]);
+    /// let err = I18n::with_schema(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    ///     &schema,
+    /// )
+    /// .err()
+    /// .unwrap();
+    /// assert_eq!(
+    ///     err,
+    ///     "Invalid structure for language fr: /nav: expected object, found string"
+    /// );
+    /// ```
+    pub fn with_schema(
+        config: I18nConfig,
         translations: HashMap<&str, &str>,
-    ) -> Result<HashMap<String, Value>, String> {
-        let mut loaded_translations = HashMap::new();
-        let languages: Vec<&str> = translations.keys().copied().collect();
-
-        for language in &languages {
-            if let Some(json_str) = translations.get(language) {
-                let json: Value = serde_json::from_str(json_str)
-                    .map_err(|err| format!("Invalid JSON for language {}: {}", language, err))?;
-                loaded_translations.insert(language.to_string(), json);
-            } else {
-                return Err(format!("Translation data for '{}' not found", language));
-            }
-        }
+        schema: &Value,
+    ) -> Result<Self, String> {
+        let translations = Self::load_translations(translations)?;
+        check_schema(
+            translations
+                .iter()
+                .map(|(language, json)| (language.as_str(), json)),
+            schema,
+        )?;
 
-        Ok(loaded_translations)
+        Self::from_values(config, translations)
     }
 
-    /// Sets the translation language and stores it in the browser's storage.
+    /// Validates loaded languages against a JSON Schema, like `with_schema`.
     ///
     /// # Arguments
-    /// - `language`: The language code to set (e.g., `"en"`).
-    /// - `storage_type`: The type of browser storage to use (`StorageType::LocalStorage` or `StorageType::SessionStorage`).
-    /// - `storage_name`: The key to use for storing the selected language.
+    /// - `languages`: The language codes to validate; languages that aren't loaded are
+    ///   skipped.
+    /// - `schema`: The JSON Schema every language must satisfy.
     ///
     /// # Returns
-    /// - `Ok(())` if the language was successfully set.
-    /// - `Err(String)` if the language is not supported or storage fails.
-    pub fn set_translation_language(
-        &mut self,
-        language: &str,
-        _storage_type: &StorageType,
-        _storage_name: &str,
-    ) -> Result<(), String> {
-        let languages: Vec<&str> = self
-            .translations
-            .keys()
-            .map(|arg: &String| arg.as_str())
-            .collect();
+    /// - `Ok(())` if every language is valid.
+    /// - `Err(String)` listing the violations of each invalid language.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, schema};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"title": "Shop"}"#), ("fr", r#"{"titre": "Boutique"}"#)]);
+    /// let i18n = I18n::new(I18nConfig { translations: translations.clone() }, translations).unwrap();
+    /// let schema = schema::from_locale(&serde_json::json!({"title": "Shop"}));
+    ///
+    /// assert!(i18n.validate_schema(&["en"], &schema).is_ok());
+    /// assert_eq!(
+    ///     i18n.validate_schema(&["en", "fr"], &schema).unwrap_err(),
+    ///     "Invalid structure for language fr: /titre: unexpected key"
+    /// );
+    /// ```
+    pub fn validate_schema(&self, languages: &[&str], schema: &Value) -> Result<(), String> {
+        check_schema(
+            languages.iter().filter_map(|language| {
+                self.translations
+                    .get(*language)
+                    .map(|json| (*language, json))
+            }),
+            schema,
+        )
+    }
 
-        if !languages.contains(&language) {
-            return Err(format!("Language '{}' is not supported", language));
-        }
+    /// Initializes an `I18n` instance from a compact binary (MessagePack) bundle.
+    ///
+    /// Decoding the binary bundle skips JSON parsing at startup, which matters for large
+    /// catalogs on low-end devices. Bundles are produced with `I18n::to_binary`, typically
+    /// at build time. Requires the `binary` feature.
+    ///
+    /// # Arguments
+    /// - `bytes`: The binary bundle containing every language's translations.
+    ///
+    /// # Returns
+    /// - `Ok(I18n)` if the bundle is valid and contains at least one language.
+    /// - `Err(String)` if the bundle cannot be decoded.
+    #[cfg(feature = "binary")]
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, String> {
+        let translations: HashMap<String, Value> = rmp_serde::from_slice(bytes)
+            .map_err(|err| format!("Invalid binary translation bundle: {}", err))?;
 
-        self.current_language = language.to_string();
+        Self::from_values(
+            I18nConfig {
+                translations: HashMap::new(),
+            },
+            translations,
+        )
+    }
 
-        #[cfg(target_arch = "wasm32")]
-        {
+    /// Encodes every loaded language into a compact binary (MessagePack) bundle.
+    ///
+    /// The result can be embedded with `include_bytes!` and loaded with `I18n::from_binary`.
+    /// Requires the `binary` feature.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)` containing the encoded bundle.
+    /// - `Err(String)` if encoding fails.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"greeting": "Hello"}"#)]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// let bytes = i18n.to_binary().unwrap();
+    /// let restored = I18n::from_binary(&bytes).unwrap();
+    /// assert_eq!(restored.t("greeting"), "Hello");
+    /// ```
+    #[cfg(feature = "binary")]
+    pub fn to_binary(&self) -> Result<Vec<u8>, String> {
+        rmp_serde::to_vec(&*self.translations)
+            .map_err(|err| format!("Failed to encode binary translation bundle: {}", err))
+    }
+
+    /// Initializes an `I18n` instance from translations precompiled by `i18nrs-build`.
+    ///
+    /// The static structure holds language codes mapped to flattened `(key, value)` pairs,
+    /// so no JSON parsing happens at runtime.
+    ///
+    /// # Arguments
+    /// - `translations`: The `TRANSLATIONS` static emitted by `i18nrs_build::compile_dir`.
+    ///
+    /// # Returns
+    /// - `Ok(I18n)` if at least one language is present.
+    /// - `Err(String)` if the structure is empty, or if a key is defined twice or is both a
+    ///   translation and the prefix of other keys (e.g. `"menu"` and `"menu.open"`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::I18n;
+    ///
+    /// static TRANSLATIONS: &[(&str, &[(&str, &str)])] = &[
+    ///     ("en", &[("menu.file.open", "Open")]),
+    /// ];
+    ///
+    /// let i18n = I18n::from_static(TRANSLATIONS).unwrap();
+    /// assert_eq!(i18n.t("menu.file.open"), "Open");
+    /// ```
+    pub fn from_static(translations: StaticTranslations) -> Result<Self, String> {
+        let mut languages = HashMap::new();
+        for (language, entries) in translations.iter() {
+            let mut json = Value::Object(Default::default());
+            for (key, value) in entries.iter() {
+                let mut current = &mut json;
+                let mut walked = 0;
+                for segment in key.split('.') {
+                    if current.is_null() {
+                        *current = Value::Object(Default::default());
+                    }
+                    let Value::Object(object) = current else {
+                        return Err(format!(
+                            "Key '{}' is both a translation and a prefix of '{}' in language '{}'",
+                            &key[..walked - 1],
+                            key,
+                            language
+                        ));
+                    };
+                    current = object.entry(segment).or_insert(Value::Null);
+                    walked += segment.len() + 1;
+                }
+                match current {
+                    Value::Null => *current = Value::String(value.to_string()),
+                    Value::Object(_) => {
+                        return Err(format!(
+                            "Key '{}' is both a translation and a prefix of other keys in language '{}'",
+                            key, language
+                        ));
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Key '{}' is defined twice in language '{}'",
+                            key, language
+                        ));
+                    }
+                }
+            }
+            languages.insert(language.to_string(), json);
+        }
+
+        Self::from_values(
+            I18nConfig {
+                translations: HashMap::new(),
+            },
+            languages,
+        )
+    }
+
+    /// Initializes an `I18n` instance from a locale directory read at runtime.
+    ///
+    /// Desktop and server apps can ship locale files next to the binary instead of
+    /// embedding them with `include_str!`. The directory is read like
+    /// `i18nrs_build::load_dir` reads it at build time: each language is a
+    /// `<dir>/<language>.json` bundle or a `<dir>/<language>/` directory whose `base.json`
+    /// is merged at the root and whose other files are nested under their file stem
+    /// (e.g., `checkout.json` → `"checkout.title"`). Not available on wasm.
+    ///
+    /// # Arguments
+    /// - `dir`: The locale directory (e.g., `"locales"`).
+    ///
+    /// # Returns
+    /// - `Ok(I18n)` with the translations of each language found.
+    /// - `Err(String)` if the directory can't be read, a file is not a JSON object, or no
+    ///   language was found.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::I18n;
+    /// use std::fs;
+    ///
+    /// let dir = std::env::temp_dir().join("i18nrs-from-dir-doctest");
+    /// fs::create_dir_all(dir.join("de")).unwrap();
+    /// fs::write(dir.join("de").join("base.json"), r#"{"greeting": "Hallo"}"#).unwrap();
+    /// fs::write(dir.join("de").join("checkout.json"), r#"{"title": "Kasse"}"#).unwrap();
+    ///
+    /// let i18n = I18n::from_dir(&dir).unwrap();
+    /// assert_eq!(i18n.t("greeting"), "Hallo");
+    /// assert_eq!(i18n.t("checkout.title"), "Kasse");
+    /// # fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_dir(dir: impl AsRef<std::path::Path>) -> Result<Self, String> {
+        let translations = i18nrs_build::load_dir(dir)?;
+
+        Self::from_values(
+            I18nConfig {
+                translations: HashMap::new(),
+            },
+            translations.into_iter().collect(),
+        )
+    }
+
+    /// Builds an `I18n` instance from already parsed translations.
+    fn from_values(
+        config: I18nConfig,
+        translations: HashMap<String, Value>,
+    ) -> Result<Self, String> {
+        // The alphabetically first language, rather than whichever one the `HashMap` yields
+        // first, so the fallback is the same on every run until it is set explicitly.
+        let default_language = translations
+            .keys()
+            .min()
+            .cloned()
+            .ok_or_else(|| "You must add at least one supported language".to_string())?;
+
+        let mut i18n = I18n {
+            current_language: default_language.clone(),
+            default_language: Some(default_language),
+            config,
+            translations: Arc::new(translations),
+            flat: Arc::default(),
+            layers: Arc::default(),
+            tenants: Arc::default(),
+            tenant: None,
+            unloaded: HashSet::new(),
+            supported: None,
+            directions: HashMap::new(),
+            show_keys: false,
+            strict: false,
+            cookie_name: crate::negotiate::DEFAULT_COOKIE_NAME.to_string(),
+            missing_key_handler: None,
+            conflicts: None,
+            usage: None,
+            messages: MessageCache::default(),
+        };
+        let languages: Vec<String> = i18n.translations.keys().cloned().collect();
+        for language in &languages {
+            i18n.reindex(language);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: "i18nrs",
+            languages = ?languages,
+            current = %i18n.current_language,
+            "catalog loaded"
+        );
+        Ok(i18n)
+    }
+
+    /// Creates an instance with a single language and no translations.
+    ///
+    /// Stands in for the real instance when a provider fails to initialize, so the
+    /// provider can render its error fallback instead of panicking.
+    #[cfg(any(feature = "yew", feature = "dio"))]
+    pub(crate) fn empty(language: &str) -> Self {
+        I18n {
+            config: I18nConfig {
+                translations: HashMap::new(),
+            },
+            current_language: language.to_string(),
+            default_language: Some(language.to_string()),
+            translations: Arc::new(HashMap::from([(
+                language.to_string(),
+                Value::Object(Default::default()),
+            )])),
+            flat: Arc::default(),
+            layers: Arc::default(),
+            tenants: Arc::default(),
+            tenant: None,
+            unloaded: HashSet::new(),
+            supported: None,
+            directions: HashMap::new(),
+            show_keys: false,
+            strict: false,
+            cookie_name: crate::negotiate::DEFAULT_COOKIE_NAME.to_string(),
+            missing_key_handler: None,
+            conflicts: None,
+            usage: None,
+            messages: MessageCache::default(),
+        }
+    }
+
+    /// Loads translations for the given languages from a `HashMap` of raw JSON strings.
+    ///
+    /// # Arguments
+    /// - `translations`: A `HashMap` containing language codes as keys and JSON strings as values.
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<String, Value>)` if all translations are valid.
+    /// - `Err(String)` if any translation is missing or invalid.
+    fn load_translations(
+        translations: HashMap<&str, &str>,
+    ) -> Result<HashMap<String, Value>, String> {
+        let mut loaded_translations = HashMap::new();
+        let languages: Vec<&str> = translations.keys().copied().collect();
+
+        for language in &languages {
+            if let Some(json_str) = translations.get(language) {
+                let json = parse_bundle(language, json_str)?;
+                loaded_translations.insert(language.to_string(), json);
+            } else {
+                return Err(format!("Translation data for '{}' not found", language));
+            }
+        }
+
+        Ok(loaded_translations)
+    }
+
+    /// Sets the translation language and stores it in the browser's storage.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to set (e.g., `"en"`).
+    /// - `storage_type`: The type of browser storage to use (`StorageType::LocalStorage`,
+    ///   `StorageType::SessionStorage`, `StorageType::Cookie` or `StorageType::UrlQuery`).
+    /// - `storage_name`: The key to use for storing the selected language, i.e. the cookie
+    ///   name or the query parameter name with `StorageType::Cookie` and
+    ///   `StorageType::UrlQuery`.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language was successfully set.
+    /// - `Err(String)` if the language is not supported or storage fails.
+    pub fn set_translation_language(
+        &mut self,
+        language: &str,
+        _storage_type: &StorageType,
+        _storage_name: &str,
+    ) -> Result<(), String> {
+        self.select_language(language)?;
+
+        #[cfg(target_arch = "wasm32")]
+        {
             let result = match _storage_type {
                 StorageType::LocalStorage => window()
                     .ok_or("No window available")?
@@ -133,6 +1250,14 @@ impl I18n {
                     .map_err(|_| "Failed to access sessionStorage".to_string())?
                     .ok_or("sessionStorage not available")?
                     .set_item(_storage_name, language),
+                StorageType::Cookie => {
+                    write_document_cookie(&Cookie::language(_storage_name, language))?;
+                    Ok(())
+                }
+                StorageType::UrlQuery => {
+                    crate::url::write_query_param(_storage_name, language);
+                    Ok(())
+                }
             };
 
             result.map_err(|_| {
@@ -141,6 +1266,8 @@ impl I18n {
                     match _storage_type {
                         StorageType::LocalStorage => "LocalStorage",
                         StorageType::SessionStorage => "SessionStorage",
+                        StorageType::Cookie => "a cookie",
+                        StorageType::UrlQuery => "the URL query",
                     }
                 )
             })?;
@@ -154,60 +1281,2549 @@ impl I18n {
         Ok(())
     }
 
-    /// Retrieves the current language code.
+    /// Switches the current language without touching browser storage.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to set (e.g., `"en"`).
     ///
     /// # Returns
-    /// - A reference to the current language code as a `&str`.
-    pub fn get_current_language(&self) -> &str {
-        &self.current_language
+    /// - `Ok(())` if the language was successfully set.
+    /// - `Err(String)` if the language is not supported.
+    pub(crate) fn select_language(&mut self, language: &str) -> Result<(), String> {
+        if !self.is_selectable(language) {
+            return Err(format!("Language '{}' is not supported", language));
+        }
+        if self.unloaded.contains(language) {
+            self.reload_language(language)?;
+        }
+        if !self.translations.contains_key(language) {
+            return Err(format!("Language '{}' is not supported", language));
+        }
+
+        #[cfg(feature = "tracing")]
+        if self.current_language != language {
+            tracing::info!(
+                target: "i18nrs",
+                from = %self.current_language,
+                to = language,
+                "language changed"
+            );
+        }
+        self.current_language = language.to_string();
+
+        Ok(())
     }
 
-    /// Translates a given key using the current language.
+    /// Drops the parsed translations of a language to free memory.
+    ///
+    /// The language stays supported: it is parsed again from its source in
+    /// `config.translations` when it is selected. Useful when many languages are compiled
+    /// in but only a few are used in a session, e.g. in memory-constrained webviews.
     ///
     /// # Arguments
-    /// - `key`: The translation key to retrieve (e.g., `"menu.file.open"`).
+    /// - `language`: The language code to unload (e.g., `"de"`).
     ///
     /// # Returns
-    /// - The translated string if the key exists.
-    /// - A fallback message if the key or translation does not exist.
-    pub fn t(&self, key: &str) -> String {
-        let keys: Vec<&str> = key.split('.').collect();
-        let languages: Vec<&str> = self.config.translations.keys().copied().collect();
+    /// - `Ok(())` if the language was unloaded or was not loaded.
+    /// - `Err(String)` if the language is the current or the default language, which
+    ///   lookups depend on, or has no source to be restored from.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, StorageType};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hello"}"#),
+    ///     ("de", r#"{"greeting": "Hallo"}"#),
+    ///     ("fr", r#"{"greeting": "Bonjour"}"#),
+    /// ]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.set_translation_language("en", &StorageType::LocalStorage, "i18nrs").unwrap();
+    ///
+    /// // The current and the default ("de") language can't be unloaded.
+    /// assert!(i18n.unload_language("en").is_err());
+    /// assert!(i18n.unload_language("de").is_err());
+    ///
+    /// i18n.unload_language("fr").unwrap();
+    /// assert!(!i18n.is_loaded("fr"));
+    /// assert!(i18n.languages().contains(&"fr"));
+    ///
+    /// i18n.set_translation_language("fr", &StorageType::LocalStorage, "i18nrs").unwrap();
+    /// assert!(i18n.is_loaded("fr"));
+    /// ```
+    pub fn unload_language(&mut self, language: &str) -> Result<(), String> {
+        if language == self.current_language || Some(language) == self.default_language.as_deref() {
+            return Err(format!(
+                "Language '{}' is in use and cannot be unloaded",
+                language
+            ));
+        }
+        if !self.config.translations.contains_key(language) {
+            return Err(format!(
+                "Language '{}' has no source to be restored from",
+                language
+            ));
+        }
+        if Arc::make_mut(&mut self.translations)
+            .remove(language)
+            .is_some()
+        {
+            Arc::make_mut(&mut self.flat).remove(language);
+            self.messages.forget(Some(language));
+            self.unloaded.insert(language.to_string());
+        }
+        Ok(())
+    }
+
+    /// Checks whether the translations of a language are parsed and in memory.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to check (e.g., `"de"`).
+    pub fn is_loaded(&self, language: &str) -> bool {
+        self.translations.contains_key(language)
+    }
+
+    /// Loads a language ahead of time so switching to it later is instant.
+    ///
+    /// Parses an unloaded language again from its source in `config.translations`; a
+    /// language that is already loaded is left as is. Call it e.g. when the user opens or
+    /// hovers a language menu.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to preload (e.g., `"de"`).
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language is loaded.
+    /// - `Err(String)` if the language is not supported or its source is invalid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hello"}"#),
+    ///     ("de", r#"{"greeting": "Hallo"}"#),
+    ///     ("fr", r#"{"greeting": "Bonjour"}"#),
+    /// ]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.unload_language("fr").unwrap();
+    /// i18n.preload_language("fr").unwrap();
+    /// assert!(i18n.is_loaded("fr"));
+    /// assert!(i18n.preload_language("tlh").is_err());
+    /// ```
+    pub fn preload_language(&mut self, language: &str) -> Result<(), String> {
+        if self.unloaded.contains(language) {
+            return self.reload_language(language);
+        }
+        if !self.translations.contains_key(language) {
+            return Err(format!("Language '{}' is not supported", language));
+        }
+        Ok(())
+    }
 
-        let first_language = languages[0];
+    /// Parses an unloaded language again from its source in `config.translations`.
+    fn reload_language(&mut self, language: &str) -> Result<(), String> {
+        let json = self
+            .config
+            .translations
+            .get(language)
+            .ok_or_else(|| format!("Language '{}' is not supported", language))?;
+        let json = parse_bundle(language, json)?;
+        self.add_language_value(language, json);
+        Ok(())
+    }
 
-        self.translations
-            .get(&self.current_language)
-            .and_then(|language_json| Self::get_nested_value(language_json, &keys))
+    /// Registers a language at runtime, or replaces the full bundle of an existing one.
+    ///
+    /// Lets plugins or user-generated content packs add locales after the instance was
+    /// created. The language becomes selectable right away, appended to the supported
+    /// languages if they are restricted; it has no source in `config.translations`, so it
+    /// can't be unloaded.
+    ///
+    /// # Arguments
+    /// - `language`: The language code the bundle belongs to (e.g., `"eo"`).
+    /// - `json`: The raw JSON content of the bundle.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the bundle was loaded.
+    /// - `Err(String)` if the JSON is invalid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, StorageType};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"greeting": "Hello"}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.add_language("eo", r#"{"greeting": "Saluton"}"#).unwrap();
+    /// i18n.set_translation_language("eo", &StorageType::LocalStorage, "i18nrs").unwrap();
+    /// assert_eq!(i18n.t("greeting"), "Saluton");
+    /// assert!(i18n.add_language("tlh", "{").is_err());
+    /// ```
+    pub fn add_language(&mut self, language: &str, json: &str) -> Result<(), String> {
+        let json = parse_bundle(language, json)?;
+        self.add_language_value(language, json);
+        Ok(())
+    }
+
+    /// Removes a language at runtime, the counterpart of `add_language`.
+    ///
+    /// If the language is the current one, the instance falls back to the default
+    /// language, so lookups keep working; compare `get_current_language` before and after
+    /// to detect the switch. The language is also dropped from `config.translations`, so
+    /// it can't be selected again until it is added back.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to remove (e.g., `"eo"`).
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language was removed.
+    /// - `Err(String)` if the language is not supported, or is the default language or
+    ///   the last language left, which lookups fall back to.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, StorageType};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"greeting": "Hello"}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.add_language("eo", r#"{"greeting": "Saluton"}"#).unwrap();
+    /// i18n.set_translation_language("eo", &StorageType::LocalStorage, "i18nrs").unwrap();
+    ///
+    /// i18n.remove_language("eo").unwrap();
+    /// assert_eq!(i18n.get_current_language(), "en");
+    /// assert_eq!(i18n.t("greeting"), "Hello");
+    /// assert!(i18n.remove_language("en").is_err());
+    /// ```
+    pub fn remove_language(&mut self, language: &str) -> Result<(), String> {
+        if !self.translations.contains_key(language) && !self.unloaded.contains(language) {
+            return Err(format!("Language '{}' is not supported", language));
+        }
+        let fallback = self
+            .default_language
+            .clone()
             .or_else(|| {
-                self.translations
-                    .get(first_language)
-                    .and_then(|default_json| Self::get_nested_value(default_json, &keys))
+                self.languages()
+                    .into_iter()
+                    .find(|other| *other != language)
+                    .map(str::to_string)
             })
-            .map_or_else(
-                || {
-                    format!(
-                        "Key '{}' not found for language '{}'",
-                        key, self.current_language
-                    )
-                },
-                |value| match value {
-                    Value::String(s) => s.clone(),
-                    _ => value.to_string(),
-                },
+            .filter(|fallback| fallback != language)
+            .ok_or_else(|| {
+                format!(
+                    "Language '{}' is the fallback language and cannot be removed",
+                    language
+                )
+            })?;
+
+        if language == self.current_language {
+            self.select_language(&fallback)?;
+        }
+        Arc::make_mut(&mut self.translations).remove(language);
+        Arc::make_mut(&mut self.flat).remove(language);
+        self.messages.forget(Some(language));
+        self.unloaded.remove(language);
+        self.config.translations.remove(language);
+        if let Some(supported) = &mut self.supported {
+            supported.retain(|other| other != language);
+        }
+        Ok(())
+    }
+
+    /// Registers a language from an already parsed bundle.
+    pub(crate) fn add_language_value(&mut self, language: &str, json: Value) {
+        let is_new = !self.translations.contains_key(language) && !self.unloaded.remove(language);
+        Arc::make_mut(&mut self.translations).insert(language.to_string(), json);
+        self.reindex(language);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(target: "i18nrs", language, is_new, "language loaded");
+        if let Some(supported) = self.supported.as_mut().filter(|_| is_new) {
+            supported.push(language.to_string());
+        }
+    }
+
+    /// Inserts a namespace bundle into an already loaded language.
+    ///
+    /// The bundle is placed under the namespace key, so `t("checkout.title")` resolves
+    /// `"title"` from the inserted JSON. Dot-separated namespaces create nested objects.
+    ///
+    /// # Arguments
+    /// - `language`: The language code the bundle belongs to (e.g., `"en"`).
+    /// - `namespace`: The namespace to insert the bundle under (e.g., `"checkout"`).
+    /// - `json`: The raw JSON content of the namespace.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the bundle was inserted.
+    /// - `Err(String)` if the language is not loaded or the JSON is invalid.
+    pub fn add_namespace(
+        &mut self,
+        language: &str,
+        namespace: &str,
+        json: &str,
+    ) -> Result<(), String> {
+        let bundle: Value = serde_json::from_str(json).map_err(|err| {
+            format!(
+                "Invalid JSON for namespace '{}' in language {}: {}",
+                namespace, language, err
             )
+        })?;
+
+        let mut current = Arc::make_mut(&mut self.translations)
+            .get_mut(language)
+            .ok_or_else(|| format!("Language '{}' is not supported", language))?;
+
+        for segment in namespace.split('.') {
+            if !current.is_object() {
+                *current = Value::Object(Default::default());
+            }
+            current = current
+                .as_object_mut()
+                .map(|object| object.entry(segment).or_insert(Value::Null))
+                .ok_or_else(|| format!("Namespace '{}' could not be created", namespace))?;
+        }
+
+        Self::record_conflicts(
+            &mut self.conflicts,
+            language,
+            &format!("namespace '{}'", namespace),
+            namespace,
+            current,
+            &bundle,
+        );
+        *current = bundle;
+        self.reindex(language);
+
+        Ok(())
     }
 
-    /// Retrieves a nested value from a JSON object using a sequence of keys.
+    /// Deep-merges translations over the loaded catalog of a language.
+    ///
+    /// Objects are merged key by key and any other value replaces the existing one, so
+    /// feature flags, A/B tests or white-label customers can override a few strings without
+    /// shipping a full bundle. Overrides are lost when the language is unloaded.
     ///
     /// # Arguments
-    /// - `json`: The root `serde_json::Value` object to search within.
-    /// - `keys`: A slice of keys representing the path to the desired value.
+    /// - `language`: The language code to override strings in (e.g., `"en"`).
+    /// - `json`: The raw JSON content of the overrides.
     ///
     /// # Returns
-    /// - `Some(&Value)` if the value exists at the specified path.
-    /// - `None` if the path does not exist.
-    fn get_nested_value<'a>(json: &'a Value, keys: &[&str]) -> Option<&'a Value> {
-        keys.iter().try_fold(json, |current, key| current.get(key))
+    /// - `Ok(())` if the overrides were merged.
+    /// - `Err(String)` if the language is not supported or the JSON is invalid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([(
+    ///     "en",
+    ///     r#"{"cart": {"title": "Cart", "checkout": "Checkout"}}"#,
+    /// )]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.merge("en", r#"{"cart": {"title": "Basket"}}"#).unwrap();
+    /// assert_eq!(i18n.t("cart.title"), "Basket");
+    /// assert_eq!(i18n.t("cart.checkout"), "Checkout");
+    /// ```
+    pub fn merge(&mut self, language: &str, json: &str) -> Result<(), String> {
+        let overrides = parse_bundle(language, json)?;
+        self.preload_language(language)?;
+
+        let target = Arc::make_mut(&mut self.translations)
+            .get_mut(language)
+            .ok_or_else(|| format!("Language '{}' is not supported", language))?;
+        Self::record_conflicts(
+            &mut self.conflicts,
+            language,
+            "merge",
+            "",
+            target,
+            &overrides,
+        );
+        deep_merge(target, overrides);
+        self.reindex(language);
+
+        Ok(())
+    }
+
+    /// Adds or replaces a named layer of translations resolved before the catalog.
+    ///
+    /// Layers form an ordered stack, e.g. tenant overrides above the base bundle and user
+    /// overrides above those: a key resolves from the topmost layer that has it, then from
+    /// the catalog, so apps don't have to pre-merge JSON and can swap one layer without
+    /// touching the others. A new layer goes on top; replacing a layer keeps its position.
+    /// Layers override leaf strings and may add keys, but not whole objects.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the layer (e.g., `"tenant"`).
+    /// - `translations`: The layer's JSON content by language code; languages without an
+    ///   entry resolve from the layers below.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the layer was set.
+    /// - `Err(String)` if the JSON of a language is invalid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"brand": "Acme", "cart": "Cart"}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.set_layer("tenant", HashMap::from([("en", r#"{"brand": "Globex", "cart": "Basket"}"#)]))
+    ///     .unwrap();
+    /// i18n.set_layer("user", HashMap::from([("en", r#"{"cart": "My basket"}"#)]))
+    ///     .unwrap();
+    /// assert_eq!(i18n.t("brand"), "Globex");
+    /// assert_eq!(i18n.t("cart"), "My basket");
+    ///
+    /// i18n.set_layer("tenant", HashMap::from([("en", r#"{"brand": "Initech"}"#)]))
+    ///     .unwrap();
+    /// assert_eq!(i18n.t("brand"), "Initech");
+    /// assert_eq!(i18n.t("cart"), "My basket");
+    ///
+    /// assert!(i18n.remove_layer("user"));
+    /// assert_eq!(i18n.t("cart"), "Cart");
+    /// assert_eq!(i18n.layers(), ["tenant"]);
+    /// ```
+    pub fn set_layer(
+        &mut self,
+        name: &str,
+        translations: HashMap<&str, &str>,
+    ) -> Result<(), String> {
+        let layer = Layer::parse(name, translations)?;
+        if self.conflicts.is_some() {
+            let source = format!("layer '{}'", name);
+            for existing in self.layers.iter().filter(|existing| existing.name != name) {
+                for (language, json) in &layer.translations {
+                    if let Some(previous) = existing.translations.get(language) {
+                        Self::record_conflicts(
+                            &mut self.conflicts,
+                            language,
+                            &source,
+                            "",
+                            previous,
+                            json,
+                        );
+                    }
+                }
+            }
+        }
+        let layers = Arc::make_mut(&mut self.layers);
+        match layers.iter_mut().find(|existing| existing.name == name) {
+            Some(existing) => *existing = layer,
+            None => layers.push(layer),
+        }
+        self.messages.forget(None);
+        Ok(())
+    }
+
+    /// Removes a layer set with `set_layer`.
+    ///
+    /// # Returns
+    /// - `true` if the layer existed, `false` otherwise.
+    pub fn remove_layer(&mut self, name: &str) -> bool {
+        let layers = Arc::make_mut(&mut self.layers);
+        let count = layers.len();
+        layers.retain(|layer| layer.name != name);
+        self.messages.forget(None);
+        layers.len() != count
+    }
+
+    /// Retrieves the names of the layers, lowest precedence first.
+    pub fn layers(&self) -> Vec<&str> {
+        self.layers
+            .iter()
+            .map(|layer| layer.name.as_str())
+            .collect()
+    }
+
+    /// Registers the override catalog of a tenant, or replaces an existing one.
+    ///
+    /// White-label products serving many brands from one bundle keep the shared strings in
+    /// the catalog and register each brand's overrides as a tenant; `set_tenant` then
+    /// selects the brand at runtime. The selected tenant resolves above the catalog and
+    /// below the layers of `set_layer`, and overrides leaf strings like a layer.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the tenant (e.g., `"acme"`).
+    /// - `translations`: The tenant's JSON overrides by language code; keys and languages
+    ///   without an override resolve from the shared catalog.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the tenant was registered.
+    /// - `Err(String)` if the JSON of a language is invalid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"brand": "Shop", "cart": "Cart"}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.add_tenant("acme", HashMap::from([("en", r#"{"brand": "Acme"}"#)]))
+    ///     .unwrap();
+    /// i18n.add_tenant("globex", HashMap::from([("en", r#"{"brand": "Globex", "cart": "Basket"}"#)]))
+    ///     .unwrap();
+    /// assert_eq!(i18n.tenants(), ["acme", "globex"]);
+    ///
+    /// i18n.set_tenant("acme").unwrap();
+    /// assert_eq!(i18n.t("brand"), "Acme");
+    /// assert_eq!(i18n.t("cart"), "Cart");
+    ///
+    /// i18n.set_tenant("globex").unwrap();
+    /// assert_eq!(i18n.t("cart"), "Basket");
+    /// assert!(i18n.set_tenant("initech").is_err());
+    ///
+    /// i18n.clear_tenant();
+    /// assert_eq!(i18n.t("brand"), "Shop");
+    /// ```
+    pub fn add_tenant(
+        &mut self,
+        name: &str,
+        translations: HashMap<&str, &str>,
+    ) -> Result<(), String> {
+        let tenant = Layer::parse(name, translations)?;
+        Arc::make_mut(&mut self.tenants).insert(name.to_string(), tenant);
+        self.messages.forget(None);
+        Ok(())
+    }
+
+    /// Removes a tenant registered with `add_tenant`, clearing it if it was selected.
+    ///
+    /// # Returns
+    /// - `true` if the tenant existed, `false` otherwise.
+    pub fn remove_tenant(&mut self, name: &str) -> bool {
+        if self.tenant.as_deref() == Some(name) {
+            self.tenant = None;
+        }
+        self.messages.forget(None);
+        Arc::make_mut(&mut self.tenants).remove(name).is_some()
+    }
+
+    /// Selects the tenant whose overrides apply over the shared catalog.
+    ///
+    /// # Arguments
+    /// - `name`: The name of a tenant registered with `add_tenant`.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the tenant was selected.
+    /// - `Err(String)` if no such tenant is registered.
+    pub fn set_tenant(&mut self, name: &str) -> Result<(), String> {
+        if !self.tenants.contains_key(name) {
+            return Err(format!("Tenant '{}' is not registered", name));
+        }
+        self.tenant = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Deselects the current tenant, so lookups resolve from the shared catalog.
+    pub fn clear_tenant(&mut self) {
+        self.tenant = None;
+    }
+
+    /// Retrieves the selected tenant, if any.
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
+    }
+
+    /// Retrieves the names of the registered tenants, sorted.
+    pub fn tenants(&self) -> Vec<&str> {
+        let mut tenants: Vec<&str> = self.tenants.keys().map(String::as_str).collect();
+        tenants.sort_unstable();
+        tenants
+    }
+
+    /// The selected tenant followed by the layers, lowest precedence first.
+    fn overrides(&self) -> impl DoubleEndedIterator<Item = &Layer> {
+        self.tenant
+            .as_ref()
+            .and_then(|name| self.tenants.get(name))
+            .into_iter()
+            .chain(self.layers.iter())
+    }
+
+    /// Searches the keys of the current language by key or translation.
+    ///
+    /// Matches keys containing the query and keys whose translation, layers included,
+    /// contains it, ignoring case, e.g. to find the key of a string seen on screen.
+    ///
+    /// # Arguments
+    /// - `query`: The text to look for (e.g., `"Sign in"`).
+    ///
+    /// # Returns
+    /// - The matching keys, sorted alphabetically.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([(
+    ///     "en",
+    ///     r#"{"auth": {"login": "Sign in", "logout": "Sign out"}, "nav": {"home": "Home"}}"#,
+    /// )]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.search_keys("sign"), ["auth.login", "auth.logout"]);
+    /// assert_eq!(i18n.search_keys("nav."), ["nav.home"]);
+    /// ```
+    pub fn search_keys(&self, query: &str) -> Vec<String> {
+        let query = query.trim().to_lowercase();
+        let language = self.current_language.as_str();
+        let keys: BTreeSet<&str> = self
+            .overrides()
+            .map(|layer| &layer.index)
+            .chain(std::iter::once(&*self.flat))
+            .filter_map(|index| index.languages.get(language))
+            .flat_map(|keys| keys.keys().map(|key| &**key))
+            .collect();
+
+        keys.into_iter()
+            .filter(|key| {
+                key.to_lowercase().contains(&query)
+                    || self
+                        .flat_get(language, key)
+                        .is_some_and(|value| value.to_lowercase().contains(&query))
+            })
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Exports the active catalog of a language as JSON.
+    ///
+    /// The output is the catalog as lookups see it, with `merge` overrides, the selected
+    /// tenant and the layers applied, pretty-printed with sorted keys, so it can be backed
+    /// up, diffed, or loaded again as a bundle.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to export (e.g., `"en"`).
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the JSON of the catalog.
+    /// - `Err(String)` if the language is not supported.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"nav": {"home": "Home"}, "brand": "Acme"}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    /// i18n.merge("en", r#"{"nav": {"about": "About"}}"#).unwrap();
+    /// i18n.set_layer("tenant", HashMap::from([("en", r#"{"brand": "Globex"}"#)]))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     i18n.export("en").unwrap(),
+    ///     r#"{
+    ///   "brand": "Globex",
+    ///   "nav": {
+    ///     "about": "About",
+    ///     "home": "Home"
+    ///   }
+    /// }"#
+    /// );
+    /// ```
+    pub fn export(&self, language: &str) -> Result<String, String> {
+        let mut json = match self.translations.get(language) {
+            Some(json) => json.clone(),
+            None if self.unloaded.contains(language) => {
+                let source = self.config.translations.get(language).copied();
+                parse_bundle(language, source.unwrap_or_default())?
+            }
+            None => return Err(format!("Language '{}' is not supported", language)),
+        };
+        for layer in self.overrides() {
+            if let Some(overrides) = layer.translations.get(language) {
+                deep_merge(&mut json, overrides.clone());
+            }
+        }
+
+        serde_json::to_string_pretty(&json)
+            .map_err(|err| format!("Failed to serialize '{}': {}", language, err))
+    }
+
+    /// Checks whether a namespace is present for the given language.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to check (e.g., `"en"`).
+    /// - `namespace`: The namespace to look for (e.g., `"checkout"`).
+    pub fn has_namespace(&self, language: &str, namespace: &str) -> bool {
+        let keys: Vec<&str> = namespace.split('.').collect();
+        self.translations
+            .get(language)
+            .and_then(|json| Self::get_nested_value(json, &keys))
+            .is_some()
+    }
+
+    /// Checks whether a key resolves in the current language or the default language.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to look up (e.g., `"menu.file.open"`).
+    pub fn has_key(&self, key: &str) -> bool {
+        let keys: Vec<&str> = key.split('.').collect();
+        let default_language = self.default_language.as_deref();
+        std::iter::once(self.current_language.as_str())
+            .chain(default_language)
+            .any(|language| {
+                self.flat_get(language, key).is_some()
+                    || self
+                        .translations
+                        .get(language)
+                        .and_then(|json| Self::get_nested_value(json, &keys))
+                        .is_some()
+            })
+    }
+
+    /// Retrieves the current language code.
+    ///
+    /// # Returns
+    /// - A reference to the current language code as a `&str`.
+    pub fn get_current_language(&self) -> &str {
+        &self.current_language
+    }
+
+    /// Retrieves the codes of the supported languages, unloaded ones included.
+    ///
+    /// # Returns
+    /// - The language codes in the order given to `set_supported_languages`, or sorted
+    ///   alphabetically if the languages are not restricted.
+    pub fn languages(&self) -> Vec<&str> {
+        if let Some(supported) = &self.supported {
+            return supported.iter().map(String::as_str).collect();
+        }
+        let mut languages: Vec<&str> = self
+            .translations
+            .keys()
+            .chain(&self.unloaded)
+            .map(String::as_str)
+            .collect();
+        languages.sort_unstable();
+        languages
+    }
+
+    /// Retrieves the languages a language picker can offer, with their metadata.
+    ///
+    /// Lists every bundle the instance can switch to, including remote, unloaded and
+    /// runtime-added ones, with the names, direction (honoring the overrides) and flag of
+    /// each, so a complete picker can be generated from the actual bundles.
+    ///
+    /// # Returns
+    /// - The languages in the same order as `languages`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("fr", "{}"), ("en", "{}")]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    /// i18n.add_language("ar", "{}").unwrap();
+    ///
+    /// let languages = i18n.available_languages();
+    /// let codes: Vec<&str> = languages.iter().map(|language| language.code.as_str()).collect();
+    /// assert_eq!(codes, ["ar", "en", "fr"]);
+    /// assert_eq!(languages[0].label(), "العربية");
+    /// assert_eq!(languages[2].flag.as_deref(), Some("🇫🇷"));
+    /// ```
+    pub fn available_languages(&self) -> Vec<LanguageInfo> {
+        self.languages()
+            .into_iter()
+            .map(|language| LanguageInfo {
+                direction: self.direction_of(language),
+                ..LanguageInfo::new(language)
+            })
+            .collect()
+    }
+
+    /// Restricts and orders the languages that can be selected.
+    ///
+    /// Bundles left out of the list stay loaded but can't be selected, and pickers built
+    /// from `languages` or `available_languages` list the languages in the given order
+    /// instead of alphabetically. The first language becomes the fallback for missing keys
+    /// and removed languages, like with `set_default_language`. The current language
+    /// switches to the first one if it is left out.
+    ///
+    /// # Arguments
+    /// - `languages`: The selectable language codes, by priority; empty lifts the
+    ///   restriction.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the languages were restricted.
+    /// - `Err(String)` if a listed language has no bundle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, StorageType};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hello", "farewell": "Goodbye"}"#),
+    ///     ("de", r#"{"greeting": "Hallo"}"#),
+    ///     ("fr", r#"{"greeting": "Bonjour"}"#),
+    /// ]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.set_supported_languages(&["en", "de"]).unwrap();
+    /// assert_eq!(i18n.languages(), ["en", "de"]);
+    /// assert!(i18n.set_translation_language("fr", &StorageType::LocalStorage, "i18nrs").is_err());
+    ///
+    /// i18n.set_translation_language("de", &StorageType::LocalStorage, "i18nrs").unwrap();
+    /// assert_eq!(i18n.t("farewell"), "Goodbye");
+    /// assert!(i18n.set_supported_languages(&["tlh"]).is_err());
+    /// ```
+    pub fn set_supported_languages(&mut self, languages: &[&str]) -> Result<(), String> {
+        let Some(first) = languages.first() else {
+            self.supported = None;
+            return Ok(());
+        };
+        if let Some(language) = languages.iter().find(|language| {
+            !self.translations.contains_key(**language) && !self.unloaded.contains(**language)
+        }) {
+            return Err(format!("Language '{}' is not supported", language));
+        }
+
+        let mut supported: Vec<String> = Vec::with_capacity(languages.len());
+        for language in languages {
+            if !supported.iter().any(|other| other == language) {
+                supported.push(language.to_string());
+            }
+        }
+        self.supported = Some(supported);
+        self.default_language = Some(first.to_string());
+        if !self.is_selectable(&self.current_language) {
+            self.select_language(first)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the language that missing keys and removed languages fall back to.
+    ///
+    /// Defaults to the first supported language if restricted with
+    /// `set_supported_languages`, and to the alphabetically first language otherwise.
+    ///
+    /// # Arguments
+    /// - `language`: The fallback language code (e.g., `"en"`).
+    ///
+    /// # Returns
+    /// - `Ok(())` if the fallback was set.
+    /// - `Err(String)` if the language has no bundle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, StorageType};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hello", "farewell": "Goodbye"}"#),
+    ///     ("de", r#"{"greeting": "Hallo"}"#),
+    ///     ("fr", r#"{"greeting": "Bonjour", "farewell": "Au revoir"}"#),
+    /// ]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(i18n.default_language(), Some("de"));
+    ///
+    /// i18n.set_default_language("fr").unwrap();
+    /// i18n.set_translation_language("de", &StorageType::LocalStorage, "i18nrs").unwrap();
+    /// assert_eq!(i18n.t("farewell"), "Au revoir");
+    /// assert!(i18n.set_default_language("tlh").is_err());
+    /// ```
+    pub fn set_default_language(&mut self, language: &str) -> Result<(), String> {
+        if !self.translations.contains_key(language) && !self.unloaded.contains(language) {
+            return Err(format!("Language '{}' is not supported", language));
+        }
+        self.default_language = Some(language.to_string());
+        Ok(())
+    }
+
+    /// Retrieves the language that missing keys fall back to.
+    pub fn default_language(&self) -> Option<&str> {
+        self.default_language.as_deref()
+    }
+
+    /// Checks whether a language is allowed by the supported languages, if restricted.
+    fn is_selectable(&self, language: &str) -> bool {
+        self.supported
+            .as_ref()
+            .is_none_or(|supported| supported.iter().any(|other| other == language))
+    }
+
+    /// Retrieves the writing direction of the current language.
+    ///
+    /// # Returns
+    /// - `TextDirection::Rtl` for right-to-left languages, `TextDirection::Ltr` otherwise.
+    pub fn direction(&self) -> TextDirection {
+        self.direction_of(&self.current_language)
+    }
+
+    /// Retrieves the writing direction of a language, honoring the overrides.
+    pub(crate) fn direction_of(&self, language: &str) -> TextDirection {
+        TextDirection::resolve(language, &self.directions)
+    }
+
+    /// Overrides the writing direction of specific languages.
+    ///
+    /// # Arguments
+    /// - `directions`: Language codes mapped to their direction; see `TextDirection::resolve`.
+    pub fn set_directions(&mut self, directions: HashMap<&'static str, TextDirection>) {
+        self.directions = directions;
+    }
+
+    /// Checks whether the current language is written right-to-left.
+    pub fn is_rtl(&self) -> bool {
+        self.direction() == TextDirection::Rtl
+    }
+
+    /// Enables or disables the raw-keys debug mode.
+    ///
+    /// While enabled, `t` and every helper built on it return the key itself, so QA and
+    /// translators can see which key drives which piece of UI.
+    ///
+    /// # Arguments
+    /// - `show_keys`: Whether to render keys instead of translations.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"menu": {"open": "Open"}}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.set_show_keys(true);
+    /// assert_eq!(i18n.t("menu.open"), "menu.open");
+    /// ```
+    pub fn set_show_keys(&mut self, show_keys: bool) {
+        self.show_keys = show_keys;
+    }
+
+    /// Enables or disables strict mode, which fails fast on missing keys.
+    ///
+    /// In debug builds, looking up a key missing for the current language panics, so
+    /// untranslated strings are caught during development instead of shipping. Release
+    /// builds ignore the setting and fall back gracefully, like `t` always does.
+    ///
+    /// # Arguments
+    /// - `strict`: Whether missing keys panic in debug builds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, StorageType};
+    /// use std::collections::HashMap;
+    /// use std::panic::{AssertUnwindSafe, catch_unwind};
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"menu": {"open": "Open", "close": "Close"}}"#),
+    ///     ("fr", r#"{"menu": {"open": "Ouvrir"}}"#),
+    /// ]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    /// i18n.set_translation_language("fr", &StorageType::LocalStorage, "i18nrs").unwrap();
+    /// i18n.set_strict(true);
+    ///
+    /// assert_eq!(i18n.t("menu.open"), "Ouvrir");
+    /// let missing = catch_unwind(AssertUnwindSafe(|| i18n.t("menu.close")));
+    /// assert_eq!(missing.is_err(), cfg!(debug_assertions));
+    /// ```
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Checks whether strict mode is enabled.
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets the name of the cookie server integrations read the selected language from.
+    ///
+    /// Should match the `storage_name` of the providers. Defaults to `"i18nrs"`.
+    ///
+    /// # Arguments
+    /// - `name`: The cookie name (e.g., `"lang"`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"greeting": "Hello"}"#)]);
+    /// let mut i18n = I18n::new(I18nConfig { translations: translations.clone() }, translations).unwrap();
+    /// assert_eq!(i18n.cookie_name(), "i18nrs");
+    ///
+    /// i18n.set_cookie_name("lang");
+    /// assert_eq!(i18n.cookie_name(), "lang");
+    /// ```
+    pub fn set_cookie_name(&mut self, name: &str) {
+        self.cookie_name = name.to_string();
+    }
+
+    /// Retrieves the name of the cookie holding the selected language.
+    pub fn cookie_name(&self) -> &str {
+        &self.cookie_name
+    }
+
+    /// Enables or disables the detection of conflicting keys.
+    ///
+    /// While enabled, `merge`, `add_namespace` and `set_layer` record every key they
+    /// redefine with a different value, instead of letting the last write win silently.
+    /// Keys redefined with the same value, and a layer replacing itself, are not conflicts.
+    /// Disabling the detection discards the conflicts not yet taken.
+    ///
+    /// # Arguments
+    /// - `detect`: Whether conflicting keys are recorded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"checkout": {"title": "Checkout"}}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    /// i18n.set_detect_conflicts(true);
+    ///
+    /// i18n.add_namespace("en", "checkout", r#"{"title": "Pay", "total": "Total"}"#)
+    ///     .unwrap();
+    /// let conflicts = i18n.take_conflicts();
+    /// assert_eq!(conflicts.len(), 1);
+    /// assert_eq!(conflicts[0].key, "checkout.title");
+    /// assert_eq!(
+    ///     conflicts[0].to_string(),
+    ///     "Key 'checkout.title' in 'en' is defined as 'Checkout' and redefined as 'Pay' by namespace 'checkout'"
+    /// );
+    /// assert!(i18n.take_conflicts().is_empty());
+    /// ```
+    pub fn set_detect_conflicts(&mut self, detect: bool) {
+        match (detect, &self.conflicts) {
+            (true, None) => self.conflicts = Some(Vec::new()),
+            (false, _) => self.conflicts = None,
+            _ => {}
+        }
+    }
+
+    /// Checks whether conflicting keys are detected.
+    pub fn detect_conflicts(&self) -> bool {
+        self.conflicts.is_some()
+    }
+
+    /// Retrieves the conflicting keys recorded and not yet taken, oldest first.
+    pub fn conflicts(&self) -> &[KeyConflict] {
+        self.conflicts.as_deref().unwrap_or_default()
+    }
+
+    /// Takes the conflicting keys recorded since the last call, oldest first.
+    pub fn take_conflicts(&mut self) -> Vec<KeyConflict> {
+        self.conflicts
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Records the keys `incoming` redefines in `existing`, found under `prefix`, when
+    /// detection is enabled.
+    fn record_conflicts(
+        conflicts: &mut Option<Vec<KeyConflict>>,
+        language: &str,
+        source: &str,
+        prefix: &str,
+        existing: &Value,
+        incoming: &Value,
+    ) {
+        let Some(conflicts) = conflicts else {
+            return;
+        };
+        let mut found = Vec::new();
+        conflicting_keys(existing, incoming, prefix, &mut found);
+        conflicts.extend(found.into_iter().map(|(key, previous, value)| KeyConflict {
+            language: language.to_string(),
+            key,
+            source: source.to_string(),
+            previous,
+            value,
+        }));
+    }
+
+    /// Installs a handler invoked whenever a key is missing for the current language.
+    ///
+    /// The handler runs even when the key is then resolved from the fallback language.
+    ///
+    /// # Arguments
+    /// - `handler`: The handler to install, or `None` to remove it.
+    pub fn set_missing_key_handler(&mut self, handler: Option<MissingKeyHandler>) {
+        self.missing_key_handler = handler;
+    }
+
+    /// Enables or disables tracking of which keys are resolved.
+    ///
+    /// Enabling starts a new session of counts shared by every clone made afterwards;
+    /// read them with `usage_report`.
+    ///
+    /// # Arguments
+    /// - `enabled`: Whether to track key usage.
+    pub fn set_track_usage(&mut self, enabled: bool) {
+        self.usage = enabled.then(KeyUsage::default);
+    }
+
+    /// Reports which keys were resolved since usage tracking was enabled.
+    ///
+    /// Keys that were never resolved are candidates for pruning from large catalogs.
+    ///
+    /// # Returns
+    /// - The used keys with their counts, and the loaded keys that were never used.
+    ///   Both are empty if usage tracking is disabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"greeting": "Hello!", "farewell": "Bye!"}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.set_track_usage(true);
+    /// i18n.t("greeting");
+    ///
+    /// let report = i18n.usage_report();
+    /// assert_eq!(report.used["greeting"], 1);
+    /// assert!(report.unused.contains("farewell"));
+    /// ```
+    pub fn usage_report(&self) -> UsageReport {
+        let Some(usage) = &self.usage else {
+            return UsageReport::default();
+        };
+
+        let used = usage.snapshot();
+        let unused = self
+            .translations
+            .values()
+            .flat_map(collect_keys)
+            .filter(|key| !used.contains_key(key))
+            .collect();
+
+        UsageReport { used, unused }
+    }
+
+    /// Computes how much of the catalog is translated in a language.
+    ///
+    /// The catalog is the union of the keys of every loaded language. Keys whose value is
+    /// an empty string count as untranslated.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to measure (e.g., `"fr"`).
+    ///
+    /// # Returns
+    /// - `Ok(Coverage)` with the translated and total key counts and the missing keys.
+    /// - `Err(String)` if the language is not loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hello!", "farewell": "Bye!"}"#),
+    ///     ("fr", r#"{"greeting": "Bonjour !"}"#),
+    /// ]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// let coverage = i18n.coverage("fr").unwrap();
+    /// assert_eq!(coverage.percent(), 50.0);
+    /// assert!(coverage.missing.contains("farewell"));
+    /// ```
+    pub fn coverage(&self, language: &str) -> Result<Coverage, String> {
+        let translated = self
+            .translations
+            .get(language)
+            .map(translated_keys)
+            .ok_or_else(|| format!("Language '{}' is not supported", language))?;
+        let all: BTreeSet<String> = self.translations.values().flat_map(collect_keys).collect();
+        let missing: BTreeSet<String> = all.difference(&translated).cloned().collect();
+
+        Ok(Coverage {
+            translated: all.len() - missing.len(),
+            total: all.len(),
+            missing,
+        })
+    }
+
+    /// Compares the keys of two languages.
+    ///
+    /// # Arguments
+    /// - `language_a`: The reference language (e.g., `"en"`).
+    /// - `language_b`: The language to compare against it (e.g., `"fr"`).
+    ///
+    /// # Returns
+    /// - `Ok(LocaleDiff)` with the keys missing from and extra in `language_b`.
+    /// - `Err(String)` if either language is not loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hello!", "farewell": "Bye!"}"#),
+    ///     ("fr", r#"{"greeting": "Bonjour !", "beta": "Bêta"}"#),
+    /// ]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// let diff = i18n.diff("en", "fr").unwrap();
+    /// assert!(diff.missing.contains("farewell"));
+    /// assert!(diff.extra.contains("beta"));
+    /// ```
+    pub fn diff(&self, language_a: &str, language_b: &str) -> Result<LocaleDiff, String> {
+        let keys = |language: &str| {
+            self.translations
+                .get(language)
+                .map(translated_keys)
+                .ok_or_else(|| format!("Language '{}' is not supported", language))
+        };
+        let a = keys(language_a)?;
+        let b = keys(language_b)?;
+
+        Ok(LocaleDiff {
+            missing: a.difference(&b).cloned().collect(),
+            extra: b.difference(&a).cloned().collect(),
+        })
+    }
+
+    /// Compares the placeholders of every loaded language with a base language.
+    ///
+    /// A translation that drops or renames a placeholder (`{name}` in English but `{nom}`
+    /// in French) only breaks interpolation for the users of that language, so run this at
+    /// load time or in tests. Keys missing or empty on either side are skipped; see `diff`
+    /// for those.
+    ///
+    /// # Arguments
+    /// - `base`: The reference language code (e.g., `"en"`).
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PlaceholderMismatch>)` with the mismatching keys, by language and key.
+    /// - `Err(String)` if the base language is not loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hi {name}!", "due": "Due {date, date, long}"}"#),
+    ///     ("fr", r#"{"greeting": "Salut {nom} !", "due": "Échéance {date, date, long}"}"#),
+    /// ]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// let mismatches = i18n.check_placeholders("en").unwrap();
+    /// assert_eq!(mismatches.len(), 1);
+    /// assert_eq!(
+    ///     mismatches[0].to_string(),
+    ///     "Placeholders of 'greeting' in 'fr' ({nom}) differ from the base language ({name})"
+    /// );
+    /// ```
+    pub fn check_placeholders(&self, base: &str) -> Result<Vec<PlaceholderMismatch>, String> {
+        let base_values = self
+            .flat
+            .languages
+            .get(base)
+            .ok_or_else(|| format!("Language '{}' is not supported", base))?;
+        let names = |value: &str| -> BTreeSet<String> {
+            Message::parse(value)
+                .placeholder_names()
+                .into_iter()
+                .map(str::to_string)
+                .collect()
+        };
+
+        let mut mismatches = Vec::new();
+        for (language, values) in &self.flat.languages {
+            if language == base {
+                continue;
+            }
+            for (key, value) in values {
+                let Some(base_value) = base_values.get(key) else {
+                    continue;
+                };
+                if value.is_empty() || base_value.is_empty() {
+                    continue;
+                }
+                let (expected, found) = (names(base_value), names(value));
+                if expected != found {
+                    mismatches.push(PlaceholderMismatch {
+                        language: language.clone(),
+                        key: key.to_string(),
+                        expected,
+                        found,
+                    });
+                }
+            }
+        }
+        mismatches.sort_by(|a, b| (&a.language, &a.key).cmp(&(&b.language, &b.key)));
+        Ok(mismatches)
+    }
+
+    /// Checks whether the raw-keys debug mode is enabled.
+    pub fn show_keys(&self) -> bool {
+        self.show_keys
+    }
+
+    /// Translates a given key using the current language.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to retrieve (e.g., `"menu.file.open"`).
+    ///
+    /// # Returns
+    /// - The translated string if the key exists.
+    /// - A fallback message if the key or translation does not exist.
+    pub fn t(&self, key: &str) -> String {
+        self.translate(&self.current_language, key)
+    }
+
+    /// Translates a given key using the current language, borrowing the translation.
+    ///
+    /// Behaves like `t`, but a key that resolves to a string is returned without copying
+    /// it, which saves an allocation per label when rendering many of them.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to retrieve (e.g., `"menu.file.open"`).
+    ///
+    /// # Returns
+    /// - `Cow::Borrowed` with the translation if the key exists.
+    /// - `Cow::Owned` with the fallback message if it does not, or with the key itself in
+    ///   raw-keys mode.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::borrow::Cow;
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"nav": {"home": "Home"}}"#)]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(matches!(i18n.t_cow("nav.home"), Cow::Borrowed("Home")));
+    /// ```
+    pub fn t_cow(&self, key: &str) -> Cow<'_, str> {
+        self.translate_cow(&self.current_language, key)
+    }
+
+    /// Translates a given key using the current language as a shared string.
+    ///
+    /// Translations are interned when loaded, so the returned `Arc<str>` points to the
+    /// catalog's own copy: components can keep or clone common labels without allocating.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to retrieve (e.g., `"menu.file.open"`).
+    ///
+    /// # Returns
+    /// - The interned translation if the key resolves in the current language.
+    /// - A new string with the translation of the default language or the fallback
+    ///   message otherwise, like `t`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    /// use std::sync::Arc;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"ok": "OK", "dialog": {"confirm": "OK"}}"#)]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(Arc::ptr_eq(&i18n.t_shared("ok"), &i18n.t_shared("dialog.confirm")));
+    /// ```
+    pub fn t_shared(&self, key: &str) -> Arc<str> {
+        match self.flat_get(&self.current_language, key) {
+            Some(value) if !self.show_keys => {
+                if let Some(usage) = &self.usage {
+                    usage.record(key);
+                }
+                value.clone()
+            }
+            _ => Arc::from(self.t(key)),
+        }
+    }
+
+    /// Translates a given key using the given language.
+    ///
+    /// Falls back to the default language, and to a message naming the key, like `t`.
+    pub(crate) fn translate(&self, language: &str, key: &str) -> String {
+        self.translate_cow(language, key).into_owned()
+    }
+
+    /// Translates a given key using the given language, borrowing the translation.
+    fn translate_cow(&self, language: &str, key: &str) -> Cow<'_, str> {
+        self.resolve(language, key)
+            .unwrap_or_else(|| Cow::Owned(Self::missing_message(language, key)))
+    }
+
+    /// The message returned for a key missing in both the given and the default language.
+    fn missing_message(language: &str, key: &str) -> String {
+        format!("Key '{}' not found for language '{}'", key, language)
+    }
+
+    /// Resolves a key in the given language, then in the default language.
+    ///
+    /// Returns the key itself in raw-keys mode, and `None` if it is missing in both.
+    fn resolve(&self, language: &str, key: &str) -> Option<Cow<'_, str>> {
+        if self.show_keys {
+            return Some(Cow::Owned(key.to_string()));
+        }
+
+        let first_language = self.default_language.as_deref().unwrap_or(language);
+
+        let value = self.lookup(language, key);
+        if value.is_none()
+            && let Some(handler) = &self.missing_key_handler
+        {
+            handler.call(key, language);
+        }
+        if cfg!(debug_assertions) && self.strict && value.is_none() {
+            panic!(
+                "Key '{}' not found for language '{}' (strict mode)",
+                key, language
+            );
+        }
+
+        #[cfg(feature = "tracing")]
+        let found = value.is_some();
+        let value = value.or_else(|| self.lookup(first_language, key));
+        if value.is_some()
+            && let Some(usage) = &self.usage
+        {
+            usage.record(key);
+        }
+
+        #[cfg(feature = "tracing")]
+        match value {
+            Some(_) if !found => tracing::debug!(
+                target: "i18nrs",
+                key,
+                language,
+                fallback = first_language,
+                "fallback translation used"
+            ),
+            None => tracing::warn!(target: "i18nrs", key, language, "missing translation key"),
+            Some(_) => {}
+        }
+
+        value
+    }
+
+    /// Resolves a key in one language, without any fallback.
+    ///
+    /// Leaf values come from the flattened index; keys naming a whole object (e.g., a
+    /// namespace) are not indexed and resolve to its JSON through the tree.
+    fn lookup(&self, language: &str, key: &str) -> Option<Cow<'_, str>> {
+        if let Some(value) = self.flat_get(language, key) {
+            return Some(Cow::Borrowed(value));
+        }
+        let keys: Vec<&str> = key.split('.').collect();
+        self.translations
+            .get(language)
+            .and_then(|json| Self::get_nested_value(json, &keys))
+            .filter(|value| value.is_object())
+            .map(|value| Cow::Owned(value.to_string()))
+    }
+
+    /// Looks up a leaf translation in the layers, topmost first, then in the selected
+    /// tenant and the catalog.
+    fn flat_get(&self, language: &str, key: &str) -> Option<&Arc<str>> {
+        self.overrides()
+            .rev()
+            .find_map(|layer| layer.index.get(language, key))
+            .or_else(|| self.flat.get(language, key))
+    }
+
+    /// Rebuilds the flattened index of a language after its translations changed.
+    fn reindex(&mut self, language: &str) {
+        if let Some(json) = self.translations.get(language) {
+            Arc::make_mut(&mut self.flat).insert(language, json);
+        }
+        self.messages.forget(Some(language));
+    }
+
+    /// Translates a key and substitutes its `{name}` placeholders.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to retrieve (e.g., `"welcome.user"`).
+    /// - `args`: Named values for the placeholders; see also the `t!` macro. With the
+    ///   `datetime` feature, typed placeholders such as `{due, date, long}`, `{at, time}` or
+    ///   `{due, date, ::yMMMd}` format a date, e.g. a `chrono::NaiveDate`, for the language.
+    ///
+    /// # Returns
+    /// - The translated string with every known placeholder replaced.
+    /// - The usual fallback message if the key does not exist.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"welcome": "Hi {name}, {count} items"}"#)]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// let text = i18n.t_with("welcome", &[("name", &"Ada"), ("count", &3)]);
+    /// assert_eq!(text, "Hi Ada, 3 items");
+    /// ```
+    pub fn t_with(&self, key: &str, args: I18nArgs<'_>) -> String {
+        self.translate_with(&self.current_language, key, args)
+    }
+
+    /// Translates a key using the given language and substitutes its placeholders,
+    /// reusing the parsed form of its translation.
+    pub(crate) fn translate_with(&self, language: &str, key: &str, args: I18nArgs<'_>) -> String {
+        match self.resolve(language, key) {
+            Some(template) => self
+                .messages
+                .get(language, key, &template)
+                .format(language, args),
+            None => Self::missing_message(language, key),
+        }
+    }
+
+    /// Translates a key qualified by a context, like gettext's `msgctxt`.
+    ///
+    /// Homographs such as "May" the month and "may" the verb share a source text but need
+    /// different translations. The contextual translation is stored under `<key>_<context>`
+    /// (e.g., `"May_month"`), and the plain key is used when the context has none. Both are
+    /// looked up in the current language before the fallback language.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key (e.g., `"May"`).
+    /// - `context`: The disambiguating context (e.g., `"month"` or `"verb"`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([(
+    ///     "fr",
+    ///     r#"{"May": "Mai", "May_month": "mai", "May_verb": "pouvoir"}"#,
+    /// )]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.t_ctx("May", "month"), "mai");
+    /// assert_eq!(i18n.t_ctx("May", "verb"), "pouvoir");
+    /// assert_eq!(i18n.t_ctx("May", "name"), "Mai");
+    /// ```
+    pub fn t_ctx(&self, key: &str, context: &str) -> String {
+        let qualified = format!("{}_{}", key, context);
+        self.t(self.pick_key(&[&qualified, key]).unwrap_or(key))
+    }
+
+    /// Translates a select-style message, picking the variant that matches a value.
+    ///
+    /// The variants are sub-keys of `key`, one per value (e.g., a gender), plus an `other`
+    /// variant used when no sub-key matches the value. Both are looked up in the current
+    /// language before the fallback language.
+    ///
+    /// # Arguments
+    /// - `key`: The key of the variants (e.g., `"invite"`).
+    /// - `value`: The value selecting the variant (e.g., `"female"`).
+    /// - `args`: Named values for the placeholders, as for `t_with`.
+    ///
+    /// # Returns
+    /// - The translated variant with every known placeholder replaced.
+    /// - The usual fallback message if neither the variant nor `other` exists.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([(
+    ///     "fr",
+    ///     r#"{"invite": {"female": "{name} est invitée", "male": "{name} est invité", "other": "{name} a une invitation"}}"#,
+    /// )]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.t_select("invite", "female", &[("name", &"Ada")]), "Ada est invitée");
+    /// assert_eq!(i18n.t_select("invite", "unknown", &[("name", &"Sam")]), "Sam a une invitation");
+    /// ```
+    pub fn t_select(&self, key: &str, value: &str, args: I18nArgs<'_>) -> String {
+        let variant = format!("{}.{}", key, value);
+        let other = format!("{}.other", key);
+        self.t_with(self.pick_key(&[&variant, &other]).unwrap_or(&other), args)
+    }
+
+    /// Picks the first candidate key translated in the current language, then in the
+    /// fallback language, so a more specific key only the fallback has never shadows a
+    /// translation in the current language.
+    fn pick_key<'k>(&self, candidates: &[&'k str]) -> Option<&'k str> {
+        std::iter::once(self.current_language.as_str())
+            .chain(self.default_language.as_deref())
+            .find_map(|language| {
+                candidates
+                    .iter()
+                    .find(|key| self.lookup(language, key).is_some())
+                    .copied()
+            })
+    }
+
+    /// Translates a key whose value is Markdown and renders it to sanitized HTML.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to retrieve (e.g., `"help.body"`).
+    ///
+    /// # Returns
+    /// - The rendered HTML; see `markdown::to_html` for the sanitization rules.
+    #[cfg(feature = "markdown")]
+    pub fn t_markdown(&self, key: &str) -> String {
+        crate::markdown::to_html(&self.t(key))
+    }
+
+    /// Translates a key whose value is an HTML snippet and sanitizes it.
+    ///
+    /// Only a safe allowlist of tags and attributes is kept, so snippets returned by a
+    /// translation vendor can be rendered as real nodes.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to retrieve (e.g., `"legal.notice"`).
+    ///
+    /// # Returns
+    /// - The sanitized HTML; see `html::sanitize` for the rules.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations =
+    ///     HashMap::from([("en", r#"{"notice": "<b>Note</b><script>alert(1)</script>"}"#)]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.t_html("notice"), "<b>Note</b>");
+    /// ```
+    #[cfg(feature = "html")]
+    pub fn t_html(&self, key: &str) -> String {
+        crate::html::sanitize(&self.t(key))
+    }
+
+    /// Formats a date according to the current language.
+    ///
+    /// Month and weekday names, field order and separators come from the CLDR data of the
+    /// current locale.
+    ///
+    /// # Arguments
+    /// - `date`: The date to format.
+    /// - `style`: The length of the output.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the formatted date.
+    /// - `Err(String)` if the current language code is not a valid locale.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::datetime::{Date, DateStyle};
+    /// use i18nrs::{I18n, I18nConfig, StorageType};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", "{}"), ("de", "{}")]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    /// i18n.set_translation_language("de", &StorageType::LocalStorage, "i18nrs")
+    ///     .unwrap();
+    ///
+    /// let date = Date::try_new_iso(2025, 1, 15).unwrap();
+    /// assert_eq!(
+    ///     i18n.format_date(&date, DateStyle::Full).unwrap(),
+    ///     "Mittwoch, 15. Januar 2025"
+    /// );
+    /// ```
+    #[cfg(feature = "datetime")]
+    pub fn format_date(
+        &self,
+        date: &impl crate::datetime::ToDate,
+        style: crate::datetime::DateStyle,
+    ) -> Result<String, String> {
+        crate::datetime::format_date(&self.current_language, date, style)
+    }
+
+    /// Returns the first day of the week and the weekend days of the current language.
+    ///
+    /// # Returns
+    /// - `Ok(WeekInfo)` for laying out calendars and date pickers; see
+    ///   `datetime::week_info`.
+    /// - `Err(String)` if the current language code is not a valid locale.
+    #[cfg(feature = "datetime")]
+    pub fn week_info(&self) -> Result<crate::datetime::WeekInfo, String> {
+        crate::datetime::week_info(&self.current_language)
+    }
+
+    /// Returns whether the current language prefers 12-hour or 24-hour time.
+    ///
+    /// # Returns
+    /// - `Ok(HourCycle)` for choosing the default of time pickers and schedules; see
+    ///   `datetime::hour_cycle`.
+    /// - `Err(String)` if the current language code is not a valid locale.
+    #[cfg(feature = "datetime")]
+    pub fn hour_cycle(&self) -> Result<crate::datetime::HourCycle, String> {
+        crate::datetime::hour_cycle(&self.current_language)
+    }
+
+    /// Formats a date and time from a CLDR skeleton according to the current language.
+    ///
+    /// # Arguments
+    /// - `datetime`: The date and time to format.
+    /// - `skeleton`: The fields to show (e.g., `"yMMMd"` or `"Hm"`); see
+    ///   `datetime::format_skeleton` for the supported symbols.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the formatted value.
+    /// - `Err(String)` if the skeleton or the current language code is invalid.
+    #[cfg(feature = "datetime")]
+    pub fn format_skeleton(
+        &self,
+        datetime: &impl crate::datetime::ToDateTime,
+        skeleton: &str,
+    ) -> Result<String, String> {
+        crate::datetime::format_skeleton(&self.current_language, datetime, skeleton)
+    }
+
+    /// Formats a date and time with its time zone according to the current language.
+    ///
+    /// # Arguments
+    /// - `datetime`: The zoned date and time, e.g., built with `datetime::zoned`.
+    /// - `style`: The length of the date.
+    /// - `zone_style`: Whether to write the zone as a specific (`"Central European Summer
+    ///   Time"`) or generic (`"Central European Time"`) name, a location or a GMT offset.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the formatted value.
+    /// - `Err(String)` if the current language code is not a valid locale.
+    #[cfg(feature = "datetime")]
+    pub fn format_zoned(
+        &self,
+        datetime: &impl crate::datetime::ToZonedDateTime,
+        style: crate::datetime::DateStyle,
+        zone_style: crate::datetime::ZoneStyle,
+    ) -> Result<String, String> {
+        crate::datetime::format_zoned(&self.current_language, datetime, style, zone_style)
+    }
+
+    /// Formats a date and time with an explicit CLDR pattern in the current language.
+    ///
+    /// # Arguments
+    /// - `datetime`: The date and time to format.
+    /// - `pattern`: The pattern (e.g., `"EEEE d MMMM y"`); names are localized, the field
+    ///   order and literal text are kept as written.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the formatted value.
+    /// - `Err(String)` if the pattern or the current language code is invalid.
+    #[cfg(feature = "datetime")]
+    pub fn format_pattern(
+        &self,
+        datetime: &impl crate::datetime::ToDateTime,
+        pattern: &str,
+    ) -> Result<String, String> {
+        crate::datetime::format_pattern(&self.current_language, datetime, pattern)
+    }
+
+    /// Formats a number in compact notation according to the current language.
+    ///
+    /// # Arguments
+    /// - `value`: The number to format.
+    /// - `style`: `CompactStyle::Short` for `1.2K`, `CompactStyle::Long` for `1.2 thousand`.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the formatted number.
+    /// - `Err(String)` if the current language code is not a valid locale or the value is
+    ///   not finite.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::number::CompactStyle;
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", "{}")]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.format_compact(2_500_000.0, CompactStyle::Short).unwrap(), "2.5M");
+    /// ```
+    #[cfg(feature = "number")]
+    pub fn format_compact(
+        &self,
+        value: f64,
+        style: crate::number::CompactStyle,
+    ) -> Result<String, String> {
+        crate::number::format_compact(&self.current_language, value, style)
+    }
+
+    /// Formats a monetary amount according to the current language.
+    ///
+    /// # Arguments
+    /// - `amount`: The amount, e.g. an `f64` or, with the `rust_decimal` feature, a
+    ///   `rust_decimal::Decimal` formatted without loss of precision.
+    /// - `currency`: The ISO 4217 currency code (e.g., `"EUR"`).
+    /// - `style`: Whether to write the currency as a symbol, a code or a name.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the formatted amount; see `currency::format_currency`.
+    /// - `Err(String)` if the current language or the currency code is invalid, or the
+    ///   amount is not finite.
+    #[cfg(feature = "currency")]
+    pub fn format_currency(
+        &self,
+        amount: &impl crate::currency::ToDecimal,
+        currency: &str,
+        style: crate::currency::CurrencyStyle,
+    ) -> Result<String, String> {
+        crate::currency::format_currency(&self.current_language, amount, currency, style)
+    }
+
+    /// Returns the measurement conventions of the current language.
+    ///
+    /// # Returns
+    /// - `Ok(MeasurementPreferences)` telling whether metric or US customary units and
+    ///   Celsius or Fahrenheit are expected; see `measurement::measurement_preferences`.
+    /// - `Err(String)` if the current language code is not a valid locale.
+    #[cfg(feature = "measurement")]
+    pub fn measurement_preferences(
+        &self,
+    ) -> Result<crate::measurement::MeasurementPreferences, String> {
+        crate::measurement::measurement_preferences(&self.current_language)
+    }
+
+    /// Creates a collator for the sorting rules of the current language.
+    ///
+    /// # Returns
+    /// - `Ok(Collator)` to compare or sort many strings.
+    /// - `Err(String)` if the current language code is not a valid locale.
+    #[cfg(feature = "collation")]
+    pub fn collator(&self) -> Result<crate::collation::Collator, String> {
+        crate::collation::Collator::new(&self.current_language)
+    }
+
+    /// Compares two strings using the collation rules of the current language.
+    ///
+    /// Falls back to comparing code points if the current language code is not a valid
+    /// locale. When sorting a list, build a `collator` once instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::cmp::Ordering;
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("de", "{}")]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.compare("Österreich", "Polen"), Ordering::Less);
+    /// ```
+    #[cfg(feature = "collation")]
+    pub fn compare(&self, a: &str, b: &str) -> std::cmp::Ordering {
+        match self.collator() {
+            Ok(collator) => collator.compare(a, b),
+            Err(_) => a.cmp(b),
+        }
+    }
+
+    /// Computes the sort key of a string for the current language.
+    ///
+    /// Keys compare byte-wise like `compare` compares the strings. Falls back to the UTF-8
+    /// bytes of the string if the current language code is not a valid locale.
+    #[cfg(feature = "collation")]
+    pub fn sort_key(&self, s: &str) -> Vec<u8> {
+        match self.collator() {
+            Ok(collator) => collator.sort_key(s),
+            Err(_) => s.as_bytes().to_vec(),
+        }
+    }
+
+    /// Converts a string to uppercase using the rules of the current language.
+    ///
+    /// Handles language-specific mappings such as the Turkish dotted `İ`; see
+    /// `casemap::to_upper`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("tr", "{}")]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.to_upper_locale("izmir"), "İZMİR");
+    /// ```
+    #[cfg(feature = "casemap")]
+    pub fn to_upper_locale(&self, s: &str) -> String {
+        crate::casemap::to_upper(&self.current_language, s)
+    }
+
+    /// Converts a string to lowercase using the rules of the current language.
+    #[cfg(feature = "casemap")]
+    pub fn to_lower_locale(&self, s: &str) -> String {
+        crate::casemap::to_lower(&self.current_language, s)
+    }
+
+    /// Capitalizes every word of a string using the rules of the current language.
+    #[cfg(feature = "casemap")]
+    pub fn to_title_locale(&self, s: &str) -> String {
+        crate::casemap::to_title(&self.current_language, s)
+    }
+
+    /// Returns the name of a language in the current language.
+    ///
+    /// Useful to label the options of a language picker without maintaining a label map.
+    /// Falls back to the code itself if it can't be resolved.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to name (e.g., `"fr"`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("de", "{}")]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.language_display_name("fr"), "Französisch");
+    /// ```
+    #[cfg(feature = "displaynames")]
+    pub fn language_display_name(&self, language: &str) -> String {
+        crate::displaynames::language_display_name(&self.current_language, language)
+            .unwrap_or_else(|_| language.to_string())
+    }
+
+    /// Returns the name of a region or country in the current language.
+    ///
+    /// Falls back to the code itself if it can't be resolved.
+    ///
+    /// # Arguments
+    /// - `region`: The ISO 3166 alpha-2 or UN M.49 region code (e.g., `"DE"`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("es", "{}")]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.region_display_name("DE"), "Alemania");
+    /// ```
+    #[cfg(feature = "displaynames")]
+    pub fn region_display_name(&self, region: &str) -> String {
+        crate::displaynames::region_display_name(&self.current_language, region)
+            .unwrap_or_else(|_| region.to_string())
+    }
+
+    /// Translates a typed key using the current language.
+    ///
+    /// # Arguments
+    /// - `key`: A typed key, usually a variant of the generated `TranslationKey` enum.
+    ///
+    /// # Returns
+    /// - The translated string, with the same fallbacks as `t`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, I18nKey};
+    /// use std::collections::HashMap;
+    ///
+    /// struct Greeting;
+    ///
+    /// impl I18nKey for Greeting {
+    ///     fn key(&self) -> &'static str {
+    ///         "greeting"
+    ///     }
+    /// }
+    ///
+    /// let translations = HashMap::from([("en", r#"{"greeting": "Hello!"}"#)]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.tk(Greeting), "Hello!");
+    /// ```
+    pub fn tk(&self, key: impl I18nKey) -> String {
+        self.t(key.key())
+    }
+
+    /// Creates a scoped view that resolves keys relative to a prefix.
+    ///
+    /// # Arguments
+    /// - `prefix`: The dot-separated key prefix (e.g., `"form.address"`).
+    ///
+    /// # Returns
+    /// - An `I18nScope` whose `t("city")` resolves `"form.address.city"`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"form": {"address": {"city": "City"}}}"#)]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// let address = i18n.scope("form.address");
+    /// assert_eq!(address.t("city"), "City");
+    /// ```
+    pub fn scope(&self, prefix: &str) -> I18nScope<'_> {
+        I18nScope {
+            i18n: self,
+            prefix: prefix.to_string(),
+        }
+    }
+
+    /// Retrieves a nested value from a JSON object using a sequence of keys.
+    ///
+    /// # Arguments
+    /// - `json`: The root `serde_json::Value` object to search within.
+    /// - `keys`: A slice of keys representing the path to the desired value.
+    ///
+    /// # Returns
+    /// - `Some(&Value)` if the value exists at the specified path.
+    /// - `None` if the path does not exist.
+    fn get_nested_value<'a>(json: &'a Value, keys: &[&str]) -> Option<&'a Value> {
+        keys.iter().try_fold(json, |current, key| current.get(key))
+    }
+}
+
+/// A view over an `I18n` instance that resolves keys within a namespace.
+///
+/// Returned by the namespace-scoped hooks so components belonging to a feature area
+/// (e.g. `"checkout"`) can call `t("title")` instead of `t("checkout.title")`.
+#[derive(Clone, PartialEq)]
+pub struct I18nNamespace {
+    /// The underlying i18n instance used for lookups.
+    i18n: I18n,
+    /// The namespace prepended to every key.
+    namespace: String,
+}
+
+impl I18nNamespace {
+    /// Creates a namespaced view over the given `I18n` instance.
+    ///
+    /// # Arguments
+    /// - `i18n`: The `I18n` instance to resolve keys against.
+    /// - `namespace`: The key prefix to resolve within (e.g., `"checkout"`).
+    pub fn new(i18n: I18n, namespace: &str) -> Self {
+        Self {
+            i18n,
+            namespace: namespace.to_string(),
+        }
+    }
+
+    /// Translates a key relative to the namespace.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key within the namespace (e.g., `"title"`).
+    ///
+    /// # Returns
+    /// - The translated string for `"<namespace>.<key>"`, or the usual fallback message.
+    pub fn t(&self, key: &str) -> String {
+        self.i18n.t(&format!("{}.{}", self.namespace, key))
+    }
+
+    /// Translates a key relative to the namespace and substitutes its placeholders.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key within the namespace (e.g., `"total"`).
+    /// - `args`: Named values for the `{name}` placeholders.
+    pub fn t_with(&self, key: &str, args: I18nArgs<'_>) -> String {
+        self.i18n.translate_with(
+            &self.i18n.current_language,
+            &format!("{}.{}", self.namespace, key),
+            args,
+        )
+    }
+
+    /// Retrieves the namespace this view resolves keys within.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Retrieves the underlying `I18n` instance for lookups outside the namespace.
+    pub fn i18n(&self) -> &I18n {
+        &self.i18n
+    }
+}
+
+/// A borrowed view over an `I18n` instance that resolves keys relative to a prefix.
+///
+/// Created with `I18n::scope`. Scopes can be nested, so deeply nested components only
+/// need to know the part of the key they own.
+#[derive(Clone)]
+pub struct I18nScope<'a> {
+    /// The underlying i18n instance used for lookups.
+    i18n: &'a I18n,
+    /// The dot-separated prefix prepended to every key.
+    prefix: String,
+}
+
+impl<'a> I18nScope<'a> {
+    /// Translates a key relative to the scope prefix.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key within the scope (e.g., `"city"`).
+    ///
+    /// # Returns
+    /// - The translated string for `"<prefix>.<key>"`, or the usual fallback message.
+    pub fn t(&self, key: &str) -> String {
+        self.i18n.t(&format!("{}.{}", self.prefix, key))
+    }
+
+    /// Translates a key relative to the scope prefix and substitutes its placeholders.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key within the scope (e.g., `"greeting"`).
+    /// - `args`: Named values for the `{name}` placeholders.
+    pub fn t_with(&self, key: &str, args: I18nArgs<'_>) -> String {
+        self.i18n.translate_with(
+            &self.i18n.current_language,
+            &format!("{}.{}", self.prefix, key),
+            args,
+        )
+    }
+
+    /// Creates a nested scope below the current prefix.
+    ///
+    /// # Arguments
+    /// - `prefix`: The prefix relative to the current scope (e.g., `"billing"`).
+    pub fn scope(&self, prefix: &str) -> I18nScope<'a> {
+        I18nScope {
+            i18n: self.i18n,
+            prefix: format!("{}.{}", self.prefix, prefix),
+        }
+    }
+
+    /// Retrieves the full prefix this scope resolves keys within.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn i18n(translations: &[(&'static str, &'static str)]) -> I18n {
+        let translations: HashMap<&str, &str> = translations.iter().copied().collect();
+        I18n::new(
+            I18nConfig {
+                translations: translations.clone(),
+            },
+            translations,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn message_parses_placeholders() {
+        let message = Message::parse("Hi {name}, due {due, date, long}");
+        assert_eq!(
+            message.placeholder_names().into_iter().collect::<Vec<_>>(),
+            ["due", "name"]
+        );
+        assert_eq!(
+            message.format("en", &[("name", &"Ada")]),
+            "Hi Ada, due {due, date, long}"
+        );
+    }
+
+    #[test]
+    fn message_keeps_unbalanced_braces_as_text() {
+        for (template, expected) in [
+            ("{", "{"),
+            ("}", "}"),
+            ("a { b", "a { b"),
+            ("{name", "{name"),
+            ("name}", "name}"),
+            ("{{name}", "{x"),
+            ("{a {name}", "{a x"),
+        ] {
+            let message = Message::parse(template);
+            assert_eq!(message.format("en", &[("name", &"x")]), expected);
+        }
+        assert!(Message::parse("a { b").placeholder_names().is_empty());
+    }
+
+    #[test]
+    fn message_keeps_empty_and_unmatched_placeholders() {
+        let message = Message::parse("{} and {missing}");
+        assert_eq!(message.format("en", &[]), "{} and {missing}");
+    }
+
+    #[test]
+    fn message_does_not_rescan_substituted_values() {
+        let message = Message::parse("{a}{b}");
+        assert_eq!(message.format("en", &[("a", &"{b}"), ("b", &"x")]), "{b}x");
+    }
+
+    #[test]
+    fn cache_reparses_changed_templates() {
+        let mut i18n = i18n(&[("en", r#"{"greeting": "Hi {name}"}"#)]);
+        assert_eq!(i18n.t_with("greeting", &[("name", &"Ada")]), "Hi Ada");
+
+        let clone = i18n.clone();
+        i18n.merge("en", r#"{"greeting": "Hello {name}"}"#).unwrap();
+        assert_eq!(i18n.t_with("greeting", &[("name", &"Ada")]), "Hello Ada");
+        assert_eq!(clone.t_with("greeting", &[("name", &"Ada")]), "Hi Ada");
+    }
+
+    #[test]
+    fn cache_skips_missing_keys() {
+        let i18n = i18n(&[("en", r#"{"greeting": "Hi"}"#)]);
+        assert_eq!(
+            i18n.t_with("missing", &[]),
+            "Key 'missing' not found for language 'en'"
+        );
+        assert!(i18n.messages.0.lock().unwrap().is_empty());
+
+        i18n.t_with("greeting", &[]);
+        assert_eq!(i18n.messages.0.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn cache_is_bounded_and_cleared_on_unload() {
+        let mut i18n = i18n(&[("en", "{}"), ("de", "{}")]);
+        let keys: Vec<String> = (0..=MAX_MESSAGES).map(|n| format!("k{}", n)).collect();
+        let bundle = serde_json::to_string(
+            &keys
+                .iter()
+                .map(|key| (key.clone(), Value::String(key.clone())))
+                .collect::<serde_json::Map<_, _>>(),
+        )
+        .unwrap();
+        i18n.add_language("fr", &bundle).unwrap();
+        i18n.select_language("fr").unwrap();
+        for key in &keys {
+            i18n.t_with(key, &[]);
+        }
+        assert!(i18n.messages.0.lock().unwrap().len() <= MAX_MESSAGES);
+
+        i18n.select_language("en").unwrap();
+        i18n.remove_language("fr").unwrap();
+        assert!(i18n.messages.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn fallback_is_the_alphabetically_first_language() {
+        for _ in 0..8 {
+            let i18n = i18n(&[("fr", "{}"), ("de", "{}"), ("en", "{}")]);
+            assert_eq!(i18n.default_language(), Some("de"));
+            assert_eq!(i18n.get_current_language(), "de");
+        }
+
+        static TRANSLATIONS: StaticTranslations = &[
+            ("fr", &[("farewell", "Au revoir")]),
+            ("en", &[("greeting", "Hello")]),
+        ];
+        let mut i18n = I18n::from_static(TRANSLATIONS).unwrap();
+        assert_eq!(i18n.default_language(), Some("en"));
+        i18n.select_language("fr").unwrap();
+        assert_eq!(i18n.t("greeting"), "Hello");
+    }
+
+    #[test]
+    fn flat_index_releases_only_unused_strings() {
+        let mut index = FlatIndex::default();
+        index.insert("en", &serde_json::json!({"ok": "OK", "cancel": "Cancel"}));
+        index.insert(
+            "de",
+            &serde_json::json!({"ok": "OK", "cancel": "Abbrechen"}),
+        );
+        assert_eq!(index.strings.len(), 5);
+
+        index.insert("de", &serde_json::json!({"ok": "OK"}));
+        assert!(!index.strings.contains_key("Abbrechen"));
+        assert!(index.strings.contains_key("cancel"));
+
+        index.remove("en");
+        let strings: BTreeSet<&str> = index.strings.keys().map(|s| &**s).collect();
+        assert_eq!(strings, BTreeSet::from(["OK", "ok"]));
+    }
+
+    #[test]
+    fn removing_a_language_from_a_clone_releases_its_strings() {
+        let original = i18n(&[
+            ("en", r#"{"ok": "OK", "cancel": "Cancel"}"#),
+            ("de", r#"{"ok": "OK", "cancel": "Abbrechen"}"#),
+            ("fr", r#"{"ok": "OK", "cancel": "Annuler"}"#),
+        ]);
+        let shared = original.t_shared("cancel");
+
+        let mut i18n = original.clone();
+        i18n.remove_language("en").unwrap();
+        assert!(i18n.flat.strings.contains_key("Abbrechen"));
+        assert!(!i18n.flat.strings.contains_key("Cancel"));
+        assert!(original.flat.strings.contains_key("Cancel"));
+        assert_eq!(&*shared, "Abbrechen");
+
+        let mut unloaded = original.clone();
+        unloaded.unload_language("fr").unwrap();
+        assert!(!unloaded.flat.strings.contains_key("Annuler"));
+        assert!(unloaded.flat.strings.contains_key("OK"));
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn binary_bundles_round_trip() {
+        let i18n = i18n(&[("en", r#"{"a": {"b": "B"}}"#), ("fr", r#"{"c": "C"}"#)]);
+        let mut decoded = I18n::from_binary(&i18n.to_binary().unwrap()).unwrap();
+        assert_eq!(decoded.languages(), ["en", "fr"]);
+        assert_eq!(decoded.default_language(), Some("en"));
+        assert_eq!(decoded.t("a.b"), "B");
+        decoded.select_language("fr").unwrap();
+        assert_eq!(decoded.t("c"), "C");
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn binary_bundles_reject_malformed_input() {
+        let bytes = i18n(&[("en", r#"{"greeting": "Hello"}"#)])
+            .to_binary()
+            .unwrap();
+        for len in 0..bytes.len() {
+            let err = I18n::from_binary(&bytes[..len]).err().unwrap_or_default();
+            assert!(
+                err.starts_with("Invalid binary translation bundle"),
+                "{}",
+                err
+            );
+        }
+
+        assert!(I18n::from_binary(&[0xc1]).is_err());
+        assert!(I18n::from_binary(&rmp_serde::to_vec(&["en"]).unwrap()).is_err());
+        assert_eq!(
+            I18n::from_binary(&rmp_serde::to_vec(&HashMap::<String, Value>::new()).unwrap())
+                .err()
+                .as_deref(),
+            Some("You must add at least one supported language")
+        );
+    }
+
+    #[test]
+    fn static_bundles_nest_dotted_keys() {
+        static TRANSLATIONS: StaticTranslations = &[("en", &[("a.b.c", "C"), ("a.d", "D")])];
+        let i18n = I18n::from_static(TRANSLATIONS).unwrap();
+        assert_eq!(i18n.t("a.b.c"), "C");
+        assert_eq!(i18n.t("a.d"), "D");
+
+        assert_eq!(
+            I18n::from_static(&[]).err().as_deref(),
+            Some("You must add at least one supported language")
+        );
+    }
+
+    #[test]
+    fn static_bundles_reject_colliding_keys() {
+        assert_eq!(
+            I18n::from_static(&[("en", &[("menu", "Menu"), ("menu.open", "Open")])])
+                .err()
+                .as_deref(),
+            Some("Key 'menu' is both a translation and a prefix of 'menu.open' in language 'en'")
+        );
+        assert_eq!(
+            I18n::from_static(&[("en", &[("menu.file.open", "Open"), ("menu", "Menu")])])
+                .err()
+                .as_deref(),
+            Some("Key 'menu' is both a translation and a prefix of other keys in language 'en'")
+        );
+        assert_eq!(
+            I18n::from_static(&[("en", &[("menu.open", "Open"), ("menu.open", "Ouvrir")])])
+                .err()
+                .as_deref(),
+            Some("Key 'menu.open' is defined twice in language 'en'")
+        );
+    }
+
+    #[test]
+    fn contexts_prefer_the_current_language() {
+        let mut i18n = i18n(&[
+            (
+                "en",
+                r#"{"May": "May", "May_month": "may", "Can_verb": "can"}"#,
+            ),
+            ("fr", r#"{"May": "Mai", "Can": "Boîte"}"#),
+        ]);
+        i18n.select_language("fr").unwrap();
+        assert_eq!(i18n.t_ctx("May", "month"), "Mai");
+        assert_eq!(i18n.t_ctx("Can", "verb"), "Boîte");
+        assert_eq!(i18n.t_ctx("May_month", "x"), "may");
+
+        i18n.add_language("fr", r#"{"May": "Mai", "May_month": "mai"}"#)
+            .unwrap();
+        assert_eq!(i18n.t_ctx("May", "month"), "mai");
+    }
+
+    #[test]
+    fn select_prefers_the_current_language() {
+        let mut i18n = i18n(&[
+            (
+                "en",
+                r#"{"invite": {"female": "{name} is invited", "other": "{name} has an invite"}, "bye": {"female": "Bye {name}"}}"#,
+            ),
+            ("fr", r#"{"invite": {"other": "{name} a une invitation"}}"#),
+        ]);
+        i18n.select_language("fr").unwrap();
+        let args: I18nArgs<'_> = &[("name", &"Ada")];
+        assert_eq!(
+            i18n.t_select("invite", "female", args),
+            "Ada a une invitation"
+        );
+        assert_eq!(i18n.t_select("bye", "female", args), "Bye Ada");
+        assert_eq!(
+            i18n.t_select("bye", "male", args),
+            "Key 'bye.other' not found for language 'fr'"
+        );
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio"))]
+    #[test]
+    fn bundles_loaded_later_are_checked_against_the_schema() {
+        let schema = crate::schema::from_locale(&serde_json::json!({"nav": {"home": "Home"}}));
+        assert!(
+            check_bundle_schema("fr", r#"{"nav": {"home": "Accueil"}}"#, Some(&schema)).is_ok()
+        );
+        assert!(check_bundle_schema("fr", r#"{"nav": "Accueil"}"#, None).is_ok());
+        assert_eq!(
+            check_bundle_schema("fr", r#"{"nav": "Accueil"}"#, Some(&schema)),
+            Err("Invalid structure for language fr: /nav: expected object, found string".into())
+        );
+        assert!(
+            check_bundle_schema("fr", "{", Some(&schema))
+                .unwrap_err()
+                .starts_with("Invalid JSON for language fr")
+        );
     }
 }
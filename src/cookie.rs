@@ -0,0 +1,183 @@
+//! Cookies holding the selected language.
+//!
+//! Parses `Cookie` headers and `document.cookie`, which share their format, and formats
+//! `Set-Cookie` values with their `Path`, `Max-Age` and `SameSite` attributes, for
+//! `StorageType::Cookie`, the server-side rendering helpers and the server middleware.
+
+use std::fmt::{self, Display};
+
+/// How long the language cookie is kept, in seconds (one year).
+const LANGUAGE_MAX_AGE: u64 = 31_536_000;
+
+/// The `SameSite` attribute of a cookie.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SameSite {
+    /// Sent with same-site requests only.
+    Strict,
+    /// Also sent when navigating to the site from elsewhere.
+    Lax,
+    /// Sent with cross-site requests too; browsers require `Secure` along with it.
+    None,
+}
+
+impl SameSite {
+    /// Returns the value of the attribute (e.g., `"Lax"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Strict => "Strict",
+            Self::Lax => "Lax",
+            Self::None => "None",
+        }
+    }
+}
+
+/// A cookie to set, formatted as a `Set-Cookie` value by `Display`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::cookie::{Cookie, SameSite};
+///
+/// let cookie = Cookie::new("i18nrs", "fr")
+///     .with_path("/app")
+///     .with_max_age(3600)
+///     .with_same_site(SameSite::Strict);
+/// assert_eq!(
+///     cookie.to_string(),
+///     "i18nrs=fr; Path=/app; Max-Age=3600; SameSite=Strict"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cookie {
+    name: String,
+    value: String,
+    path: Option<String>,
+    max_age: Option<u64>,
+    same_site: Option<SameSite>,
+}
+
+impl Cookie {
+    /// Creates a session cookie without attributes.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            path: None,
+            max_age: None,
+            same_site: None,
+        }
+    }
+
+    /// Creates the cookie persisting the selected language, as written by the providers:
+    /// site-wide (`Path=/`), kept for a year, with `SameSite=Lax`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::cookie::Cookie;
+    ///
+    /// assert_eq!(
+    ///     Cookie::language("i18nrs", "fr").to_string(),
+    ///     "i18nrs=fr; Path=/; Max-Age=31536000; SameSite=Lax"
+    /// );
+    /// ```
+    pub fn language(name: impl Into<String>, language: impl Into<String>) -> Self {
+        Self::new(name, language)
+            .with_path("/")
+            .with_max_age(LANGUAGE_MAX_AGE)
+            .with_same_site(SameSite::Lax)
+    }
+
+    /// Sets the `Path` attribute.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Sets the `Max-Age` attribute, in seconds.
+    pub fn with_max_age(mut self, seconds: u64) -> Self {
+        self.max_age = Some(seconds);
+        self
+    }
+
+    /// Sets the `SameSite` attribute.
+    pub fn with_same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// The name of the cookie.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value of the cookie.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Reads a cookie from a `Cookie` header or `document.cookie`.
+    ///
+    /// # Arguments
+    /// - `header`: The raw cookie list (e.g., `"theme=dark; i18nrs=fr"`).
+    /// - `name`: The cookie name.
+    ///
+    /// # Returns
+    /// - `Some(&str)` with the cookie value, `None` if the cookie is absent.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::cookie::Cookie;
+    ///
+    /// assert_eq!(Cookie::get("theme=dark; i18nrs=fr", "i18nrs"), Some("fr"));
+    /// assert_eq!(Cookie::get("theme=dark", "i18nrs"), None);
+    /// ```
+    pub fn get<'a>(header: &'a str, name: &str) -> Option<&'a str> {
+        header
+            .split(';')
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl Display for Cookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)?;
+        if let Some(path) = &self.path {
+            write!(f, "; Path={}", path)?;
+        }
+        if let Some(max_age) = self.max_age {
+            write!(f, "; Max-Age={}", max_age)?;
+        }
+        if let Some(same_site) = self.same_site {
+            write!(f, "; SameSite={}", same_site.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a cookie of the page, in the browser.
+#[cfg(all(target_arch = "wasm32", any(feature = "yew", feature = "dio")))]
+pub(crate) fn read_document_cookie(name: &str) -> Option<String> {
+    use web_sys::wasm_bindgen::JsCast;
+
+    let cookies = web_sys::window()?
+        .document()?
+        .dyn_into::<web_sys::HtmlDocument>()
+        .ok()?
+        .cookie()
+        .ok()?;
+    Cookie::get(&cookies, name).map(str::to_owned)
+}
+
+/// Sets a cookie of the page, in the browser.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn write_document_cookie(cookie: &Cookie) -> Result<(), String> {
+    use web_sys::wasm_bindgen::JsCast;
+
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.dyn_into::<web_sys::HtmlDocument>().ok())
+        .ok_or("No document available")?
+        .set_cookie(&cookie.to_string())
+        .map_err(|_| format!("Failed to write the '{}' cookie", cookie.name()))
+}
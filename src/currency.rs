@@ -0,0 +1,104 @@
+//! Locale-aware currency formatting.
+//!
+//! Formats monetary amounts with the CLDR data compiled into ICU4X, so the currency symbol,
+//! its position, the separators and the number of fraction digits follow the conventions
+//! of the current language and currency. Requires the `currency` feature.
+
+use crate::cldr::locale;
+use fixed_decimal::{Decimal, FloatPrecision};
+use icu_experimental::dimension::currency::CurrencyType;
+use icu_experimental::dimension::currency::formatter::CurrencyFormatter;
+
+/// How the currency of a formatted amount is written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CurrencyStyle {
+    /// The localized symbol (e.g., `$12.50`, `12,50 €`).
+    #[default]
+    Symbol,
+    /// The ISO 4217 code (e.g., `USD 12.50`).
+    Code,
+    /// The localized name of the currency (e.g., `12.50 US dollars`).
+    Name,
+}
+
+/// An amount that can be formatted as money.
+///
+/// Implemented for `f64` and the integer types and, with the `rust_decimal` feature, for
+/// `rust_decimal::Decimal`, which keeps every digit of the amount.
+pub trait ToDecimal {
+    /// Converts the amount to an exact decimal.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the amount is not a finite number.
+    fn to_fixed_decimal(&self) -> Result<Decimal, String>;
+}
+
+impl ToDecimal for f64 {
+    fn to_fixed_decimal(&self) -> Result<Decimal, String> {
+        Decimal::try_from_f64(*self, FloatPrecision::RoundTrip)
+            .map_err(|_| format!("Cannot format '{}' as a number", self))
+    }
+}
+
+macro_rules! impl_to_decimal {
+    ($($int:ty),*) => {
+        $(impl ToDecimal for $int {
+            fn to_fixed_decimal(&self) -> Result<Decimal, String> {
+                Ok(Decimal::from(*self))
+            }
+        })*
+    };
+}
+
+impl_to_decimal!(i32, i64, i128, u32, u64, u128);
+
+/// Formats a monetary amount for a language.
+///
+/// The amount is rounded to the fraction digits of the currency (two for `USD`, none for
+/// `JPY`).
+///
+/// # Arguments
+/// - `language`: The language code to format for (e.g., `"de"`).
+/// - `amount`: The amount, e.g. an `f64` or, with the `rust_decimal` feature, a
+///   `rust_decimal::Decimal`.
+/// - `currency`: The ISO 4217 currency code (e.g., `"EUR"`).
+/// - `style`: How to write the currency.
+///
+/// # Returns
+/// - `Ok(String)` with the formatted amount.
+/// - `Err(String)` if the language or currency code is invalid, or the amount is not finite.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::currency::{CurrencyStyle, format_currency};
+///
+/// assert_eq!(format_currency("en", &1234.5, "USD", CurrencyStyle::Symbol).unwrap(), "$1,234.50");
+/// assert_eq!(
+///     format_currency("de", &1234.5, "EUR", CurrencyStyle::Symbol).unwrap(),
+///     "1.234,50\u{a0}€"
+/// );
+/// assert_eq!(format_currency("en", &1500, "JPY", CurrencyStyle::Code).unwrap(), "JPY\u{a0}1,500");
+/// ```
+pub fn format_currency(
+    language: &str,
+    amount: &impl ToDecimal,
+    currency: &str,
+    style: CurrencyStyle,
+) -> Result<String, String> {
+    let locale = locale(language)?;
+    let code = CurrencyType::try_from_str(currency)
+        .map_err(|_| format!("Invalid currency code '{}'", currency))?;
+    let amount = amount.to_fixed_decimal()?;
+
+    let formatter = match style {
+        CurrencyStyle::Symbol => {
+            CurrencyFormatter::try_new_symbol(locale.into(), code, Default::default())
+        }
+        CurrencyStyle::Code => {
+            CurrencyFormatter::try_new_code(locale.into(), code, Default::default())
+        }
+        CurrencyStyle::Name => CurrencyFormatter::try_new_name(locale.into(), code),
+    }
+    .map_err(|err| format!("No currency formats for '{}': {}", language, err))?;
+    Ok(formatter.format_fixed_decimal(&amount).to_string())
+}
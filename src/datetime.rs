@@ -0,0 +1,636 @@
+//! Locale-aware date and time formatting.
+//!
+//! Formats dates with the CLDR data compiled into ICU4X, so month and weekday names, the
+//! order of the fields and the separators follow the conventions of the current language.
+//! Requires the `datetime` feature.
+
+use crate::cldr::locale;
+use icu_calendar::Gregorian;
+use icu_calendar::week::WeekInformation;
+use icu_datetime::fieldsets::builder::{DateFields, FieldSetBuilder};
+use icu_datetime::fieldsets::{T, YMD, YMDE};
+use icu_datetime::options::{Length, TimePrecision};
+use icu_datetime::pattern::{DateTimePattern, FixedCalendarDateTimeNames};
+use icu_datetime::preferences::HourCycle as IcuHourCycle;
+use icu_datetime::{DateTimeFormatter, DateTimeFormatterPreferences, NoCalendarFormatter};
+use icu_time::zone::models::AtTime;
+use writeable::TryWriteable;
+
+pub use icu_calendar::Iso;
+pub use icu_calendar::types::Weekday;
+pub use icu_datetime::fieldsets::builder::ZoneStyle;
+pub use icu_datetime::input::{Date, DateTime, Time, TimeZone, TimeZoneInfo, UtcOffset};
+
+/// A local date and time in a time zone, as accepted by `format_zoned`.
+pub type ZonedDateTime = icu_datetime::input::ZonedDateTime<Iso, TimeZoneInfo<AtTime>>;
+
+/// A value that can be formatted as a date.
+///
+/// Implemented for the ICU4X types re-exported by this module and, with the `chrono`
+/// feature, for `chrono::NaiveDate`, `NaiveDateTime` and `DateTime`, or with the `jiff`
+/// feature, for `jiff::civil::Date`, `civil::DateTime` and `Zoned`.
+pub trait ToDate {
+    /// Converts the value to an ISO date.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the date is outside the range supported by ICU4X.
+    fn to_iso_date(&self) -> Result<Date<Iso>, String>;
+}
+
+/// A value that can be formatted as a date and time.
+///
+/// Values without a time of day, such as a `Date`, are taken at midnight.
+pub trait ToDateTime {
+    /// Converts the value to an ISO date and time.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the date is outside the range supported by ICU4X.
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String>;
+}
+
+/// A value that can be formatted as a date and time in a time zone.
+pub trait ToZonedDateTime {
+    /// Converts the value to a zoned ISO date and time.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the date or the offset is outside the range supported by ICU4X.
+    fn to_zoned_datetime(&self) -> Result<ZonedDateTime, String>;
+}
+
+impl ToDate for Date<Iso> {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        Ok(*self)
+    }
+}
+
+impl ToDate for DateTime<Iso> {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        Ok(self.date)
+    }
+}
+
+impl ToDate for ZonedDateTime {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        Ok(self.date)
+    }
+}
+
+impl ToDateTime for Date<Iso> {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        Ok(DateTime {
+            date: *self,
+            time: Time::start_of_day(),
+        })
+    }
+}
+
+impl ToDateTime for DateTime<Iso> {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        Ok(*self)
+    }
+}
+
+impl ToDateTime for ZonedDateTime {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        Ok(DateTime {
+            date: self.date,
+            time: self.time,
+        })
+    }
+}
+
+impl ToZonedDateTime for ZonedDateTime {
+    fn to_zoned_datetime(&self) -> Result<ZonedDateTime, String> {
+        Ok(*self)
+    }
+}
+
+/// The length of a formatted date.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DateStyle {
+    /// Numeric fields only (e.g., `1/15/25`).
+    Short,
+    /// An abbreviated month name (e.g., `Jan 15, 2025`).
+    #[default]
+    Medium,
+    /// The full month name (e.g., `January 15, 2025`).
+    Long,
+    /// The full month name and the weekday (e.g., `Wednesday, January 15, 2025`).
+    Full,
+}
+
+/// The week conventions of a locale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeekInfo {
+    /// The first day of the week, i.e. the first column of a calendar.
+    pub first_day: Weekday,
+    /// The days of the weekend, in week order. They are not always contiguous.
+    pub weekend: Vec<Weekday>,
+}
+
+impl WeekInfo {
+    /// Returns the seven days of the week, starting with `first_day`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::datetime::{Weekday, week_info};
+    ///
+    /// let days = week_info("en-US").unwrap().days();
+    /// assert_eq!(days[0], Weekday::Sunday);
+    /// assert_eq!(days[6], Weekday::Saturday);
+    /// ```
+    pub fn days(&self) -> [Weekday; 7] {
+        std::array::from_fn(|offset| {
+            Weekday::from_days_since_sunday(self.first_day as isize + offset as isize)
+        })
+    }
+
+    /// Checks whether a day is part of the weekend.
+    pub fn is_weekend(&self, day: Weekday) -> bool {
+        self.weekend.contains(&day)
+    }
+}
+
+/// Resolves the first day of the week and the weekend days of a language.
+///
+/// The week conventions are regional, so a language without a region uses the region it
+/// is most likely spoken in. The `-u-fw-` locale extension overrides the first day (e.g.,
+/// `"en-US-u-fw-mon"`).
+///
+/// # Arguments
+/// - `language`: The language code (e.g., `"en-US"` or `"ar-EG"`).
+///
+/// # Returns
+/// - `Ok(WeekInfo)` with the week conventions.
+/// - `Err(String)` if the language code is not a valid locale.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::datetime::{Weekday, week_info};
+///
+/// let us = week_info("en-US").unwrap();
+/// assert_eq!(us.first_day, Weekday::Sunday);
+/// assert_eq!(us.weekend, [Weekday::Sunday, Weekday::Saturday]);
+///
+/// assert_eq!(week_info("de").unwrap().first_day, Weekday::Monday);
+///
+/// let egypt = week_info("ar-EG").unwrap();
+/// assert_eq!(egypt.first_day, Weekday::Saturday);
+/// assert_eq!(egypt.weekend, [Weekday::Saturday, Weekday::Friday]);
+/// ```
+pub fn week_info(language: &str) -> Result<WeekInfo, String> {
+    let info = WeekInformation::try_new(locale(language)?.into())
+        .map_err(|err| format!("No week data for '{}': {}", language, err))?;
+    Ok(WeekInfo {
+        first_day: info.first_weekday,
+        weekend: info.weekend().collect(),
+    })
+}
+
+/// The clock convention of a locale.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HourCycle {
+    /// A 12-hour clock with a day period (e.g., `5:05 PM`).
+    H12,
+    /// A 24-hour clock (e.g., `17:05`).
+    #[default]
+    H23,
+}
+
+impl HourCycle {
+    /// Checks whether times are shown on a 12-hour clock.
+    pub fn is_12_hour(self) -> bool {
+        self == HourCycle::H12
+    }
+}
+
+/// Resolves whether a language prefers 12-hour or 24-hour time.
+///
+/// The convention is regional, so a language without a region uses the region it is most
+/// likely spoken in. The `-u-hc-` locale extension overrides it (e.g., `"en-US-u-hc-h23"`).
+///
+/// # Arguments
+/// - `language`: The language code (e.g., `"en-GB"`).
+///
+/// # Returns
+/// - `Ok(HourCycle)` with the preferred clock.
+/// - `Err(String)` if the language code is not a valid locale.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::datetime::{HourCycle, hour_cycle};
+///
+/// assert_eq!(hour_cycle("en").unwrap(), HourCycle::H12);
+/// assert_eq!(hour_cycle("en-GB").unwrap(), HourCycle::H23);
+/// assert_eq!(hour_cycle("fr").unwrap(), HourCycle::H23);
+/// assert_eq!(hour_cycle("en-US-u-hc-h23").unwrap(), HourCycle::H23);
+/// ```
+pub fn hour_cycle(language: &str) -> Result<HourCycle, String> {
+    let formatter = NoCalendarFormatter::try_new(locale(language)?.into(), T::hm())
+        .map_err(|err| format!("No time formats for '{}': {}", language, err))?;
+    let noon = Time::try_new(12, 0, 0, 0).map_err(|err| err.to_string())?;
+    let pattern = formatter.format(&noon).pattern().to_string();
+
+    // `h` and `K` are the hour symbols of 12-hour clocks; text in quotes is literal.
+    let mut quoted = false;
+    for symbol in pattern.chars() {
+        match symbol {
+            '\'' => quoted = !quoted,
+            'h' | 'K' if !quoted => return Ok(HourCycle::H12),
+            _ => {}
+        }
+    }
+    Ok(HourCycle::H23)
+}
+
+/// Formats a date for a language.
+///
+/// # Arguments
+/// - `language`: The language code to format for (e.g., `"fr"`).
+/// - `date`: The date to format, e.g., a `Date` or, with the `chrono` feature, a
+///   `chrono::NaiveDate`.
+/// - `style`: The length of the output.
+///
+/// # Returns
+/// - `Ok(String)` with the formatted date.
+/// - `Err(String)` if the language code is not a valid locale or the date is out of range.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::datetime::{Date, DateStyle, format_date};
+///
+/// let date = Date::try_new_iso(2025, 1, 15).unwrap();
+/// assert_eq!(format_date("en", &date, DateStyle::Long).unwrap(), "January 15, 2025");
+/// assert_eq!(format_date("fr", &date, DateStyle::Long).unwrap(), "15 janvier 2025");
+/// ```
+pub fn format_date(language: &str, date: &impl ToDate, style: DateStyle) -> Result<String, String> {
+    let locale = locale(language)?;
+    let date = &date.to_iso_date()?;
+    let error = |err: icu_datetime::DateTimeFormatterLoadError| {
+        format!("No date formats for '{}': {}", language, err)
+    };
+
+    let formatted = match style {
+        DateStyle::Short => DateTimeFormatter::try_new(locale.into(), YMD::short())
+            .map_err(error)?
+            .format(date)
+            .to_string(),
+        DateStyle::Medium => DateTimeFormatter::try_new(locale.into(), YMD::medium())
+            .map_err(error)?
+            .format(date)
+            .to_string(),
+        DateStyle::Long => DateTimeFormatter::try_new(locale.into(), YMD::long())
+            .map_err(error)?
+            .format(date)
+            .to_string(),
+        DateStyle::Full => DateTimeFormatter::try_new(locale.into(), YMDE::long())
+            .map_err(error)?
+            .format(date)
+            .to_string(),
+    };
+    Ok(formatted)
+}
+
+/// Formats a date and time for a language from a CLDR skeleton.
+///
+/// A skeleton only lists the fields to show (e.g., `"yMMMd"` or `"Hm"`); their order,
+/// separators and punctuation come from the locale. The supported symbols are:
+///
+/// - `y`: the year.
+/// - `M`/`L`: the month; `M` and `MM` are numeric, `MMM` abbreviated and `MMMM` full.
+/// - `d`: the day of the month.
+/// - `E`/`c`: the weekday; `EEEE` is the full name.
+/// - `j`: the hour in the locale's preferred cycle, `H`/`k` forces 24-hour and `h`/`K`
+///   12-hour time.
+/// - `m`, `s`: the minutes and seconds.
+/// - `a`: the day period, implied by 12-hour time.
+///
+/// # Arguments
+/// - `language`: The language code to format for (e.g., `"de"`).
+/// - `datetime`: The date and time to format, e.g., a `DateTime` or a `Date` at midnight.
+///   Fields absent from the skeleton are ignored.
+/// - `skeleton`: The skeleton describing the fields to show.
+///
+/// # Returns
+/// - `Ok(String)` with the formatted value.
+/// - `Err(String)` if the language code or the skeleton is invalid, or the combination of
+///   fields is not supported by CLDR.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::datetime::{Date, DateTime, Time, format_skeleton};
+///
+/// let datetime = DateTime {
+///     date: Date::try_new_iso(2025, 1, 15).unwrap(),
+///     time: Time::try_new(17, 5, 0, 0).unwrap(),
+/// };
+/// assert_eq!(format_skeleton("en", &datetime, "yMMMd").unwrap(), "Jan 15, 2025");
+/// assert_eq!(format_skeleton("de", &datetime, "yMMMMd").unwrap(), "15. Januar 2025");
+/// assert_eq!(format_skeleton("en", &datetime, "Hm").unwrap(), "17:05");
+/// ```
+pub fn format_skeleton(
+    language: &str,
+    datetime: &impl ToDateTime,
+    skeleton: &str,
+) -> Result<String, String> {
+    let datetime = &datetime.to_iso_datetime()?;
+    let mut prefs = DateTimeFormatterPreferences::from(locale(language)?);
+    let invalid = |reason: &str| format!("Invalid skeleton '{}': {}", skeleton, reason);
+
+    let (mut year, mut month, mut day, mut weekday) = (false, 0, false, 0);
+    let (mut hour, mut minute, mut second) = (false, false, false);
+    let mut chars = skeleton.chars().peekable();
+    while let Some(symbol) = chars.next() {
+        let mut count = 1;
+        while chars.next_if_eq(&symbol).is_some() {
+            count += 1;
+        }
+        match symbol {
+            'y' => year = true,
+            'M' | 'L' => month = count,
+            'd' => day = true,
+            'E' | 'c' => weekday = count,
+            'j' => hour = true,
+            'H' | 'k' => {
+                hour = true;
+                prefs.hour_cycle = Some(IcuHourCycle::H23);
+            }
+            'h' | 'K' => {
+                hour = true;
+                prefs.hour_cycle = Some(IcuHourCycle::H12);
+            }
+            'm' => minute = true,
+            's' => second = true,
+            'a' => {}
+            other => return Err(invalid(&format!("unsupported symbol '{}'", other))),
+        }
+    }
+
+    let mut builder = FieldSetBuilder::new();
+    builder.date_fields = match (year, month > 0, day, weekday > 0) {
+        (false, false, false, false) => None,
+        (false, false, true, false) => Some(DateFields::D),
+        (false, true, true, false) => Some(DateFields::MD),
+        (true, true, true, false) => Some(DateFields::YMD),
+        (false, false, true, true) => Some(DateFields::DE),
+        (false, true, true, true) => Some(DateFields::MDE),
+        (true, true, true, true) => Some(DateFields::YMDE),
+        (false, false, false, true) => Some(DateFields::E),
+        (false, true, false, false) => Some(DateFields::M),
+        (true, true, false, false) => Some(DateFields::YM),
+        (true, false, false, false) => Some(DateFields::Y),
+        _ => return Err(invalid("unsupported combination of date fields")),
+    };
+    builder.time_precision = match (hour, minute, second) {
+        (false, false, false) => None,
+        (true, false, false) => Some(TimePrecision::Hour),
+        (true, true, false) => Some(TimePrecision::Minute),
+        (true, true, true) => Some(TimePrecision::Second),
+        _ => return Err(invalid("minutes and seconds require an hour")),
+    };
+    if builder.date_fields.is_some() {
+        builder.length = Some(match (month, weekday) {
+            (4.., _) | (0, 4..) => Length::Long,
+            (3, _) | (0, _) => Length::Medium,
+            _ => Length::Short,
+        });
+    }
+
+    let field_set = builder
+        .build_composite_datetime()
+        .map_err(|err| invalid(&err.to_string()))?;
+    let formatter = DateTimeFormatter::try_new(prefs, field_set)
+        .map_err(|err| format!("No date formats for '{}': {}", language, err))?;
+    Ok(formatter.format(datetime).to_string())
+}
+
+/// Formats a date and time for a language with an explicit CLDR pattern.
+///
+/// Unlike a skeleton, the pattern fixes the order of the fields and the literal text
+/// (quoted with `'`), while month, weekday and day period names are still localized.
+/// Prefer `format_skeleton` unless an exact shape is required.
+///
+/// # Arguments
+/// - `language`: The language code to format for (e.g., `"fr"`).
+/// - `datetime`: The date and time to format.
+/// - `pattern`: The pattern (e.g., `"EEEE d MMMM y 'at' HH:mm"`).
+///
+/// # Returns
+/// - `Ok(String)` with the formatted value.
+/// - `Err(String)` if the language code or the pattern is invalid.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::datetime::{Date, DateTime, Time, format_pattern};
+///
+/// let datetime = DateTime {
+///     date: Date::try_new_iso(2025, 1, 15).unwrap(),
+///     time: Time::try_new(17, 5, 0, 0).unwrap(),
+/// };
+/// assert_eq!(
+///     format_pattern("fr", &datetime, "EEEE d MMMM y, HH:mm").unwrap(),
+///     "mercredi 15 janvier 2025, 17:05"
+/// );
+/// ```
+pub fn format_pattern(
+    language: &str,
+    datetime: &impl ToDateTime,
+    pattern: &str,
+) -> Result<String, String> {
+    let datetime = datetime.to_iso_datetime()?;
+    let prefs = DateTimeFormatterPreferences::from(locale(language)?);
+    let pattern: DateTimePattern = pattern
+        .parse()
+        .map_err(|err| format!("Invalid pattern '{}': {}", pattern, err))?;
+    let mut names = FixedCalendarDateTimeNames::<Gregorian>::try_new(prefs)
+        .map_err(|err| format!("No date formats for '{}': {}", language, err))?;
+    let formatter = names
+        .include_for_pattern(&pattern)
+        .map_err(|err| format!("No date formats for '{}': {}", language, err))?;
+    let datetime = DateTime {
+        date: datetime.date.to_calendar(Gregorian),
+        time: datetime.time,
+    };
+    formatter
+        .format(&datetime)
+        .try_write_to_string()
+        .map(|formatted| formatted.into_owned())
+        .map_err(|(err, _)| format!("Failed to format '{}': {}", language, err))
+}
+
+/// Attaches a time zone to a local date and time.
+///
+/// # Arguments
+/// - `datetime`: The local (wall clock) date and time.
+/// - `iana_id`: The IANA identifier of the time zone (e.g., `"Europe/Paris"`). Unknown
+///   identifiers fall back to the offset when formatting.
+/// - `offset`: The offset from UTC in effect at `datetime`, which decides between e.g.
+///   standard and daylight time.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::datetime::{Date, DateTime, Time, UtcOffset, zoned};
+///
+/// let datetime = DateTime {
+///     date: Date::try_new_iso(2025, 7, 14).unwrap(),
+///     time: Time::try_new(9, 30, 0, 0).unwrap(),
+/// };
+/// let offset = UtcOffset::try_from_seconds(2 * 3600).unwrap();
+/// let zoned = zoned(datetime, "Europe/Paris", offset);
+/// assert_eq!(zoned.zone.offset(), Some(offset));
+/// ```
+pub fn zoned(datetime: DateTime<Iso>, iana_id: &str, offset: UtcOffset) -> ZonedDateTime {
+    let zone = TimeZone::from_iana_id(iana_id)
+        .with_offset(Some(offset))
+        .at_date_time(datetime);
+    ZonedDateTime {
+        date: datetime.date,
+        time: datetime.time,
+        zone,
+    }
+}
+
+/// Formats a date and time with its time zone for a language.
+///
+/// The zone is written in the words of the language, either as a name (`"heure d'été
+/// d'Europe centrale"`, `"Central European Time"`) or as a GMT offset (`"GMT+2"`),
+/// depending on `zone_style`. Names fall back to the offset when CLDR has none for the
+/// zone and offset.
+///
+/// # Arguments
+/// - `language`: The language code to format for (e.g., `"fr"`).
+/// - `datetime`: The zoned date and time, e.g., built with `zoned` or, with the `chrono`
+///   feature, a `chrono::DateTime`.
+/// - `style`: The length of the date; the time is shown with minutes.
+/// - `zone_style`: How to write the time zone.
+///
+/// # Returns
+/// - `Ok(String)` with the formatted value.
+/// - `Err(String)` if the language code is not a valid locale.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::datetime::{Date, DateStyle, DateTime, Time, UtcOffset, ZoneStyle};
+/// use i18nrs::datetime::{format_zoned, zoned};
+///
+/// let datetime = DateTime {
+///     date: Date::try_new_iso(2025, 7, 14).unwrap(),
+///     time: Time::try_new(9, 30, 0, 0).unwrap(),
+/// };
+/// let paris = zoned(datetime, "Europe/Paris", UtcOffset::try_from_seconds(7200).unwrap());
+///
+/// assert_eq!(
+///     format_zoned("fr", &paris, DateStyle::Long, ZoneStyle::SpecificLong).unwrap(),
+///     "14 juillet 2025 à 09:30 heure d’été d’Europe centrale"
+/// );
+/// assert_eq!(
+///     format_zoned("en", &paris, DateStyle::Medium, ZoneStyle::LocalizedOffsetShort).unwrap(),
+///     "Jul 14, 2025, 9:30\u{202f}AM GMT+2"
+/// );
+/// ```
+pub fn format_zoned(
+    language: &str,
+    datetime: &impl ToZonedDateTime,
+    style: DateStyle,
+    zone_style: ZoneStyle,
+) -> Result<String, String> {
+    let datetime = &datetime.to_zoned_datetime()?;
+    let mut builder = FieldSetBuilder::new();
+    (builder.date_fields, builder.length) = match style {
+        DateStyle::Short => (Some(DateFields::YMD), Some(Length::Short)),
+        DateStyle::Medium => (Some(DateFields::YMD), Some(Length::Medium)),
+        DateStyle::Long => (Some(DateFields::YMD), Some(Length::Long)),
+        DateStyle::Full => (Some(DateFields::YMDE), Some(Length::Long)),
+    };
+    builder.time_precision = Some(TimePrecision::Minute);
+    builder.zone_style = Some(zone_style);
+    let field_set = builder
+        .build_composite()
+        .map_err(|err| format!("Unsupported zone style: {}", err))?;
+
+    let formatter = DateTimeFormatter::try_new(locale(language)?.into(), field_set)
+        .map_err(|err| format!("No date formats for '{}': {}", language, err))?;
+    Ok(formatter.format(datetime).to_string())
+}
+
+/// Parses the ISO 8601 text of a date or a date and time.
+///
+/// Accepts `2025-01-15`, `2025-01-15T17:05` and `2025-01-15 17:05:00`, ignoring fractional
+/// seconds and any offset or zone that follows, which covers the `Display` output of the
+/// date types of `chrono`, `jiff` and `time`.
+fn parse_iso(value: &str) -> Option<DateTime<Iso>> {
+    fn field<T: std::str::FromStr>(value: &str, range: std::ops::Range<usize>) -> Option<T> {
+        value.get(range)?.parse().ok()
+    }
+    let separator = |index: usize, expected: &[u8]| {
+        value
+            .as_bytes()
+            .get(index)
+            .is_some_and(|byte| expected.contains(byte))
+    };
+
+    if !separator(4, b"-") || !separator(7, b"-") {
+        return None;
+    }
+    let date = Date::try_new_iso(
+        field(value, 0..4)?,
+        field(value, 5..7)?,
+        field(value, 8..10)?,
+    );
+    let time = if value.len() == 10 {
+        Time::start_of_day()
+    } else if separator(10, b"T ") && separator(13, b":") {
+        let second = if separator(16, b":") {
+            field(value, 17..19)?
+        } else {
+            0
+        };
+        Time::try_new(field(value, 11..13)?, field(value, 14..16)?, second, 0).ok()?
+    } else {
+        return None;
+    };
+    Some(DateTime {
+        date: date.ok()?,
+        time,
+    })
+}
+
+/// Formats the value of a typed placeholder such as `{due, date, long}`.
+///
+/// The format is the part of the placeholder after the name: `date`, `time` or `datetime`,
+/// optionally followed by a style (`short`, `medium`, `long` or `full`) or a skeleton
+/// prefixed with `::` (e.g., `{due, date, ::yMMMd}`). The value is the `Display` output of
+/// the argument, read as ISO 8601.
+///
+/// # Returns
+/// - `None` if the format is unknown or the value is not an ISO 8601 date.
+pub(crate) fn format_argument(language: &str, value: &str, format: &str) -> Option<String> {
+    let (kind, style) = format
+        .split_once(',')
+        .map_or((format.trim(), ""), |(kind, style)| {
+            (kind.trim(), style.trim())
+        });
+    let datetime = parse_iso(value.trim())?;
+
+    if let Some(skeleton) = style.strip_prefix("::") {
+        return format_skeleton(language, &datetime, skeleton).ok();
+    }
+    let style = match style {
+        "short" => DateStyle::Short,
+        "" | "medium" => DateStyle::Medium,
+        "long" => DateStyle::Long,
+        "full" => DateStyle::Full,
+        _ => return None,
+    };
+    let skeleton = match (kind, style) {
+        ("date", _) => return format_date(language, &datetime, style).ok(),
+        ("time", DateStyle::Short) => "jm",
+        ("time", _) => "jms",
+        ("datetime", DateStyle::Short) => "yMdjm",
+        ("datetime", DateStyle::Medium) => "yMMMdjm",
+        ("datetime", DateStyle::Long) => "yMMMMdjm",
+        ("datetime", DateStyle::Full) => "yMMMMEEEEdjm",
+        _ => return None,
+    };
+    format_skeleton(language, &datetime, skeleton).ok()
+}
@@ -0,0 +1,218 @@
+//! Resolution of the initial language of a provider.
+//!
+//! A `DetectionOrder` lists where a provider looks for the language to start in, such as
+//! the URL, the persistence cookie, browser storage or the browser's preferred languages.
+//! The first source naming an available language wins; the provider falls back to its
+//! `default_language` when none does.
+
+use crate::negotiate::accept_language;
+#[cfg(any(feature = "yew", feature = "dio"))]
+use crate::{config::StorageType, lifecycle::ChangeSource};
+#[cfg(any(feature = "yew", feature = "dio"))]
+use std::collections::HashMap;
+#[cfg(all(target_arch = "wasm32", any(feature = "yew", feature = "dio")))]
+use web_sys::window;
+
+/// A place the initial language can be read from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DetectionSource {
+    /// The `url_param` query parameter of the page URL (e.g., `?lang=fr`).
+    Query,
+    /// The `hash_param` fragment parameter of the page URL (e.g., `#lang=fr`). Only
+    /// visible to the browser.
+    Hash,
+    /// The cookie named after `storage_name`, also sent to the server when rendering.
+    Cookie,
+    /// The `storage_name` entry of the `storage_type` browser storage.
+    Storage,
+    /// The preferred languages of the browser (`navigator.languages`), or the
+    /// `Accept-Language` header of the request being server-side rendered.
+    Navigator,
+}
+
+/// The sources a provider reads its initial language from, by priority.
+///
+/// Defaults to the URL, then the cookie, browser storage and the browser's preferred
+/// languages. Sources that aren't configured, such as `Query` without a `url_param`, are
+/// skipped.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::detect::{DetectionOrder, DetectionSource};
+///
+/// // Ignore the browser's languages; only honor an explicit choice.
+/// let order = DetectionOrder::new([DetectionSource::Query, DetectionSource::Storage]);
+///
+/// let detected = order.resolve(&["en", "fr"], |source| match source {
+///     DetectionSource::Query => Some("de".to_string()),
+///     DetectionSource::Storage => Some("fr".to_string()),
+///     _ => None,
+/// });
+/// assert_eq!(detected, Some(("fr", DetectionSource::Storage)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DetectionOrder(Vec<DetectionSource>);
+
+impl DetectionOrder {
+    /// Creates an order from sources, highest priority first.
+    pub fn new(sources: impl IntoIterator<Item = DetectionSource>) -> Self {
+        Self(sources.into_iter().collect())
+    }
+
+    /// The sources, highest priority first.
+    pub fn sources(&self) -> &[DetectionSource] {
+        &self.0
+    }
+
+    /// Resolves the language from the first source naming an available language.
+    ///
+    /// Sources are read lazily, in order. `Navigator` values are matched like an
+    /// `Accept-Language` header (see `negotiate::accept_language`), so `"fr-CH"` matches
+    /// `"fr"`; the other sources must name an available language exactly.
+    ///
+    /// # Arguments
+    /// - `available`: The language codes the provider has translations for.
+    /// - `read`: Reads the raw value of a source, if it has one.
+    ///
+    /// # Returns
+    /// - `Some((&str, DetectionSource))` with the language and the source naming it.
+    /// - `None` if no source names an available language.
+    pub fn resolve<'a>(
+        &self,
+        available: &[&'a str],
+        mut read: impl FnMut(DetectionSource) -> Option<String>,
+    ) -> Option<(&'a str, DetectionSource)> {
+        self.0.iter().find_map(|source| {
+            let value = read(*source)?;
+            let language = match source {
+                DetectionSource::Navigator => accept_language(&value, available),
+                _ => available.iter().find(|code| **code == value).copied(),
+            }?;
+            Some((language, *source))
+        })
+    }
+}
+
+impl Default for DetectionOrder {
+    fn default() -> Self {
+        Self::new([
+            DetectionSource::Query,
+            DetectionSource::Hash,
+            DetectionSource::Cookie,
+            DetectionSource::Storage,
+            DetectionSource::Navigator,
+        ])
+    }
+}
+
+#[cfg(any(feature = "yew", feature = "dio"))]
+impl DetectionSource {
+    /// The lifecycle source reported for a language detected by this source.
+    pub(crate) fn change_source(self) -> ChangeSource {
+        match self {
+            DetectionSource::Query | DetectionSource::Hash => ChangeSource::Url,
+            DetectionSource::Cookie | DetectionSource::Storage => ChangeSource::Storage,
+            DetectionSource::Navigator => ChangeSource::Detection,
+        }
+    }
+}
+
+/// Lists the languages a provider can start in: its `supported_languages` if restricted,
+/// otherwise every embedded, remote and compressed bundle.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn provider_languages<T>(
+    translations: &HashMap<&'static str, &'static str>,
+    urls: &HashMap<&'static str, &'static str>,
+    compressed: &HashMap<&'static str, T>,
+    supported: &[&'static str],
+) -> Vec<&'static str> {
+    if !supported.is_empty() {
+        return supported.to_vec();
+    }
+    let mut languages: Vec<&'static str> = translations
+        .keys()
+        .chain(urls.keys())
+        .chain(compressed.keys())
+        .copied()
+        .collect();
+    languages.sort_unstable();
+    languages.dedup();
+    languages
+}
+
+/// Reads a detection source in the browser. Returns `None` elsewhere.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn read_browser(
+    source: DetectionSource,
+    storage_type: &StorageType,
+    storage_name: &str,
+    url_param: Option<&str>,
+    hash_param: Option<&str>,
+) -> Option<String> {
+    match source {
+        DetectionSource::Query => url_param.and_then(crate::url::read_query_param),
+        DetectionSource::Hash => hash_param.and_then(crate::url::read_hash_param),
+        DetectionSource::Cookie => browser_cookie(storage_name),
+        DetectionSource::Storage => browser_storage(storage_type, storage_name),
+        DetectionSource::Navigator => browser_languages(),
+    }
+}
+
+/// Reads a cookie of the page.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn browser_cookie(_name: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        crate::cookie::read_document_cookie(_name)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Reads an entry of browser storage.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn browser_storage(_storage_type: &StorageType, _key: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let window = window()?;
+        let storage = match _storage_type {
+            StorageType::LocalStorage => window.local_storage(),
+            StorageType::SessionStorage => window.session_storage(),
+            StorageType::Cookie => return crate::cookie::read_document_cookie(_key),
+            StorageType::UrlQuery => return crate::url::read_query_param(_key),
+        };
+        storage.ok().flatten()?.get_item(_key).ok().flatten()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Reads the preferred languages of the browser, as an `Accept-Language` list.
+#[cfg(any(feature = "yew", feature = "dio"))]
+fn browser_languages() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let navigator = window()?.navigator();
+        let languages: Vec<String> = navigator
+            .languages()
+            .iter()
+            .filter_map(|language| language.as_string())
+            .collect();
+        if languages.is_empty() {
+            navigator.language()
+        } else {
+            Some(languages.join(", "))
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
@@ -1,10 +1,38 @@
 #![doc = include_str!("../DIOXUS.md")]
 
-use crate::config::{I18n, I18nConfig, StorageType};
+use crate::compression::decompress;
+use crate::config::{
+    I18n, I18nArgs, I18nConfig, StorageType, TextDirection, check_bundle_schema, interpolate,
+    with_placeholders,
+};
+use crate::detect::{DetectionOrder, DetectionSource, provider_languages, read_browser};
+#[cfg(target_arch = "wasm32")]
+use crate::detect::{browser_cookie, browser_storage};
+#[cfg(feature = "editor")]
+use crate::editor::{EDITOR_STYLE, TranslationEdits};
+use crate::global::set_global;
+use crate::guard::{LanguageChange, LanguageGuard};
+use crate::languages::LanguageInfo;
+use crate::lifecycle::{ChangeSource, LifecycleEvent};
+use crate::loader::NamespaceLoaders;
+use crate::remote::{cached_bundle, fetch_cached};
+use crate::report::MissingKeyReporter;
+use crate::seo::{alternate_links, meta_tags};
+use crate::trans::{TransNode, parse};
+use crate::url::{write_hash_param, write_query_param};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::watch::{LocaleWatcher, sleep};
+use dioxus::core::Task;
 use dioxus::prelude::*;
-use std::collections::HashMap;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+#[cfg(feature = "dio-ssr")]
+use std::{future::Future, pin::Pin, sync::OnceLock};
 #[cfg(target_arch = "wasm32")]
-use web_sys::{Storage, wasm_bindgen::JsCast, window};
+use web_sys::window;
 
 /// Properties for the `I18nProvider` component.
 ///
@@ -27,7 +55,10 @@ pub struct I18nProviderProps {
     /// The type of browser storage to use.
     ///
     /// Determines how the selected language is persisted in the user's browser.
-    /// Options typically include `StorageType::LocalStorage` or `StorageType::SessionStorage`.
+    /// Options typically include `StorageType::LocalStorage`, `StorageType::SessionStorage`
+    /// or `StorageType::Cookie`;
+    /// `StorageType::UrlQuery` keeps it only in the `storage_name` query parameter of the URL,
+    /// without cookies or web storage.
     /// Defaults to `StorageType::LocalStorage`.
     #[props(default)]
     pub storage_type: StorageType,
@@ -46,6 +77,48 @@ pub struct I18nProviderProps {
     #[props(default = "en".to_string())]
     pub default_language: String,
 
+    /// Pins the provider to a language, ignoring storage, cookies and detection.
+    ///
+    /// Meant for pre-rendering each locale of a static site, where the language comes
+    /// from the page being generated (e.g., `/de/`) rather than from the visitor; see
+    /// `ssg::render_locales`. `set_language` still switches languages afterwards.
+    #[props(default)]
+    pub language: Option<String>,
+
+    /// Query parameter kept in sync with the current language (e.g., `"lang"`).
+    ///
+    /// Each language change rewrites the parameter in the address bar through the History
+    /// API, without a reload or a new history entry, so shared URLs open in the language
+    /// they were shared in. Defaults to `None`, leaving the URL untouched.
+    #[props(default)]
+    pub url_param: Option<String>,
+
+    /// Fragment parameter holding the language (e.g., `"lang"` for `#lang=fr`).
+    ///
+    /// A language in the fragment takes precedence over the stored one when the provider
+    /// starts, and each language change rewrites the fragment. Unlike query parameters,
+    /// fragments survive static hosts and caches that strip query strings. Defaults to
+    /// `None`, leaving the fragment untouched.
+    #[props(default)]
+    pub hash_param: Option<String>,
+
+    /// Where the initial language is read from, by priority; see `DetectionOrder`.
+    ///
+    /// The first source naming an available language wins, and `default_language` is
+    /// used when none does. Defaults to the URL (`url_param`, then `hash_param`), the
+    /// cookie, browser storage, then the browser's preferred languages.
+    #[props(default)]
+    pub detection: DetectionOrder,
+
+    /// Selectable languages, in order (e.g., `vec!["en", "de", "fr"]`).
+    ///
+    /// Restricts the languages that can be selected to these bundles, lists them in this
+    /// order in `available_languages`, and makes the first one the fallback for missing
+    /// keys, instead of relying on the iteration order of `translations`. Defaults to all
+    /// provided bundles.
+    #[props(default)]
+    pub supported_languages: Vec<&'static str>,
+
     /// Callback when the language changes.
     ///
     /// Invoked whenever the language is updated.
@@ -59,6 +132,198 @@ pub struct I18nProviderProps {
     /// Receives an error message as a `String`.
     #[props(default)]
     pub onerror: EventHandler<String>,
+
+    /// Renders the content shown when initialization fails.
+    ///
+    /// Receives the error message, e.g. malformed translation JSON, and is rendered in
+    /// place of `children`; `onerror` is still triggered. Without it, nothing is rendered.
+    #[props(default)]
+    pub error_fallback: Option<Callback<String, Element>>,
+
+    /// Content rendered instead of `children` while translations load.
+    ///
+    /// Shown while the remote bundle of the current language is fetched without a cached
+    /// copy, so apps can display a spinner instead of untranslated keys.
+    #[props(default)]
+    pub loading: Option<Element>,
+
+    /// Whether to suspend while translations load.
+    ///
+    /// Fetching the remote bundle of the current language suspends the provider, so the
+    /// nearest `SuspenseBoundary` renders its fallback until the translations arrive.
+    #[props(default)]
+    pub suspense: bool,
+
+    /// Lazy loaders for namespace bundles.
+    ///
+    /// Maps namespace names to loaders invoked the first time a component calls
+    /// `use_i18n_ns` with that namespace for the current language.
+    /// Defaults to an empty `HashMap`.
+    #[props(default)]
+    pub namespaces: NamespaceLoaders,
+
+    /// URLs of remotely hosted translation bundles.
+    ///
+    /// Maps language codes to the URL of their JSON bundle. The bundle of the current language
+    /// is fetched when it is first selected and replaces the embedded `translations` entry.
+    /// Responses are cached in `localStorage` and revalidated with `ETag`/`Last-Modified`.
+    /// If the fetch fails, the embedded translations are kept and `onerror` is triggered.
+    /// Defaults to an empty `HashMap`.
+    #[props(default)]
+    pub urls: HashMap<&'static str, &'static str>,
+
+    /// Gzip or Brotli compressed translation bundles.
+    ///
+    /// Maps language codes to compressed JSON (e.g. `include_bytes!("../i18n/en.json.gz")`),
+    /// decompressed once when the provider initializes. Requires the `compression` feature.
+    /// Defaults to an empty `HashMap`.
+    #[props(default)]
+    pub compressed_translations: HashMap<&'static str, &'static [u8]>,
+
+    /// Callback when a remote bundle replaces the translations being displayed.
+    ///
+    /// Cached remote bundles are served immediately and revalidated in the background.
+    /// Invoked with the language code when the revalidation returns newer translations
+    /// than the cached ones.
+    #[props(default)]
+    pub onupdated: EventHandler<String>,
+
+    /// Async guard awaited before a language switch requested through `set_language`
+    /// completes.
+    ///
+    /// Resolving to `false` cancels the switch, e.g. after the user declines to leave a
+    /// form with unsaved changes. Switches forced by removing the current language skip it.
+    #[props(default)]
+    pub onbeforechange: Option<LanguageGuard>,
+
+    /// Callback receiving a `Before` and an `After` event for each language change,
+    /// including the initial one, with the previous and new language and what triggered
+    /// the change (the user, storage, detection, ...).
+    #[props(default)]
+    pub onlifecycle: EventHandler<LifecycleEvent>,
+
+    /// Whether languages chosen by the user are saved to their profile on the server.
+    ///
+    /// With `dio-ssr`, each switch requested through `set_language` calls the
+    /// `save_language` server function, which hands the language to the saver registered
+    /// with `set_language_saver`, so the preference follows the user across devices.
+    /// Failures are reported through `onerror`. Defaults to `false`.
+    #[props(default)]
+    pub save_preference: bool,
+
+    /// Locale directory to watch for changes (e.g., `"assets/locales"`), in the layouts
+    /// read by `I18n::from_dir`.
+    ///
+    /// On native targets, such as desktop apps, the bundles are loaded over the embedded
+    /// translations and reloaded whenever a file changes, so edits show up without a
+    /// rebuild. Meant for development; ignored on the web.
+    #[props(default)]
+    pub locale_dir: Option<String>,
+
+    /// Overrides of the writing direction, by language code.
+    ///
+    /// Extends or overrides the built-in list of RTL languages, e.g. to treat `"dv"` or a
+    /// custom locale as RTL. Codes are matched in full first, then by primary subtag.
+    #[props(default)]
+    pub directions: HashMap<&'static str, TextDirection>,
+
+    /// Whether to also set `xml:lang` on the `<html>` element.
+    ///
+    /// The `lang` and `dir` attributes are always kept in sync with the current language;
+    /// enable this for XHTML documents that rely on `xml:lang`.
+    #[props(default)]
+    pub xml_lang: bool,
+
+    /// Whether to render translation keys instead of their values.
+    ///
+    /// A debug mode for QA and translators to identify which key drives which piece of UI.
+    /// Can be toggled at runtime.
+    #[props(default)]
+    pub show_keys: bool,
+
+    /// Override catalogs by tenant name, layered over `translations`; see `I18n::add_tenant`.
+    ///
+    /// Lets white-label products serve many brands from one bundle, e.g.
+    /// `HashMap::from([("acme", HashMap::from([("en", r#"{"brand": "Acme"}"#)]))])`.
+    #[props(default)]
+    pub tenants: HashMap<&'static str, HashMap<&'static str, &'static str>>,
+
+    /// The tenant whose overrides apply, from `tenants`. Can be switched at runtime, and
+    /// through `I18n::set_tenant` on the provided signal.
+    ///
+    /// Defaults to `None`, resolving every key from the shared `translations`.
+    #[props(default)]
+    pub tenant: Option<String>,
+
+    /// Reporter that batches missing-key events and posts them to an endpoint.
+    ///
+    /// Lets keys missing in production feed directly into the translation backlog.
+    #[props(default)]
+    pub missing_key_reporter: Option<MissingKeyReporter>,
+
+    /// Whether to track which keys are resolved, for `I18n::usage_report`.
+    #[props(default)]
+    pub track_usage: bool,
+
+    /// Whether to panic on keys missing for the current language, in debug builds only.
+    ///
+    /// Catches untranslated strings during development; release builds fall back
+    /// gracefully regardless. See `I18n::set_strict`.
+    #[props(default)]
+    pub strict: bool,
+
+    /// Whether to compare the placeholders of every language with `default_language` when
+    /// the provider starts, reporting each mismatch through `onerror`.
+    ///
+    /// See `I18n::check_placeholders`.
+    #[props(default)]
+    pub check_placeholders: bool,
+
+    /// A JSON Schema every language must satisfy, e.g. generated from the base locale with
+    /// `schema::from_locale`.
+    ///
+    /// Invalid `translations` fail initialization like malformed JSON, rendering
+    /// `error_fallback`; invalid compressed and remote bundles are rejected and reported
+    /// through `onerror`. See `I18n::with_schema`.
+    #[props(default)]
+    pub schema: Option<Value>,
+
+    /// Whether to report keys that loaded namespaces, `set_layer` or `merge` redefine with a
+    /// different value through `onerror`, instead of letting the last write win silently.
+    /// Conflicts are reported as soon as the instance changes, then discarded.
+    ///
+    /// See `I18n::set_detect_conflicts`.
+    #[props(default)]
+    pub detect_conflicts: bool,
+
+    /// Whether to install the provider's instance as `i18nrs::global::global()`.
+    ///
+    /// Keeps the global handle in sync with the active language, for error types,
+    /// validators and other code outside the component tree.
+    #[props(default)]
+    pub global: bool,
+
+    /// The key of an independent i18n context.
+    ///
+    /// A named provider registers its context under this key instead of providing the
+    /// default one, so it can coexist with another provider (e.g. app UI vs. embedded CMS
+    /// content) without shadowing it. The document's `lang` and `dir` attributes, and the
+    /// namespace and loading hooks, are left to the default provider.
+    #[props(default)]
+    pub name: Option<String>,
+}
+
+/// The remote loading state provided to children by the `I18nProvider`.
+#[derive(Clone, Copy)]
+pub struct I18nLoading {
+    /// Languages whose remote bundle is currently being fetched.
+    pub fetching: Signal<HashSet<String>>,
+
+    /// Whether the translations of the current language are available.
+    ///
+    /// `false` while the remote bundle of the current language is fetched without a
+    /// cached copy.
+    pub is_ready: Memo<bool>,
 }
 
 /// The context provided to children by the `I18nProvider`.
@@ -75,6 +340,145 @@ pub struct I18nContext {
     pub set_language: EventHandler<String>,
 }
 
+/// The provider state behind the accessors of an `I18nContext`.
+///
+/// Kept out of the context's fields so `I18nContext { i18n, set_language }` patterns
+/// keep compiling.
+#[derive(Clone, Copy)]
+struct I18nHandles {
+    /// The signal of the context the handles belong to.
+    i18n: Signal<I18n>,
+
+    /// The current language code.
+    language: Memo<String>,
+
+    /// Loads a language ahead of time without switching to it.
+    preload_language: EventHandler<String>,
+
+    /// Removes a language at runtime.
+    remove_language: Callback<String, Result<(), String>>,
+}
+
+impl I18nContext {
+    /// Retrieves the handles of the provider of this context.
+    fn handles(&self) -> I18nHandles {
+        try_consume_context::<I18nHandles>()
+            .filter(|handles| handles.i18n == self.i18n)
+            .or_else(|| {
+                try_consume_context::<I18nRegistry>()?
+                    .handles
+                    .into_values()
+                    .find(|handles| handles.i18n == self.i18n)
+            })
+            .expect("I18nContext used outside of its I18nProvider")
+    }
+
+    /// Retrieves the current language code as a memo.
+    ///
+    /// Only notifies its readers when the language changes, not when bundles or
+    /// namespaces are loaded into `i18n`, so components that only show or compare the
+    /// language code, or memoize translations per language, re-render less often.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dioxus::prelude::*;
+    /// use i18nrs::dioxus::{I18nContext, use_i18n};
+    ///
+    /// #[component]
+    /// fn LanguageBadge() -> Element {
+    ///     let context = use_i18n();
+    ///     let language = context.language();
+    ///     let I18nContext { set_language, .. } = context;
+    ///
+    ///     rsx! {
+    ///         button { onclick: move |_| set_language.call("fr".to_string()), "{language}" }
+    ///     }
+    /// }
+    /// ```
+    pub fn language(&self) -> Memo<String> {
+        self.handles().language
+    }
+
+    /// Retrieves a read-only signal of the translations.
+    ///
+    /// Hand it to components that render translations but must not load bundles or
+    /// change the language.
+    pub fn translations(&self) -> ReadSignal<I18n> {
+        self.i18n.into()
+    }
+
+    /// Loads a language ahead of time without switching to it.
+    ///
+    /// Fetches the remote bundle of the language or parses it again if it was unloaded,
+    /// so a later `set_language` is instant, e.g. when the user hovers a language menu.
+    pub fn preload_language(&self, language: &str) {
+        self.handles().preload_language.call(language.to_string());
+    }
+
+    /// Removes a language at runtime; see `I18n::remove_language`.
+    ///
+    /// Removing the current language switches to the default language like
+    /// `set_language`, so the provider's `onchange` callback is emitted.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language was removed.
+    /// - `Err(String)` if it is not registered or is the only language.
+    pub fn remove_language(&self, language: &str) -> Result<(), String> {
+        self.handles().remove_language.call(language.to_string())
+    }
+
+    /// Retrieves the languages the provider can switch to, with their metadata; see
+    /// `I18n::available_languages`.
+    pub fn available_languages(&self) -> Vec<LanguageInfo> {
+        self.i18n.read().available_languages()
+    }
+
+    /// Registers a language at runtime, or replaces the full bundle of an existing one.
+    ///
+    /// See `I18n::add_language`. Components reading `i18n` re-render with the new bundle.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the bundle was loaded.
+    /// - `Err(String)` if the JSON is invalid.
+    pub fn add_language(&self, language: &str, json: &str) -> Result<(), String> {
+        let mut i18n = self.i18n;
+        i18n.write().add_language(language, json)
+    }
+}
+
+/// The named i18n contexts provided to children by `I18nProvider`s with a `name`.
+///
+/// Nested named providers extend the registry of their parent, so every named context
+/// above a component is reachable with `use_i18n_named`.
+#[derive(Clone, Default)]
+pub struct I18nRegistry {
+    /// Contexts by provider name.
+    contexts: HashMap<String, I18nContext>,
+
+    /// Handles of the contexts by provider name.
+    handles: HashMap<String, I18nHandles>,
+}
+
+impl I18nRegistry {
+    /// Retrieves the context registered under a name.
+    pub fn get(&self, name: &str) -> Option<I18nContext> {
+        self.contexts.get(name).cloned()
+    }
+}
+
+/// The namespace loading state provided to children by the `I18nProvider`.
+#[derive(Clone)]
+pub struct I18nNamespaces {
+    /// Lazy loaders registered on the provider.
+    loaders: NamespaceLoaders,
+
+    /// `(language, namespace)` pairs currently being loaded.
+    pub loading: Signal<HashSet<(String, String)>>,
+
+    /// Error callback of the provider, invoked when a loader fails.
+    onerror: EventHandler<String>,
+}
+
 /// I18nProvider Component
 ///
 /// A Dioxus component that provides internationalization (i18n) context to its child components.
@@ -84,19 +488,19 @@ pub struct I18nContext {
 ///
 /// # Features
 /// - Loads and provides translations via a context.
-/// - Dynamically updates the `dir` attribute of the HTML document based on RTL/LTR languages.
+/// - Dynamically updates the `dir` and `lang` attributes of the HTML document based on the current language.
 /// - Handles language change events and provides reactive updates to subscribers.
 /// - Persists user-selected language in browser storage.
 /// - Emits callbacks for changes and errors.
 ///
 /// # Behavior
-/// - Reads the initial language from browser storage using the configured `storage_type` and `storage_name`.
-/// - Falls back to `default_language` if no language is stored.
+/// - Detects the initial language from the `detection` sources (URL, cookie, browser storage, browser languages).
+/// - Falls back to `default_language` if no source names an available language.
 /// - Initializes the i18n instance using provided translations.
 /// - If the language cannot be set, the `onerror` callback is triggered with the error message.
 /// - On language change:
 ///   - Updates the browser storage.
-///   - Applies text direction (`dir="rtl"` or `dir="ltr"`) on the `<html>` element.
+///   - Applies text direction (`dir="rtl"` or `dir="ltr"`) and `lang` on the `<html>` element.
 ///   - Calls the `onchange` callback.
 ///   - Updates the context state.
 ///
@@ -131,198 +535,1291 @@ pub struct I18nContext {
 ///
 /// # Notes
 /// - Right-to-left (RTL) languages like Arabic, Hebrew, Persian, and Urdu automatically set the HTML `dir` attribute.
-/// - If initialization fails (e.g., missing or malformed translation data), the `onerror` callback is triggered.
+/// - If initialization fails (e.g., missing or malformed translation data), the `onerror` callback is triggered
+///   and `error_fallback` is rendered instead of the children.
+/// - While the current language is fetched, `loading` is rendered instead of the children, or
+///   the provider suspends to the nearest `SuspenseBoundary` if `suspense` is set.
 /// - The `I18nContext` with `i18n` and `set_language` is made available via Dioxus's context API.
 #[component]
 pub fn I18nProvider(props: I18nProviderProps) -> Element {
-    let initial_language =
-        use_initial_language(props.storage_type.clone(), props.storage_name.clone())()
-            .unwrap_or(props.default_language.clone());
-
-    #[cfg(target_arch = "wasm32")]
-    fn is_rtl_language(lang: &str) -> bool {
-        matches!(lang, "ar" | "he" | "fa" | "ur" | "ps" | "ku" | "sd")
-    }
+    let available = provider_languages(
+        &props.translations,
+        &props.urls,
+        &props.compressed_translations,
+        &props.supported_languages,
+    );
+    let detected = use_detected_language(
+        props.detection.clone(),
+        &available,
+        props.storage_type.clone(),
+        props.storage_name.clone(),
+        props.url_param.clone(),
+        props.hash_param.clone(),
+    );
+    let initial_source = match (&props.language, &detected) {
+        (Some(_), _) => ChangeSource::Pinned,
+        (None, Some((_, source))) => source.change_source(),
+        (None, None) => ChangeSource::Default,
+    };
+    let initial_language = props
+        .language
+        .clone()
+        .or_else(|| detected.as_ref().map(|(language, _)| language.clone()))
+        .unwrap_or(props.default_language.clone());
 
-    let update_text_direction = |_lang: &str| {
+    let directions = props.directions.clone();
+    let _xml_lang = props.xml_lang;
+    let _named = props.name.is_some();
+    let update_document_language = move |lang: &str| {
+        let _dir = TextDirection::resolve(lang, &directions);
         #[cfg(target_arch = "wasm32")]
         {
-            if let Some(document) = window().and_then(|win| win.document()) {
-                let dir = if is_rtl_language(_lang) { "rtl" } else { "ltr" };
-                if let Some(html_element) = document.document_element() {
-                    let _ = html_element.set_attribute("dir", dir);
+            if _named {
+                return;
+            }
+            if let Some(html_element) = window()
+                .and_then(|win| win.document())
+                .and_then(|document| document.document_element())
+            {
+                let _ = html_element.set_attribute("dir", _dir.as_str());
+                let _ = html_element.set_attribute("lang", lang);
+                if _xml_lang {
+                    let _ = html_element.set_attribute("xml:lang", lang);
                 }
             }
         }
     };
 
-    update_text_direction(&initial_language.clone());
+    // Once, like the initial language itself; later switches update the document
+    // when they are applied, and the provider re-renders far more often than that.
+    use_hook(|| update_document_language(&initial_language));
+
+    let (mut i18n, init_error) = use_hook(|| {
+        let translations = with_placeholders(
+            &props.translations,
+            props
+                .urls
+                .keys()
+                .chain(props.compressed_translations.keys()),
+        );
 
-    let mut i18n = use_signal(|| {
-        I18n::new(
+        let result = I18n::new(
             I18nConfig {
-                translations: props.translations.clone(),
+                translations: translations.clone(),
             },
-            props.translations.clone(),
+            translations,
         )
+        .and_then(|instance| match &props.schema {
+            Some(schema) => {
+                let bundled: Vec<&str> = props.translations.keys().copied().collect();
+                instance
+                    .validate_schema(&bundled, schema)
+                    .map(|()| instance)
+            }
+            None => Ok(instance),
+        })
         .map(|mut instance| {
-            if let Err(err) = instance.set_translation_language(
+            for (language, bytes) in &props.compressed_translations {
+                if let Err(err) = decompress(bytes).and_then(|json| {
+                    check_bundle_schema(language, &json, props.schema.as_ref())?;
+                    instance.add_language(language, &json)
+                }) {
+                    props.onerror.call(err);
+                }
+            }
+            // Without a bundle for it, the alphabetically first language stays the fallback.
+            let _ = instance.set_default_language(&props.default_language);
+            if let Err(err) = instance.set_supported_languages(&props.supported_languages) {
+                props.onerror.call(err);
+            }
+            let event = LifecycleEvent::before(None, &initial_language, initial_source);
+            props.onlifecycle.call(event.clone());
+            match instance.set_translation_language(
                 &initial_language.clone(),
                 &props.storage_type,
                 &props.storage_name,
             ) {
-                props.onerror.call(err.clone());
+                Ok(()) => {
+                    if let Some(name) = &props.url_param {
+                        write_query_param(name, &initial_language);
+                    }
+                    if let Some(name) = &props.hash_param {
+                        write_hash_param(name, &initial_language);
+                    }
+                    props.onlifecycle.call(event.after());
+                }
+                Err(err) => props.onerror.call(err.clone()),
+            }
+            for (name, translations) in &props.tenants {
+                if let Err(err) = instance.add_tenant(name, translations.clone()) {
+                    props.onerror.call(err);
+                }
+            }
+            if let Some(name) = &props.tenant
+                && let Err(err) = instance.set_tenant(name)
+            {
+                props.onerror.call(err);
+            }
+            instance.set_directions(props.directions.clone());
+            instance.set_show_keys(props.show_keys);
+            instance.set_track_usage(props.track_usage);
+            instance.set_strict(props.strict);
+            instance.set_detect_conflicts(props.detect_conflicts);
+            if props.check_placeholders {
+                match instance.check_placeholders(&props.default_language) {
+                    Ok(mismatches) => mismatches
+                        .iter()
+                        .for_each(|mismatch| props.onerror.call(mismatch.to_string())),
+                    Err(err) => props.onerror.call(err),
+                }
             }
+            instance.set_missing_key_handler(
+                props
+                    .missing_key_reporter
+                    .as_ref()
+                    .map(MissingKeyReporter::handler),
+            );
             instance
-        })
-        .unwrap_or_else(|err| {
-            props.onerror.call(err.clone());
-            panic!("Failed to initialize I18n: {}", err);
-        })
+        });
+
+        match result {
+            Ok(instance) => (Signal::new(instance), None),
+            Err(err) => {
+                props.onerror.call(err.clone());
+                (Signal::new(I18n::empty(&props.default_language)), Some(err))
+            }
+        }
     });
 
-    let set_language = EventHandler::new({
-        move |language: String| {
+    #[cfg(not(target_arch = "wasm32"))]
+    use_hook(|| {
+        let Some(dir) = props.locale_dir.clone() else {
+            return;
+        };
+        spawn(async move {
+            let mut watcher = LocaleWatcher::new(dir);
+            let mut last_error = None;
+            loop {
+                match watcher.poll() {
+                    Ok(changed) => {
+                        last_error = None;
+                        for (language, json) in changed {
+                            i18n.write().add_language_value(&language, json);
+                        }
+                    }
+                    Err(err) if last_error.as_ref() != Some(&err) => {
+                        last_error = Some(err.clone());
+                        props.onerror.call(err);
+                    }
+                    Err(_) => {}
+                }
+                sleep(Duration::from_millis(500)).await;
+            }
+        });
+    });
+
+    use_effect(move || {
+        if i18n.read().conflicts().is_empty() {
+            return;
+        }
+        let conflicts = i18n.write().take_conflicts();
+        for conflict in conflicts {
+            props.onerror.call(conflict.to_string());
+        }
+    });
+
+    let global = props.global;
+    use_effect(move || {
+        if global {
+            set_global(i18n.read().clone());
+        }
+    });
+
+    use_effect(use_reactive((&props.show_keys,), move |(show_keys,)| {
+        if i18n.peek().show_keys() != show_keys {
+            i18n.write().set_show_keys(show_keys);
+        }
+    }));
+
+    use_effect(use_reactive((&props.tenant,), move |(tenant,)| {
+        if i18n.peek().tenant() == tenant.as_deref() {
+            return;
+        }
+        match tenant {
+            Some(name) => {
+                let result = i18n.write().set_tenant(&name);
+                if let Err(err) = result {
+                    props.onerror.call(err);
+                }
+            }
+            None => i18n.write().clear_tenant(),
+        }
+    }));
+
+    #[cfg(feature = "dio-ssr")]
+    let client_sources = (
+        props.storage_type.clone(),
+        props.storage_name.clone(),
+        props.hash_param.clone(),
+    );
+    let apply_language = EventHandler::new({
+        move |event: LifecycleEvent| {
+            let language = event.to.clone();
             let mut i18n_val = i18n();
-            update_text_direction(&language);
+            update_document_language(&language);
 
-            let lang = language.clone();
             if i18n_val
                 .set_translation_language(&language, &props.storage_type, &props.storage_name)
                 .is_ok()
             {
                 i18n.set(i18n_val);
-                props.onchange.call(language);
-                let storage_name = props.storage_name.clone();
+                if let Some(name) = &props.url_param {
+                    write_query_param(name, &language);
+                }
+                if let Some(name) = &props.hash_param {
+                    write_hash_param(name, &language);
+                }
 
                 #[cfg(feature = "dio-ssr")]
+                if props.storage_type != StorageType::UrlQuery {
+                    let storage_name = props.storage_name.clone();
+                    let lang = language.clone();
+                    spawn(async move {
+                        let _ = set_cookie(storage_name, lang).await;
+                    });
+                }
+
+                #[cfg(feature = "dio-ssr")]
+                if props.save_preference && event.source == ChangeSource::User {
+                    let lang = language.clone();
+                    spawn(async move {
+                        if let Err(err) = save_language(lang).await {
+                            props.onerror.call(err.to_string());
+                        }
+                    });
+                }
+
+                props.onchange.call(language);
+                props.onlifecycle.call(event.after());
+            }
+        }
+    });
+
+    // The server sees neither the fragment nor browser storage; once hydrated, switch to
+    // their language if they rank above the source the server detected the language from.
+    #[cfg(feature = "dio-ssr")]
+    use_hook(|| {
+        let (storage_type, storage_name, hash_param) = client_sources;
+        let sources = props.detection.sources();
+        let rank = |source| sources.iter().position(|other| *other == source);
+        let server_rank = detected
+            .as_ref()
+            .and_then(|(_, source)| rank(*source))
+            .unwrap_or(sources.len());
+        let client = props.detection.resolve(&available, |source| match source {
+            DetectionSource::Hash | DetectionSource::Storage
+                if rank(source).is_some_and(|rank| rank < server_rank) =>
+            {
+                read_browser(
+                    source,
+                    &storage_type,
+                    &storage_name,
+                    None,
+                    hash_param.as_deref(),
+                )
+            }
+            _ => None,
+        });
+
+        let from = i18n.peek().get_current_language().to_string();
+        if let Some((language, source)) = client
+            && language != from
+            && props.language.is_none()
+        {
+            let event = LifecycleEvent::before(Some(&from), language, source.change_source());
+            props.onlifecycle.call(event.clone());
+            spawn(async move { apply_language.call(event) });
+        }
+    });
+
+    let guard = props.onbeforechange.clone();
+    let set_language = EventHandler::new(move |language: String| {
+        let from = i18n.peek().get_current_language().to_string();
+        let event = LifecycleEvent::before(Some(&from), &language, ChangeSource::User);
+        props.onlifecycle.call(event.clone());
+
+        match guard.clone() {
+            Some(guard) => {
+                let change = LanguageChange { from, to: language };
                 spawn(async move {
-                    let lang = lang.clone();
-                    let _ = set_cookie(storage_name, lang).await;
+                    if guard.check(change).await {
+                        apply_language.call(event);
+                    }
                 });
             }
+            None => apply_language.call(event),
         }
     });
 
-    let context = I18nContext { i18n, set_language };
-    provide_context(context);
+    let loading = use_signal(HashSet::new);
+    let mut requested = use_signal(HashSet::<String>::new);
+    let mut fetching = use_signal(HashSet::<String>::new);
 
-    rsx! { {props.children} }
-}
+    let urls = props.urls.clone();
+    let schema = props.schema.clone();
+    let mut request_bundle = move |language: String| -> Option<Task> {
+        let url = urls.get(language.as_str()).copied()?;
+        if requested.peek().contains(&language) {
+            return None;
+        }
 
-pub fn use_i18n() -> I18nContext {
-    consume_context::<I18nContext>()
-}
+        requested.write().insert(language.clone());
+        let cached = cached_bundle(url);
+        let served = cached
+            .as_ref()
+            .map(|json| i18n.write().add_language(&language, json).is_ok())
+            .unwrap_or(false);
+        if !served {
+            fetching.write().insert(language.clone());
+        }
 
-#[allow(unused)]
-pub fn use_initial_language(storage_type: StorageType, key: String) -> Signal<Option<String>> {
-    let mut language = use_signal(|| None);
+        let schema = schema.clone();
+        Some(spawn(async move {
+            match fetch_cached(url).await {
+                Ok(json) if cached.as_ref() == Some(&json) => {}
+                Ok(json) => match check_bundle_schema(&language, &json, schema.as_ref())
+                    .and_then(|()| i18n.write().add_language(&language, &json))
+                {
+                    Ok(()) if cached.is_some() => props.onupdated.call(language.clone()),
+                    Ok(()) => {}
+                    Err(err) => props.onerror.call(err),
+                },
+                Err(err) => props.onerror.call(err),
+            }
+            fetching.write().remove(&language);
+        }))
+    };
 
-    #[cfg(target_arch = "wasm32")]
-    {
-        let stored: Option<String> = match storage_type {
-            StorageType::LocalStorage => window()
-                .expect("No window object")
-                .local_storage()
-                .expect("Failed to access localStorage")
-                .and_then(|s| s.get_item(&key).ok())
-                .expect("Stored language not found in localStorage"),
-            StorageType::SessionStorage => window()
-                .expect("No window object")
-                .session_storage()
-                .expect("Failed to access sessionStorage")
-                .and_then(|s| s.get_item(&key).ok())
-                .expect("Stored language not found in sessionStorage"),
-        };
-        language.set(stored);
-
-        // TODO: Why no cookie?
-        #[cfg(feature = "dio-ssr")]
-        let cookie = web_sys::window()
-            .and_then(|w| w.document())
-            .and_then(|d| d.dyn_into::<web_sys::HtmlDocument>().ok())
-            .and_then(|html_doc| html_doc.cookie().ok())
-            .and_then(|c| {
-                c.split(';')
-                    .map(|c| c.trim())
-                    .find_map(|c| c.strip_prefix(&format!("{key}=")))
-                    .map(|v| v.to_owned())
-            });
+    let remove_language = Callback::new(move |language: String| {
+        let mut i18n_val = i18n();
+        let previous = i18n_val.get_current_language().to_string();
+        i18n_val.remove_language(&language)?;
 
-        #[cfg(feature = "dio-ssr")]
-        language.set(cookie);
+        let current = i18n_val.get_current_language().to_string();
+        i18n.set(i18n_val);
+        if current != previous {
+            let event = LifecycleEvent::before(Some(&previous), &current, ChangeSource::Fallback);
+            props.onlifecycle.call(event.clone());
+            apply_language.call(event);
+        }
+        Ok(())
+    });
 
-        #[cfg(feature = "dio-ssr")]
-        spawn(async move {
-            let key = key.clone();
-            let _cookie = get_cookie(key).await.unwrap();
-            // language.set(Some(cookie));
+    let preload_language = EventHandler::new({
+        let mut request_bundle = request_bundle.clone();
+        move |language: String| {
+            if !i18n.peek().is_loaded(&language)
+                && let Err(err) = i18n.write().preload_language(&language)
+            {
+                props.onerror.call(err);
+            }
+            request_bundle(language);
+        }
+    });
+
+    let language = use_memo(move || i18n.read().get_current_language().to_string());
+    let context = I18nContext { i18n, set_language };
+    let handles = I18nHandles {
+        i18n,
+        language,
+        preload_language,
+        remove_language,
+    };
+    match &props.name {
+        Some(name) => {
+            let mut registry = try_consume_context::<I18nRegistry>().unwrap_or_default();
+            registry.contexts.insert(name.clone(), context);
+            registry.handles.insert(name.clone(), handles);
+            provide_context(registry);
+        }
+        None => {
+            provide_context(context);
+            provide_context(handles);
+        }
+    }
+
+    // Effects only run once the provider is mounted, so a suspending provider starts
+    // the fetch of the initial language while rendering.
+    let initial_task = use_hook({
+        let mut request_bundle = request_bundle.clone();
+        let language = initial_language.clone();
+        move || props.suspense.then(|| request_bundle(language)).flatten()
+    });
+    let mut pending = use_signal(move || initial_task);
+
+    let is_ready = use_memo(move || !fetching.read().contains(i18n.read().get_current_language()));
+    if props.name.is_none() {
+        provide_context(I18nNamespaces {
+            loaders: props.namespaces.clone(),
+            loading,
+            onerror: props.onerror,
         });
+        provide_context(I18nLoading { fetching, is_ready });
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
-    #[cfg(feature = "dio-ssr")]
-    {
-        server_only! {
-            use_future({
-                use crate::dioxus::dioxus_fullstack::FullstackContext;
-                use http::header::{COOKIE, SET_COOKIE};
-                use http::HeaderValue;
-
-                let key = key.to_owned();
-                move || {let value = key.clone();
-                    async move {
-                        let value = value.clone();
-
-                        let ctx_opt = FullstackContext::current();
-                        let ctx = match ctx_opt {
-                            Some(c) => c,
-                            None => return,
-                        };
-
-                        let parts_guard = ctx.parts_mut();
-                        let headers = &parts_guard.headers;
-
-                        if let Some(raw) = headers
-                            .get(COOKIE)
-                            .and_then(|v: &http::HeaderValue| v.to_str().ok())
-                        && let Some(v) = raw
-                                .split(';')
-                                .map(|c: &str| c.trim())
-                                .find_map(|c: &str| c.strip_prefix(&format!("{value}=")))
-                            {
-                                language.set(Some(v.to_string()));
-                                return;
-                            }
-
-                        if let Some(al) = headers
-                            .get("accept-language")
-                            .and_then(|v: &http::HeaderValue| v.to_str().ok())
-                        {
-                            let v = al.split(',').next().unwrap_or("en").trim().to_owned();
-                            language.set(Some(v.clone()));
-
-                            if let Ok(cookie_val) = HeaderValue::from_str(
-                                &format!("{value}={v}; Path=/; Max-Age=31536000; SameSite=Lax")
-                            ) {
-                                ctx.add_response_header(SET_COOKIE, cookie_val);
-                            }
-                        }
-                    }
-                }
-            });
+    use_effect(move || {
+        let language = i18n.read().get_current_language().to_string();
+        if let Some(task) = request_bundle(language) {
+            pending.set(Some(task));
         }
+    });
+
+    if let Some(err) = init_error {
+        return match props.error_fallback {
+            Some(fallback) => fallback.call(err),
+            None => rsx! {},
+        };
     }
 
-    language
-}
+    if props.suspense
+        && !is_ready()
+        && let Some(task) = pending()
+    {
+        return suspend(task);
+    }
+
+    match props.loading {
+        Some(placeholder) if !is_ready() => placeholder,
+        _ => rsx! { {props.children} },
+    }
+}
+
+/// Properties for the `I18nOverride` component.
+#[derive(Props, PartialEq, Clone)]
+pub struct I18nOverrideProps {
+    /// The language code forced for the subtree (e.g., `"fr"`).
+    pub language: String,
+
+    /// The child components rendered in the overridden language.
+    pub children: Element,
+
+    /// CSS class applied to the wrapping `div`.
+    #[props(default)]
+    pub class: String,
+
+    /// Callback when the language is not supported by the surrounding provider.
+    ///
+    /// The subtree keeps the provider's current language in that case.
+    #[props(default)]
+    pub onerror: EventHandler<String>,
+}
+
+/// Forces a different language for a subtree, e.g. an email preview rendered in the
+/// recipient's language while the rest of the UI stays in the user's language.
+///
+/// Must be placed inside an `I18nProvider`, whose translations it reuses and follows. The
+/// override is not persisted, and the subtree is wrapped in a `div` carrying the matching
+/// `lang` and `dir` attributes.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::I18nOverride;
+///
+/// #[component]
+/// fn EmailPreview() -> Element {
+///     rsx! {
+///         I18nOverride { language: "fr",
+///             p { "Rendered in French" }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn I18nOverride(props: I18nOverrideProps) -> Element {
+    let context = use_i18n();
+    let I18nContext { i18n, set_language } = context;
+    let handles = context.handles();
+    let onerror = props.onerror;
+    let overridden = move |language: &str| {
+        let mut local = i18n.read().clone();
+        if let Err(err) = local.select_language(language) {
+            onerror.call(err);
+        }
+        local
+    };
+
+    let mut local = use_signal(|| overridden(&props.language));
+    use_effect(use_reactive((&props.language,), move |(language,)| {
+        local.set(overridden(&language));
+    }));
+    let language = use_memo(move || local.read().get_current_language().to_string());
+    use_context_provider(|| I18nContext {
+        i18n: local,
+        set_language,
+    });
+    use_context_provider(|| I18nHandles {
+        i18n: local,
+        language,
+        ..handles
+    });
+
+    let lang = local.read().get_current_language().to_string();
+    let dir = local.read().direction().as_str();
+    rsx! {
+        div { class: props.class, lang, dir, {props.children} }
+    }
+}
+
+/// Properties for the `Trans` component.
+#[derive(Props, PartialEq, Clone)]
+pub struct TransProps {
+    /// The translation key to render (e.g., `"footer.docs"`).
+    pub i18n_key: String,
+
+    /// Renderers for the tags of the translated string, by tag name.
+    ///
+    /// Each slot receives the rendered inner content of its tag, e.g. `"link"` for
+    /// `"Read the <link>docs</link>"`. Tags without a slot render their content unwrapped.
+    #[props(default)]
+    pub slots: HashMap<&'static str, Callback<Element, Element>>,
+
+    /// Named values for the `{name}` placeholders of the translated string.
+    #[props(default)]
+    pub values: HashMap<&'static str, String>,
+}
+
+/// Renders a translation that contains markup, such as `"Read the <link>docs</link>"`.
+///
+/// Tags in the translated string are rendered through the matching `slots`, and everything
+/// else is rendered as text, so links, bold spans, and buttons can be translated as part of
+/// one sentence without any raw HTML being injected.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::Trans;
+/// use std::collections::HashMap;
+///
+/// #[component]
+/// fn Footer() -> Element {
+///     let slots = HashMap::from([(
+///         "link",
+///         Callback::new(|inner: Element| rsx! { a { href: "/docs", {inner} } }),
+///     )]);
+///
+///     rsx! { p { Trans { i18n_key: "footer.docs", slots } } }
+/// }
+/// ```
+#[component]
+pub fn Trans(props: TransProps) -> Element {
+    let I18nContext { i18n, .. } = use_i18n();
+    let args: Vec<(&str, &dyn Display)> = props
+        .values
+        .iter()
+        .map(|(name, value)| (*name, value as &dyn Display))
+        .collect();
+
+    let i18n = i18n.read();
+    render_trans(
+        i18n.get_current_language(),
+        &parse(&i18n.t(&props.i18n_key)),
+        &props.slots,
+        &args,
+    )
+}
+
+/// Renders parsed translation nodes, wrapping tags with their slots.
+fn render_trans(
+    language: &str,
+    nodes: &[TransNode],
+    slots: &HashMap<&'static str, Callback<Element, Element>>,
+    args: I18nArgs<'_>,
+) -> Element {
+    let children = nodes.iter().map(|node| match node {
+        TransNode::Text(text) => {
+            let text = interpolate(language, text, args);
+            rsx! { "{text}" }
+        }
+        TransNode::Tag { name, children } => {
+            let inner = render_trans(language, children, slots, args);
+            match slots.get(name.as_str()) {
+                Some(slot) => slot.call(inner),
+                None => inner,
+            }
+        }
+    });
+
+    rsx! { {children} }
+}
+
+/// Properties for the `Markdown` component.
+#[cfg(feature = "markdown")]
+#[derive(Props, PartialEq, Clone)]
+pub struct MarkdownProps {
+    /// The translation key whose value is Markdown (e.g., `"help.body"`).
+    pub i18n_key: String,
+
+    /// CSS class applied to the wrapping `div`.
+    #[props(default)]
+    pub class: String,
+}
+
+/// Renders a translation value written in Markdown, for long-form content like help panels.
+///
+/// Raw HTML inside the value is escaped and unsafe link URLs are removed before rendering.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::Markdown;
+///
+/// #[component]
+/// fn Help() -> Element {
+///     rsx! { Markdown { i18n_key: "help.body", class: "prose" } }
+/// }
+/// ```
+#[cfg(feature = "markdown")]
+#[component]
+pub fn Markdown(props: MarkdownProps) -> Element {
+    let I18nContext { i18n, .. } = use_i18n();
+    let content = i18n.read().t_markdown(&props.i18n_key);
+
+    rsx! { div { class: props.class, dangerous_inner_html: content } }
+}
+
+/// Properties for the `TransHtml` component.
+#[cfg(feature = "html")]
+#[derive(Props, PartialEq, Clone)]
+pub struct TransHtmlProps {
+    /// The translation key whose value is an HTML snippet (e.g., `"legal.notice"`).
+    pub i18n_key: String,
+
+    /// CSS class applied to the wrapping `span`.
+    #[props(default)]
+    pub class: String,
+}
+
+/// Renders a translation value containing HTML, after sanitizing it with `I18n::t_html`.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::TransHtml;
+///
+/// #[component]
+/// fn Notice() -> Element {
+///     rsx! { p { TransHtml { i18n_key: "legal.notice" } } }
+/// }
+/// ```
+#[cfg(feature = "html")]
+#[component]
+pub fn TransHtml(props: TransHtmlProps) -> Element {
+    let I18nContext { i18n, .. } = use_i18n();
+    let content = i18n.read().t_html(&props.i18n_key);
+
+    rsx! { span { class: props.class, dangerous_inner_html: content } }
+}
+
+/// Properties for the `I18nMeta` component.
+#[derive(Props, PartialEq, Clone)]
+pub struct I18nMetaProps {
+    /// The translation key of the page title, set as `og:title` (e.g., `"home.title"`).
+    #[props(default)]
+    pub title_key: Option<String>,
+
+    /// The translation key of the page description, set as the `description` and
+    /// `og:description` meta tags (e.g., `"home.description"`).
+    #[props(default)]
+    pub description_key: Option<String>,
+}
+
+/// Keeps the localized meta tags of a page in the document head.
+///
+/// Renders the `description` and OpenGraph tags from translation keys, along with
+/// `og:locale` and `og:locale:alternate`, as `document::Meta` elements, so they are
+/// part of server-rendered pages and follow language switches; see `seo::meta_tags`.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::I18nMeta;
+///
+/// #[component]
+/// fn Home() -> Element {
+///     rsx! {
+///         I18nMeta { title_key: "home.title", description_key: "home.description" }
+///         main {}
+///     }
+/// }
+/// ```
+#[component]
+pub fn I18nMeta(props: I18nMetaProps) -> Element {
+    let I18nContext { i18n, .. } = use_i18n();
+    let tags = meta_tags(
+        &i18n.read(),
+        props.title_key.as_deref(),
+        props.description_key.as_deref(),
+    );
+
+    rsx! {
+        for tag in tags {
+            if tag.attribute == "name" {
+                document::Meta { key: "{tag.name}:{tag.content}", name: tag.name, content: tag.content }
+            } else {
+                document::Meta { key: "{tag.name}:{tag.content}", property: tag.name, content: tag.content }
+            }
+        }
+    }
+}
+
+/// Properties for the `I18nAlternateLinks` component.
+#[derive(Props, PartialEq, Clone)]
+pub struct I18nAlternateLinksProps {
+    /// The URL of the page with a `{lang}` placeholder for the language code (e.g.,
+    /// `"https://example.com/{lang}/about"`).
+    pub pattern: String,
+
+    /// The language whose URL also serves as the `x-default` link.
+    #[props(default)]
+    pub x_default: Option<String>,
+}
+
+/// Renders `hreflang` alternate links to every selectable language of a page as
+/// `document::Link` elements, server-rendered pages included; see
+/// `seo::alternate_links`.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::I18nAlternateLinks;
+///
+/// #[component]
+/// fn About() -> Element {
+///     rsx! {
+///         I18nAlternateLinks { pattern: "https://example.com/{{lang}}/about", x_default: "en" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn I18nAlternateLinks(props: I18nAlternateLinksProps) -> Element {
+    let I18nContext { i18n, .. } = use_i18n();
+    let links = alternate_links(
+        &i18n.read().languages(),
+        &props.pattern,
+        props.x_default.as_deref(),
+    );
+
+    rsx! {
+        for link in links {
+            document::Link {
+                key: "{link.hreflang}",
+                rel: "alternate",
+                hreflang: link.hreflang,
+                href: link.href,
+            }
+        }
+    }
+}
+
+/// Properties for the `I18nEditor` component.
+#[cfg(feature = "editor")]
+#[derive(Props, PartialEq, Clone)]
+pub struct I18nEditorProps {
+    /// Whether the editor is shown, e.g. `cfg!(debug_assertions)` to keep it out of
+    /// release builds. The children are rendered as is otherwise.
+    #[props(default)]
+    pub enabled: bool,
+
+    /// The part of the app whose strings can be picked by Alt-clicking them.
+    pub children: Element,
+
+    /// CSS class applied to the editor panel.
+    #[props(default)]
+    pub class: String,
+}
+
+/// A development tool to review and edit translations in place.
+///
+/// Alt-clicking a string inside the children (on the web), or searching by text or key,
+/// lists the matching keys of the current language with their translations. Edits show
+/// up live, as the topmost translation layer of the surrounding provider, and the panel
+/// exports them as JSON for the locale files. Must be placed inside an `I18nProvider`.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::{I18nEditor, use_i18n};
+///
+/// #[component]
+/// fn App() -> Element {
+///     let i18n = use_i18n().i18n;
+///     rsx! {
+///         I18nEditor { enabled: cfg!(debug_assertions),
+///             h1 { {i18n.read().t("greeting")} }
+///         }
+///     }
+/// }
+/// ```
+#[cfg(feature = "editor")]
+#[component]
+pub fn I18nEditor(props: I18nEditorProps) -> Element {
+    let I18nContext { mut i18n, .. } = use_i18n();
+    let mut query = use_signal(String::new);
+    let mut edits = use_signal(TranslationEdits::default);
+
+    if !props.enabled {
+        return props.children;
+    }
+
+    let language = i18n.read().get_current_language().to_string();
+    let keys = if query.read().trim().is_empty() {
+        Vec::new()
+    } else {
+        i18n.read().search_keys(&query.read())
+    };
+
+    rsx! {
+        div {
+            onclick: move |event: MouseEvent| {
+                if !event.modifiers().alt() {
+                    return;
+                }
+                event.prevent_default();
+                #[cfg(target_arch = "wasm32")]
+                {
+                    use web_sys::wasm_bindgen::JsCast;
+                    if let Some(text) = event
+                        .data()
+                        .downcast::<web_sys::MouseEvent>()
+                        .and_then(|event| event.target())
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                        .and_then(|element| element.text_content())
+                    {
+                        query.set(text.trim().to_string());
+                    }
+                }
+            },
+            {props.children}
+        }
+        aside { class: props.class, style: EDITOR_STYLE,
+            input {
+                r#type: "search",
+                placeholder: "Search text or keys, or Alt-click a string",
+                value: "{query}",
+                oninput: move |event| query.set(event.value()),
+            }
+            ul {
+                for key in keys {
+                    li { key: "{key}",
+                        code { "{key}" }
+                        textarea {
+                            value: i18n.read().t(&key),
+                            oninput: {
+                                let language = language.clone();
+                                let key = key.clone();
+                                move |event: FormEvent| {
+                                    edits.write().set(&language, &key, &event.value());
+                                    let _ = edits.read().apply(&mut i18n.write());
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+            if !edits.read().is_empty() {
+                pre { {edits.read().export(&language)} }
+            }
+        }
+    }
+}
+
+/// Pre-renders an app once per language, writing each page under a directory.
+///
+/// Each page is rendered to HTML on its own `VirtualDom`, from the component `app` given
+/// the language code; it should pin its `I18nProvider` to that language with the
+/// `language` prop. See `ssg::render_locales` for the arguments. Requires the `server`
+/// feature.
+///
+/// # Example
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::{I18nProvider, render_locales};
+/// use std::collections::HashMap;
+///
+/// fn App(language: String) -> Element {
+///     let translations = HashMap::from([
+///         ("en", r#"{"greeting": "Hello"}"#),
+///         ("de", r#"{"greeting": "Hallo"}"#),
+///     ]);
+///     rsx! {
+///         I18nProvider { translations, language, p { "…" } }
+///     }
+/// }
+///
+/// let pages = render_locales(&["en", "de"], "dist", "{lang}/index.html", App).unwrap();
+/// ```
+#[cfg(all(feature = "server", not(target_arch = "wasm32")))]
+pub fn render_locales(
+    languages: &[&str],
+    out_dir: impl AsRef<std::path::Path>,
+    pattern: &str,
+    app: fn(String) -> Element,
+) -> Result<Vec<std::path::PathBuf>, String> {
+    crate::ssg::render_locales(languages, out_dir, pattern, |language| {
+        let mut dom = VirtualDom::new_with_props(app, language.to_string());
+        dom.rebuild_in_place();
+        Ok(dioxus::ssr::render(&dom))
+    })
+}
+
+pub fn use_i18n() -> I18nContext {
+    consume_context::<I18nContext>()
+}
+
+/// Retrieves the context of a named `I18nProvider`.
+///
+/// # Arguments
+/// - `name`: The `name` prop of the provider (e.g., `"cms"`).
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::use_i18n_named;
+///
+/// #[component]
+/// fn Article() -> Element {
+///     let cms = use_i18n_named("cms");
+///     let title = cms.i18n.read().t("article.title");
+///
+///     rsx! { h1 { "{title}" } }
+/// }
+/// ```
+pub fn use_i18n_named(name: &str) -> I18nContext {
+    try_consume_context::<I18nRegistry>()
+        .and_then(|registry| registry.get(name))
+        .unwrap_or_else(|| panic!("No I18n context named '{}' provided", name))
+}
+
+/// Keeps the document title set to a translation, updated when the language changes.
+///
+/// # Arguments
+/// - `key`: The translation key of the title (e.g., `"pages.settings.title"`).
+/// - `args`: Named values for the placeholders of the title; see `I18n::t_with`.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::use_document_title;
+///
+/// #[component]
+/// fn Inbox() -> Element {
+///     let unread = 3;
+///     use_document_title("inbox.title", &[("count", &unread)]);
+///
+///     rsx! { main {} }
+/// }
+/// ```
+pub fn use_document_title(key: &str, args: I18nArgs<'_>) {
+    let I18nContext { i18n, .. } = use_i18n();
+    let title = i18n.read().t_with(key, args);
+    use_effect(use_reactive((&title,), |(title,)| {
+        document::document().set_title(title);
+    }));
+}
+
+/// Checks whether the remote bundle of the current language is still being fetched.
+pub fn use_i18n_loading() -> bool {
+    let I18nContext { i18n, .. } = use_i18n();
+    try_consume_context::<I18nLoading>()
+        .map(|state| {
+            state
+                .fetching
+                .read()
+                .contains(i18n.read().get_current_language())
+        })
+        .unwrap_or(false)
+}
+
+/// Checks whether the translations of the current language are available.
+///
+/// Returns `false` while the remote bundle of the current language is fetched without a
+/// cached copy, and `true` outside of an `I18nProvider`.
+pub fn use_i18n_ready() -> bool {
+    try_consume_context::<I18nLoading>()
+        .map(|state| (state.is_ready)())
+        .unwrap_or(true)
+}
+
+/// The namespaced context returned by `use_i18n_ns`.
+///
+/// Resolves keys relative to `namespace` while sharing the provider's reactive state.
+#[derive(Clone)]
+pub struct I18nNsContext {
+    /// Reactive signal containing the current internationalization state.
+    pub i18n: Signal<I18n>,
+
+    /// Function to change the current language.
+    pub set_language: EventHandler<String>,
+
+    /// The namespace prepended to every key.
+    pub namespace: String,
+
+    /// `(language, namespace)` pairs currently being loaded by the provider.
+    pub loading: Signal<HashSet<(String, String)>>,
+}
+
+impl I18nNsContext {
+    /// Translates a key relative to the namespace.
+    ///
+    /// Reading the signal subscribes the calling component to language changes.
+    pub fn t(&self, key: &str) -> String {
+        self.i18n.read().t(&format!("{}.{}", self.namespace, key))
+    }
+
+    /// Checks whether the namespace is still loading for the current language.
+    pub fn is_loading(&self) -> bool {
+        let language = self.i18n.read().get_current_language().to_string();
+        self.loading
+            .read()
+            .contains(&(language, self.namespace.clone()))
+    }
+}
+
+/// Retrieves the i18n context scoped to a namespace.
+///
+/// If the provider has a lazy loader registered for the namespace, the first use for the
+/// current language triggers the load, and `is_loading` reports `true` until it completes.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::use_i18n_ns;
+///
+/// #[component]
+/// fn CheckoutTitle() -> Element {
+///     let checkout = use_i18n_ns("checkout");
+///
+///     rsx! { h1 { {checkout.t("title")} } }
+/// }
+/// ```
+pub fn use_i18n_ns(namespace: &str) -> I18nNsContext {
+    let I18nContext {
+        mut i18n,
+        set_language,
+    } = use_i18n();
+    let namespaces = try_consume_context::<I18nNamespaces>();
+    let fallback = use_signal(HashSet::new);
+    let mut loading = namespaces
+        .as_ref()
+        .map(|namespaces| namespaces.loading)
+        .unwrap_or(fallback);
+
+    let ns = namespace.to_string();
+    use_effect(move || {
+        let language = i18n.read().get_current_language().to_string();
+        let Some(namespaces) = namespaces.clone() else {
+            return;
+        };
+        let Some(loader) = namespaces.loaders.get(ns.as_str()).cloned() else {
+            return;
+        };
+        let pending = (language.clone(), ns.clone());
+        if i18n.peek().has_namespace(&language, &ns) || loading.peek().contains(&pending) {
+            return;
+        }
+
+        loading.write().insert(pending.clone());
+        let namespace = ns.clone();
+        spawn(async move {
+            let result = loader
+                .load(&language)
+                .await
+                .and_then(|json| i18n.write().add_namespace(&language, &namespace, &json));
+            if let Err(err) = result {
+                namespaces.onerror.call(err);
+            }
+            loading.write().remove(&pending);
+        });
+    });
+
+    I18nNsContext {
+        i18n,
+        set_language,
+        namespace: namespace.to_string(),
+        loading,
+    }
+}
+
+/// Detects the initial language of a provider from its `detection` sources.
+///
+/// With `dio-ssr`, the server detects the language of the request and serializes it into
+/// the page, so the first client render hydrates in the same language.
+fn use_detected_language(
+    order: DetectionOrder,
+    available: &[&'static str],
+    storage_type: StorageType,
+    storage_name: String,
+    url_param: Option<String>,
+    hash_param: Option<String>,
+) -> Option<(String, DetectionSource)> {
+    let available = available.to_vec();
+
+    #[cfg(feature = "dio-ssr")]
+    {
+        let sources = order.sources().to_vec();
+        // Sources are serialized by their rank in the order.
+        let detected: Option<(String, usize)> = use_server_cached(move || {
+            #[cfg(target_arch = "wasm32")]
+            let detected = order.resolve(&available, |source| {
+                read_browser(
+                    source,
+                    &storage_type,
+                    &storage_name,
+                    url_param.as_deref(),
+                    hash_param.as_deref(),
+                )
+            });
+
+            #[cfg(not(target_arch = "wasm32"))]
+            let detected = {
+                // Browser storage and the fragment are read once hydrated.
+                let _ = &hash_param;
+                request_detection(
+                    &order,
+                    &available,
+                    &storage_type,
+                    &storage_name,
+                    url_param.as_deref(),
+                )
+            };
+
+            detected.map(|(language, source)| {
+                let rank = order.sources().iter().position(|other| *other == source);
+                (language.to_string(), rank.unwrap_or(order.sources().len()))
+            })
+        });
+        detected.map(|(language, rank)| {
+            let source = sources.get(rank).copied();
+            (language, source.unwrap_or(DetectionSource::Navigator))
+        })
+    }
+
+    #[cfg(not(feature = "dio-ssr"))]
+    use_hook(move || {
+        order
+            .resolve(&available, |source| {
+                read_browser(
+                    source,
+                    &storage_type,
+                    &storage_name,
+                    url_param.as_deref(),
+                    hash_param.as_deref(),
+                )
+            })
+            .map(|(language, source)| (language.to_string(), source))
+    })
+}
+
+/// Detects the language of the request being server-side rendered.
+///
+/// Prefers the language resolved by a `LocaleLayer`, reported as `Navigator`; otherwise
+/// reads the query parameter, cookie and `Accept-Language` header in the given order, and
+/// the storage query parameter with `StorageType::UrlQuery`. A language negotiated from
+/// `Accept-Language` is persisted in a cookie on the response, so later requests and the
+/// client agree, unless the provider keeps the language in the URL only.
+#[cfg(all(not(target_arch = "wasm32"), feature = "dio-ssr"))]
+fn request_detection<'a>(
+    order: &DetectionOrder,
+    available: &[&'a str],
+    storage_type: &StorageType,
+    key: &str,
+    url_param: Option<&str>,
+) -> Option<(&'a str, DetectionSource)> {
+    let mut detected = None;
+
+    server_only! {
+        use crate::dioxus::dioxus_fullstack::FullstackContext;
+        use crate::cookie::Cookie;
+        use crate::negotiate::RequestLocale;
+        use crate::url::query_param;
+        use http::HeaderValue;
+        use http::header::{ACCEPT_LANGUAGE, COOKIE, SET_COOKIE};
+
+        if let Some(ctx) = FullstackContext::current() {
+            let parts = ctx.parts_mut();
+            let header = |name| {
+                parts
+                    .headers
+                    .get(name)
+                    .and_then(|value: &HeaderValue| value.to_str().ok())
+            };
+
+            detected = match parts.extensions.get::<RequestLocale>() {
+                Some(RequestLocale(language)) => available
+                    .iter()
+                    .find(|code| *code == language)
+                    .map(|language| (*language, DetectionSource::Navigator)),
+                None => order.resolve(available, |source| match source {
+                    DetectionSource::Query => url_param.and_then(|name| {
+                        query_param(&parts.uri.to_string(), name)
+                    }),
+                    DetectionSource::Cookie => header(COOKIE)
+                        .and_then(|raw| Cookie::get(raw, key))
+                        .map(str::to_owned),
+                    DetectionSource::Navigator => header(ACCEPT_LANGUAGE).map(str::to_owned),
+                    DetectionSource::Storage if *storage_type == StorageType::UrlQuery => {
+                        query_param(&parts.uri.to_string(), key)
+                    }
+                    DetectionSource::Storage if *storage_type == StorageType::Cookie => {
+                        header(COOKIE)
+                            .and_then(|raw| Cookie::get(raw, key))
+                            .map(str::to_owned)
+                    }
+                    DetectionSource::Hash | DetectionSource::Storage => None,
+                }),
+            };
+
+            if let Some((language, DetectionSource::Navigator)) = detected
+                && parts.extensions.get::<RequestLocale>().is_none()
+                && *storage_type != StorageType::UrlQuery
+                && let Ok(cookie) = HeaderValue::from_str(&Cookie::language(key, language).to_string())
+            {
+                ctx.add_response_header(SET_COOKIE, cookie);
+            }
+        }
+    }
+
+    detected
+}
+
+/// Resolves the language a provider without URL parameters starts with.
+///
+/// Detects the language with the default `DetectionOrder`, like an `I18nProvider` that
+/// keeps the language in `storage_type` under `key`. Only languages in `available` are
+/// returned. With `dio-ssr`, the server's choice is serialized into the page and reused by
+/// the first client render, so hydration starts in the same language without a flash.
+pub fn use_initial_language(
+    storage_type: StorageType,
+    key: String,
+    available: &[&'static str],
+) -> Signal<Option<String>> {
+    let detected = use_detected_language(
+        DetectionOrder::default(),
+        available,
+        storage_type,
+        key,
+        None,
+        None,
+    );
+    use_signal(move || detected.map(|(language, _)| language))
+}
 
 #[cfg(feature = "dio-ssr")]
 #[server]
 pub async fn set_cookie(key: String, lang: String) -> Result<(), ServerFnError> {
     use crate::dioxus::dioxus_fullstack::FullstackContext;
-    use http::HeaderValue;
-    use http::header::SET_COOKIE;
+    use http::HeaderMap;
 
     let ctx = FullstackContext::current().ok_or_else(|| ServerFnError::ServerError {
         message: "FullstackContext not available".into(),
@@ -330,16 +1827,17 @@ pub async fn set_cookie(key: String, lang: String) -> Result<(), ServerFnError>
         details: None,
     })?;
 
-    let value = HeaderValue::from_str(&format!(
-        "{key}={lang}; Path=/; SameSite=Lax; Max-Age=31536000"
-    ))
-    .map_err(|e| ServerFnError::ServerError {
-        message: e.to_string(),
-        code: 500,
-        details: None,
+    let mut headers = HeaderMap::new();
+    crate::ssr::set_cookie(&mut headers, &key, &lang).map_err(|message| {
+        ServerFnError::ServerError {
+            message,
+            code: 500,
+            details: None,
+        }
     })?;
-
-    ctx.add_response_header(SET_COOKIE, value);
+    for (name, value) in &headers {
+        ctx.add_response_header(name.clone(), value.clone());
+    }
 
     Ok(())
 }
@@ -348,7 +1846,6 @@ pub async fn set_cookie(key: String, lang: String) -> Result<(), ServerFnError>
 #[server]
 pub async fn get_cookie(key: String) -> Result<String, ServerFnError> {
     use crate::dioxus::dioxus_fullstack::FullstackContext;
-    use http::header::COOKIE;
 
     let ctx = FullstackContext::current().ok_or_else(|| ServerFnError::ServerError {
         message: "FullstackContext not available".into(),
@@ -356,17 +1853,77 @@ pub async fn get_cookie(key: String) -> Result<String, ServerFnError> {
         details: None,
     })?;
 
-    let parts_guard = ctx.parts_mut();
-    let headers = &parts_guard.headers;
+    let language = crate::ssr::get_cookie(&ctx.parts_mut().headers, &key);
 
-    if let Some(raw) = headers.get(COOKIE).and_then(|v| v.to_str().ok())
-        && let Some(v) = raw
-            .split(';')
-            .map(str::trim)
-            .find_map(|c| c.strip_prefix(&format!("{key}=")))
-    {
-        return Ok(v.to_string());
-    }
+    Ok(language.unwrap_or_else(|| "en".to_string()))
+}
+
+/// The future returned by a language saver.
+#[cfg(feature = "dio-ssr")]
+pub type LanguageSaverFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// A function saving the language chosen by a user, given the request headers.
+#[cfg(feature = "dio-ssr")]
+type LanguageSaver = Box<dyn Fn(String, http::HeaderMap) -> LanguageSaverFuture + Send + Sync>;
+
+#[cfg(feature = "dio-ssr")]
+static LANGUAGE_SAVER: OnceLock<LanguageSaver> = OnceLock::new();
+
+/// Registers the function `save_language` persists the chosen language with.
+///
+/// Call it once on the server at startup. The saver receives the language code and the
+/// headers of the request, e.g. to find the logged-in user from a session cookie, and
+/// resolves to `Err` when the preference can't be saved.
+///
+/// # Returns
+/// - `Ok(())` if the saver was registered.
+/// - `Err(String)` if a saver is already registered.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::dioxus::set_language_saver;
+///
+/// set_language_saver(|language, headers| async move {
+///     let _session = headers.get("cookie");
+///     // e.g. `UPDATE users SET language = $1 WHERE session = $2`
+///     let _ = language;
+///     Ok(())
+/// })
+/// .unwrap();
+/// ```
+#[cfg(feature = "dio-ssr")]
+pub fn set_language_saver<F, Fut>(saver: F) -> Result<(), String>
+where
+    F: Fn(String, http::HeaderMap) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), String>> + Send + 'static,
+{
+    LANGUAGE_SAVER
+        .set(Box::new(move |language, headers| {
+            Box::pin(saver(language, headers))
+        }))
+        .map_err(|_| "A language saver is already registered".to_string())
+}
+
+/// Saves the language chosen by the user through the saver registered with
+/// `set_language_saver`.
+///
+/// Called by the `I18nProvider` when its `save_preference` prop is set.
+#[cfg(feature = "dio-ssr")]
+#[server]
+pub async fn save_language(lang: String) -> Result<(), ServerFnError> {
+    use crate::dioxus::dioxus_fullstack::FullstackContext;
+
+    let server_error = |message: String| ServerFnError::ServerError {
+        message,
+        code: 500,
+        details: None,
+    };
+    let saver = LANGUAGE_SAVER
+        .get()
+        .ok_or_else(|| server_error("No language saver registered".into()))?;
+    let ctx = FullstackContext::current()
+        .ok_or_else(|| server_error("FullstackContext not available".into()))?;
+    let headers = ctx.parts_mut().headers.clone();
 
-    Ok("en".to_string())
+    saver(lang, headers).await.map_err(server_error)
 }
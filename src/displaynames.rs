@@ -0,0 +1,71 @@
+//! Localized display names of languages and regions.
+//!
+//! Names languages and countries in the words of another language from CLDR data ("French",
+//! "Français", "Francés"), so language pickers, country selectors and address forms don't
+//! need hand-maintained label maps. Requires the `displaynames` feature.
+
+use crate::cldr::locale;
+use icu_experimental::displaynames::multi::{LocaleDisplayNamesFormatter, RegionDisplayNames};
+use icu_locale_core::subtags::Region;
+
+/// Returns the name of a language as written in another language.
+///
+/// Regional and script variants are named as a whole when CLDR has a name for them
+/// (`"en-GB"` is "British English") and qualified otherwise (`"fr-CH"` is
+/// "French (Switzerland)"). Unknown codes are returned unchanged.
+///
+/// # Arguments
+/// - `display_language`: The language to write the name in (e.g., `"es"`).
+/// - `language`: The language code to name (e.g., `"fr"`).
+///
+/// # Returns
+/// - `Ok(String)` with the display name.
+/// - `Err(String)` if either code is not a valid locale.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::displaynames::language_display_name;
+///
+/// assert_eq!(language_display_name("en", "fr").unwrap(), "French");
+/// assert_eq!(language_display_name("fr", "fr").unwrap(), "français");
+/// assert_eq!(language_display_name("es", "fr").unwrap(), "francés");
+/// assert_eq!(language_display_name("en", "pt-BR").unwrap(), "Brazilian Portuguese");
+/// ```
+pub fn language_display_name(display_language: &str, language: &str) -> Result<String, String> {
+    let formatter =
+        LocaleDisplayNamesFormatter::try_new(locale(display_language)?.into(), Default::default())
+            .map_err(|err| format!("No display names for '{}': {}", display_language, err))?;
+    Ok(formatter.of(&locale(language)?).into_owned())
+}
+
+/// Returns the name of a region or country as written in a language.
+///
+/// # Arguments
+/// - `display_language`: The language to write the name in (e.g., `"fr"`).
+/// - `region`: The ISO 3166 alpha-2 or UN M.49 code of the region (e.g., `"DE"` or
+///   `"419"`). Letters are matched case-insensitively. Codes without a CLDR name are
+///   returned unchanged.
+///
+/// # Returns
+/// - `Ok(String)` with the display name.
+/// - `Err(String)` if the language or region code is invalid.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::displaynames::region_display_name;
+///
+/// assert_eq!(region_display_name("en", "DE").unwrap(), "Germany");
+/// assert_eq!(region_display_name("fr", "de").unwrap(), "Allemagne");
+/// assert_eq!(region_display_name("ja", "DE").unwrap(), "ドイツ");
+/// assert!(region_display_name("en", "Germany").is_err());
+/// ```
+pub fn region_display_name(display_language: &str, region: &str) -> Result<String, String> {
+    let code = Region::try_from_str(&region.to_ascii_uppercase())
+        .map_err(|_| format!("Invalid region code '{}'", region))?;
+    let names = RegionDisplayNames::try_new(locale(display_language)?.into(), Default::default())
+        .map_err(|err| format!("No display names for '{}': {}", display_language, err))?;
+    Ok(names
+        .of(code)
+        .map(str::to_string)
+        .unwrap_or_else(|| code.to_string()))
+}
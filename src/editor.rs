@@ -0,0 +1,137 @@
+//! Live editing of translations, for copy review in development builds.
+//!
+//! Edits are kept apart from the catalog and applied as the topmost translation layer, so
+//! reviewers see their changes right away while the original strings stay untouched, and
+//! the edits can be exported as JSON to be merged into the locale files. The `I18nEditor`
+//! components of the Yew and Dioxus integrations build on this module. Requires the
+//! `editor` feature.
+
+use crate::config::I18n;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// The name of the translation layer edits are applied as.
+pub const EDITOR_LAYER: &str = "editor";
+
+/// The inline style of the `I18nEditor` panels, pinned to a corner above the app.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) const EDITOR_STYLE: &str = "position: fixed; right: 1rem; bottom: 1rem; \
+    z-index: 2147483647; width: 24rem; max-height: 50vh; overflow: auto; padding: 0.5rem; \
+    background: #fff; color: #000; border: 1px solid #888; font: 12px monospace;";
+
+/// Translations edited at runtime, by language and key.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TranslationEdits {
+    edits: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl TranslationEdits {
+    /// Records the edited translation of a key, replacing any previous edit.
+    ///
+    /// # Arguments
+    /// - `language`: The language code of the translation (e.g., `"en"`).
+    /// - `key`: The translation key (e.g., `"auth.login"`).
+    /// - `value`: The new translation.
+    pub fn set(&mut self, language: &str, key: &str, value: &str) {
+        self.edits
+            .entry(language.to_string())
+            .or_default()
+            .insert(key.to_string(), value.to_string());
+    }
+
+    /// Retrieves the edited translation of a key, if any.
+    pub fn get(&self, language: &str, key: &str) -> Option<&str> {
+        self.edits.get(language)?.get(key).map(String::as_str)
+    }
+
+    /// Checks whether nothing has been edited.
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Discards every edit.
+    pub fn clear(&mut self) {
+        self.edits.clear();
+    }
+
+    /// Exports the edits of a language as a JSON bundle.
+    ///
+    /// Keys are nested by their dot-separated path, so the output can be merged into the
+    /// locale file of the language as is.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::editor::TranslationEdits;
+    ///
+    /// let mut edits = TranslationEdits::default();
+    /// edits.set("en", "auth.login", "Log in");
+    /// edits.set("en", "auth.logout", "Log out");
+    ///
+    /// assert_eq!(
+    ///     edits.export("en"),
+    ///     r#"{
+    ///   "auth": {
+    ///     "login": "Log in",
+    ///     "logout": "Log out"
+    ///   }
+    /// }"#
+    /// );
+    /// assert_eq!(edits.export("de"), "{}");
+    /// ```
+    pub fn export(&self, language: &str) -> String {
+        let mut json = Value::Object(Map::new());
+        for (key, value) in self.edits.get(language).into_iter().flatten() {
+            let mut current = &mut json;
+            for segment in key.split('.') {
+                if !current.is_object() {
+                    *current = Value::Object(Map::new());
+                }
+                current = current
+                    .as_object_mut()
+                    .map(|object| object.entry(segment).or_insert(Value::Null))
+                    .expect("converted to an object above");
+            }
+            *current = Value::String(value.clone());
+        }
+        serde_json::to_string_pretty(&json).unwrap_or_default()
+    }
+
+    /// Exports the edits of every edited language, by language code.
+    pub fn export_all(&self) -> HashMap<String, String> {
+        self.edits
+            .keys()
+            .map(|language| (language.clone(), self.export(language)))
+            .collect()
+    }
+
+    /// Applies the edits to an instance as the `EDITOR_LAYER` translation layer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::editor::TranslationEdits;
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"auth": {"login": "Sign in"}}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig { translations: translations.clone() },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut edits = TranslationEdits::default();
+    /// edits.set("en", "auth.login", "Log in");
+    /// edits.apply(&mut i18n).unwrap();
+    /// assert_eq!(i18n.t("auth.login"), "Log in");
+    /// ```
+    pub fn apply(&self, i18n: &mut I18n) -> Result<(), String> {
+        let bundles = self.export_all();
+        i18n.set_layer(
+            EDITOR_LAYER,
+            bundles
+                .iter()
+                .map(|(language, json)| (language.as_str(), json.as_str()))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,84 @@
+//! Localization of error types.
+//!
+//! Implementing `LocalizedError` maps each error variant to a translation key and its
+//! placeholder values, so errors reach the UI as localized messages rather than the
+//! English text of their `Display` implementation.
+
+use crate::config::I18n;
+use std::fmt::Display;
+
+/// An error that can be displayed through a translation.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::{I18n, I18nConfig, LocalizeResult, LocalizedError};
+/// use std::collections::HashMap;
+/// use std::fmt::Display;
+///
+/// #[derive(Debug)]
+/// enum ApiError {
+///     NotFound { id: u32 },
+///     RateLimited { retry_after: u64 },
+/// }
+///
+/// impl LocalizedError for ApiError {
+///     fn error_key(&self) -> &'static str {
+///         match self {
+///             ApiError::NotFound { .. } => "errors.not_found",
+///             ApiError::RateLimited { .. } => "errors.rate_limited",
+///         }
+///     }
+///
+///     fn error_args(&self) -> Vec<(&'static str, &dyn Display)> {
+///         match self {
+///             ApiError::NotFound { id } => vec![("id", id)],
+///             ApiError::RateLimited { retry_after } => vec![("seconds", retry_after)],
+///         }
+///     }
+/// }
+///
+/// let translations = HashMap::from([(
+///     "fr",
+///     r#"{"errors": {"not_found": "Élément {id} introuvable", "rate_limited": "Réessayez dans {seconds} s"}}"#,
+/// )]);
+/// let i18n = I18n::new(I18nConfig { translations: translations.clone() }, translations).unwrap();
+///
+/// assert_eq!(ApiError::NotFound { id: 7 }.localize(&i18n), "Élément 7 introuvable");
+///
+/// let result: Result<(), ApiError> = Err(ApiError::RateLimited { retry_after: 30 });
+/// assert_eq!(result.localize_err(&i18n), Err("Réessayez dans 30 s".to_string()));
+/// ```
+pub trait LocalizedError {
+    /// Returns the translation key of the error (e.g., `"errors.not_found"`).
+    fn error_key(&self) -> &'static str;
+
+    /// Returns the values of the message's `{name}` placeholders.
+    ///
+    /// Defaults to no values.
+    fn error_args(&self) -> Vec<(&'static str, &dyn Display)> {
+        Vec::new()
+    }
+
+    /// Translates the error with an i18n instance.
+    fn localize(&self, i18n: &I18n) -> String {
+        i18n.t_with(self.error_key(), &self.error_args())
+    }
+}
+
+/// Localizes the error of a `Result`.
+pub trait LocalizeResult<T> {
+    /// Replaces the error with its translation.
+    ///
+    /// # Arguments
+    /// - `i18n`: The i18n instance to translate with.
+    ///
+    /// # Returns
+    /// - The `Ok` value unchanged, or the localized message as the `Err` value.
+    fn localize_err(self, i18n: &I18n) -> Result<T, String>;
+}
+
+impl<T, E: LocalizedError> LocalizeResult<T> for Result<T, E> {
+    fn localize_err(self, i18n: &I18n) -> Result<T, String> {
+        self.map_err(|err| err.localize(i18n))
+    }
+}
@@ -0,0 +1,168 @@
+//! Extraction of translation keys referenced in Rust source.
+//!
+//! Backs the `i18nrs extract` command. The scanner recognizes string-literal keys passed
+//! to `t`, `t_with`, `t_ctx`, `t_html`, `t_markdown`, and `t!`, as well as `i18n_key`
+//! props. Keys built at runtime and keys relative to a scope or namespace cannot be
+//! resolved statically and are not reported.
+
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// Call patterns followed by a key, and whether the key is the second argument.
+const PATTERNS: &[(&str, bool)] = &[
+    ("t(", false),
+    ("t_with(", false),
+    ("t_ctx(", false),
+    ("t_html(", false),
+    ("t_markdown(", false),
+    ("t!(", true),
+    ("i18n_key=", false),
+    ("i18n_key:", false),
+];
+
+/// Extracts the translation keys referenced in a Rust source file.
+///
+/// # Arguments
+/// - `source`: The content of the source file.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::extract::extract_keys;
+///
+/// let keys = extract_keys(r#"
+///     let title = i18n.t("checkout.title");
+///     let total = t!(i18n, "checkout.total", amount = 3);
+///     let text = format!("{}", title);
+/// "#);
+/// assert_eq!(keys.into_iter().collect::<Vec<_>>(), ["checkout.title", "checkout.total"]);
+/// ```
+pub fn extract_keys(source: &str) -> BTreeSet<String> {
+    let mut keys = BTreeSet::new();
+
+    for (pattern, second_argument) in PATTERNS {
+        let mut rest = source;
+        while let Some(position) = rest.find(pattern) {
+            let preceded_by_ident = rest[..position]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            rest = &rest[position + pattern.len()..];
+            if preceded_by_ident {
+                continue;
+            }
+
+            let arguments = if *second_argument {
+                match rest.find(',') {
+                    Some(comma) => &rest[comma + 1..],
+                    None => continue,
+                }
+            } else {
+                rest
+            };
+
+            if let Some(key) = string_literal(arguments.trim_start()).filter(|key| is_key(key)) {
+                keys.insert(key.to_string());
+            }
+        }
+    }
+
+    keys
+}
+
+/// Extracts the translation keys referenced in every `.rs` file below a directory.
+///
+/// # Arguments
+/// - `dir`: The directory to scan recursively (e.g., `"src"`).
+///
+/// # Returns
+/// - `Ok(BTreeSet<String>)` with every referenced key.
+/// - `Err(String)` if a directory or file cannot be read.
+pub fn scan_dir(dir: impl AsRef<Path>) -> Result<BTreeSet<String>, String> {
+    let dir = dir.as_ref();
+    let mut keys = BTreeSet::new();
+    let entries =
+        fs::read_dir(dir).map_err(|err| format!("Failed to read '{}': {}", dir.display(), err))?;
+
+    for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+        if path.is_dir() {
+            keys.extend(scan_dir(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let source = fs::read_to_string(&path)
+                .map_err(|err| format!("Failed to read '{}': {}", path.display(), err))?;
+            keys.extend(extract_keys(&source));
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Adds keys missing from a locale JSON object, with empty strings as placeholders.
+///
+/// Existing values are never overwritten. A key whose path runs through an existing string
+/// value is skipped.
+///
+/// # Arguments
+/// - `json`: The locale JSON object to update.
+/// - `keys`: The dot-separated keys to add.
+///
+/// # Returns
+/// - The keys that were added.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::extract::merge_keys;
+/// use serde_json::json;
+///
+/// let mut locale = json!({"checkout": {"title": "Checkout"}});
+/// let added = merge_keys(&mut locale, ["checkout.title", "checkout.total"]);
+///
+/// assert_eq!(added, ["checkout.total"]);
+/// assert_eq!(locale, json!({"checkout": {"title": "Checkout", "total": ""}}));
+/// ```
+pub fn merge_keys<'a>(json: &mut Value, keys: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut added = Vec::new();
+
+    'keys: for key in keys {
+        let mut current = &mut *json;
+        let segments: Vec<&str> = key.split('.').collect();
+        for (index, segment) in segments.iter().enumerate() {
+            let Value::Object(map) = current else {
+                continue 'keys;
+            };
+            let last = index == segments.len() - 1;
+            if !map.contains_key(*segment) {
+                let value = if last {
+                    Value::String(String::new())
+                } else {
+                    Value::Object(Map::new())
+                };
+                map.insert(segment.to_string(), value);
+                if last {
+                    added.push(key.to_string());
+                }
+            }
+            current = map.get_mut(*segment).expect("inserted above");
+        }
+    }
+
+    added
+}
+
+/// Reads a plain string literal at the start of the input, without escapes.
+fn string_literal(input: &str) -> Option<&str> {
+    let content = input.strip_prefix('"')?;
+    let end = content.find(['"', '\\'])?;
+    content[end..].starts_with('"').then(|| &content[..end])
+}
+
+/// Checks whether a literal looks like a translation key rather than arbitrary text.
+fn is_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with('.')
+        && !key.ends_with('.')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
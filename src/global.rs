@@ -0,0 +1,77 @@
+//! A process-wide i18n handle for code outside the component tree.
+//!
+//! Error types, validators and logging code can translate with `global()` instead of
+//! threading the context through every function. Providers with the `global` prop keep
+//! the handle in sync with the active language. On servers, where each request has its
+//! own language, prefer `SharedI18n` views.
+
+use crate::config::{I18n, I18nArgs, interpolate};
+use std::sync::{Arc, RwLock};
+
+static GLOBAL: RwLock<Option<Arc<I18n>>> = RwLock::new(None);
+
+/// Installs the instance used by `global()`, replacing any previous one.
+pub fn set_global(i18n: I18n) {
+    *GLOBAL.write().unwrap_or_else(|err| err.into_inner()) = Some(Arc::new(i18n));
+}
+
+/// Removes the instance used by `global()`.
+pub fn clear_global() {
+    *GLOBAL.write().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// Retrieves a snapshot of the global instance.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::global::{global, set_global};
+/// use i18nrs::{I18n, I18nConfig};
+/// use std::collections::HashMap;
+///
+/// fn validate(name: &str) -> Result<(), String> {
+///     if name.is_empty() {
+///         return Err(global().t("errors.required"));
+///     }
+///     Ok(())
+/// }
+///
+/// let translations = HashMap::from([("en", r#"{"errors": {"required": "Required"}}"#)]);
+/// set_global(I18n::new(I18nConfig { translations: translations.clone() }, translations).unwrap());
+///
+/// assert_eq!(validate(""), Err("Required".to_string()));
+/// ```
+pub fn global() -> GlobalI18n {
+    GlobalI18n(GLOBAL.read().unwrap_or_else(|err| err.into_inner()).clone())
+}
+
+/// A snapshot of the global instance, returned by `global()`.
+#[derive(Clone)]
+pub struct GlobalI18n(Option<Arc<I18n>>);
+
+impl GlobalI18n {
+    /// Checks whether a global instance is installed.
+    pub fn is_set(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Retrieves the active language code, if a global instance is installed.
+    pub fn language(&self) -> Option<&str> {
+        self.0.as_deref().map(I18n::get_current_language)
+    }
+
+    /// Translates a key in the active language; see `I18n::t`.
+    ///
+    /// Returns the key itself if no global instance is installed yet.
+    pub fn t(&self, key: &str) -> String {
+        self.0
+            .as_ref()
+            .map_or_else(|| key.to_string(), |i18n| i18n.t(key))
+    }
+
+    /// Translates a key and substitutes its `{name}` placeholders; see `I18n::t_with`.
+    pub fn t_with(&self, key: &str, args: I18nArgs<'_>) -> String {
+        self.0
+            .as_ref()
+            .map_or_else(|| interpolate("", key, args), |i18n| i18n.t_with(key, args))
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// The future returned by a `LanguageGuard`, resolving to whether the switch may proceed.
+pub type GuardFuture = Pin<Box<dyn Future<Output = bool>>>;
+
+/// A requested switch from one language to another.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LanguageChange {
+    /// The current language code (e.g., `"en"`).
+    pub from: String,
+    /// The requested language code (e.g., `"fr"`).
+    pub to: String,
+}
+
+/// An async guard invoked before a language switch completes.
+///
+/// The provider awaits the guard before applying a switch requested through
+/// `set_language`, so the guard can persist the preference to a server or ask the user to
+/// confirm leaving a form with unsaved changes. Resolving to `false` cancels the switch;
+/// taking time to resolve delays it.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::guard::{LanguageChange, LanguageGuard};
+///
+/// let guard = LanguageGuard::new(|change: LanguageChange| async move {
+///     // e.g. save `change.to` to the user's profile, and only switch if that worked.
+///     change.to != "tlh"
+/// });
+/// ```
+#[derive(Clone)]
+pub struct LanguageGuard(Rc<dyn Fn(LanguageChange) -> GuardFuture>);
+
+impl LanguageGuard {
+    /// Creates a guard from an async function taking the requested change.
+    ///
+    /// # Arguments
+    /// - `guard`: A function returning a future that resolves to `true` to let the switch
+    ///   proceed, or `false` to cancel it.
+    pub fn new<F, Fut>(guard: F) -> Self
+    where
+        F: Fn(LanguageChange) -> Fut + 'static,
+        Fut: Future<Output = bool> + 'static,
+    {
+        Self(Rc::new(move |change| Box::pin(guard(change))))
+    }
+
+    /// Invokes the guard for a change.
+    ///
+    /// # Returns
+    /// - `true` if the switch may proceed, `false` if it is cancelled.
+    pub async fn check(&self, change: LanguageChange) -> bool {
+        (self.0)(change).await
+    }
+}
+
+impl PartialEq for LanguageGuard {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for LanguageGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LanguageGuard").finish_non_exhaustive()
+    }
+}
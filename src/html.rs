@@ -0,0 +1,29 @@
+//! Sanitization of translation values that contain HTML snippets.
+//!
+//! Some translation vendors return values with inline HTML. These helpers keep a safe
+//! allowlist of formatting tags and attributes, and strip scripts, event handlers, and
+//! unsafe URLs, so the values can be rendered as real nodes.
+
+/// Sanitizes an HTML snippet.
+///
+/// Uses [`ammonia`]'s default allowlist: common formatting and structural tags are kept,
+/// `<script>`/`<style>` elements are removed with their content, event handler attributes
+/// are dropped, links only keep safe URL schemes, and `rel="noopener noreferrer"` is added
+/// to links.
+///
+/// # Arguments
+/// - `html`: The HTML snippet, usually a translation value.
+///
+/// # Returns
+/// - The sanitized HTML.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::html::sanitize;
+///
+/// let html = sanitize(r#"<em onclick="steal()">Hi</em> <a href="javascript:x()">there</a>"#);
+/// assert_eq!(html, r#"<em>Hi</em> <a rel="noopener noreferrer">there</a>"#);
+/// ```
+pub fn sanitize(html: &str) -> String {
+    ammonia::clean(html)
+}
@@ -0,0 +1,112 @@
+//! Conversions from `jiff` types to the inputs of the `datetime` formatters.
+//!
+//! Lets `jiff::civil::Date`, `civil::DateTime` and `Zoned` values be passed directly to
+//! `format_date`, `format_skeleton`, `format_pattern` and `format_zoned`. Requires the
+//! `jiff` feature.
+//!
+//! # Example
+//! ```rust
+//! use i18nrs::datetime::{DateStyle, ZoneStyle, format_date, format_skeleton, format_zoned};
+//! use jiff::civil::date;
+//! use jiff::tz::{TimeZone, offset};
+//!
+//! let day = date(2025, 1, 15);
+//! assert_eq!(format_date("fr", &day, DateStyle::Long).unwrap(), "15 janvier 2025");
+//!
+//! let meeting = day.at(17, 5, 0, 0);
+//! assert_eq!(format_skeleton("de", &meeting, "EEEEHm").unwrap(), "Mittwoch um 17:05");
+//!
+//! let zoned = meeting.to_zoned(TimeZone::fixed(offset(1))).unwrap();
+//! assert_eq!(
+//!     format_zoned("en", &zoned, DateStyle::Medium, ZoneStyle::LocalizedOffsetShort).unwrap(),
+//!     "Jan 15, 2025, 5:05\u{202f}PM GMT+1"
+//! );
+//! ```
+
+use crate::datetime::{
+    Date, DateTime, Iso, Time, TimeZone, ToDate, ToDateTime, ToZonedDateTime, UtcOffset,
+    ZonedDateTime,
+};
+use ::jiff::Zoned;
+use ::jiff::civil;
+
+/// Converts a `jiff` date.
+fn date(date: civil::Date) -> Result<Date<Iso>, String> {
+    Date::try_new_iso(date.year().into(), date.month() as u8, date.day() as u8)
+        .map_err(|err| format!("Unsupported date: {}", err))
+}
+
+/// Converts a `jiff` date and time.
+fn datetime(datetime: civil::DateTime) -> Result<DateTime<Iso>, String> {
+    let time = Time::try_new(
+        datetime.hour() as u8,
+        datetime.minute() as u8,
+        datetime.second() as u8,
+        datetime.subsec_nanosecond() as u32,
+    )
+    .map_err(|err| format!("Unsupported time: {}", err))?;
+    Ok(DateTime {
+        date: date(datetime.date())?,
+        time,
+    })
+}
+
+impl ToDate for civil::Date {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        date(*self)
+    }
+}
+
+impl ToDateTime for civil::Date {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        Ok(DateTime {
+            date: date(*self)?,
+            time: Time::start_of_day(),
+        })
+    }
+}
+
+impl ToDate for civil::DateTime {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        date(self.date())
+    }
+}
+
+impl ToDateTime for civil::DateTime {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        datetime(*self)
+    }
+}
+
+impl ToDate for Zoned {
+    fn to_iso_date(&self) -> Result<Date<Iso>, String> {
+        date(self.date())
+    }
+}
+
+impl ToDateTime for Zoned {
+    fn to_iso_datetime(&self) -> Result<DateTime<Iso>, String> {
+        datetime(self.datetime())
+    }
+}
+
+/// The value is formatted in its own time zone. Zones with an IANA identifier are named in
+/// the formatted output, while fixed offsets fall back to the GMT offset.
+impl ToZonedDateTime for Zoned {
+    fn to_zoned_datetime(&self) -> Result<ZonedDateTime, String> {
+        let local = datetime(self.datetime())?;
+        let offset = UtcOffset::try_from_seconds(self.offset().seconds())
+            .map_err(|err| format!("Unsupported offset: {}", err))?;
+        let zone = self
+            .time_zone()
+            .iana_name()
+            .map_or(TimeZone::UNKNOWN, TimeZone::from_iana_id)
+            .with_offset(Some(offset))
+            .at_date_time(local);
+        Ok(ZonedDateTime {
+            date: local.date,
+            time: local.time,
+            zone,
+        })
+    }
+}
@@ -0,0 +1,455 @@
+//! Built-in metadata of common languages.
+//!
+//! Provides the English name and the autonym (the name of a language in itself, e.g.
+//! "العربية" or "Deutsch") of common languages without any locale data, so language
+//! selectors can always label each option in its own script, whatever the active language.
+
+use crate::config::TextDirection;
+use std::collections::HashMap;
+
+/// Metadata of a language known to the crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LanguageMetadata {
+    /// The BCP 47 language code (e.g., `"pt-BR"`).
+    pub code: &'static str,
+    /// The name of the language in English (e.g., `"Brazilian Portuguese"`).
+    pub english_name: &'static str,
+    /// The name of the language in itself (e.g., `"Português (Brasil)"`).
+    pub native_name: &'static str,
+}
+
+macro_rules! languages {
+    ($(($code:literal, $english:literal, $native:literal)),* $(,)?) => {
+        &[$(LanguageMetadata { code: $code, english_name: $english, native_name: $native }),*]
+    };
+}
+
+/// The languages known to the crate, regional variants included.
+pub const LANGUAGES: &[LanguageMetadata] = languages![
+    ("af", "Afrikaans", "Afrikaans"),
+    ("am", "Amharic", "አማርኛ"),
+    ("ar", "Arabic", "العربية"),
+    ("az", "Azerbaijani", "Azərbaycan"),
+    ("be", "Belarusian", "Беларуская"),
+    ("bg", "Bulgarian", "Български"),
+    ("bn", "Bangla", "বাংলা"),
+    ("bs", "Bosnian", "Bosanski"),
+    ("ca", "Catalan", "Català"),
+    ("cs", "Czech", "Čeština"),
+    ("cy", "Welsh", "Cymraeg"),
+    ("da", "Danish", "Dansk"),
+    ("de", "German", "Deutsch"),
+    ("el", "Greek", "Ελληνικά"),
+    ("en", "English", "English"),
+    ("en-GB", "British English", "English (UK)"),
+    ("en-US", "American English", "English (US)"),
+    ("eo", "Esperanto", "Esperanto"),
+    ("es", "Spanish", "Español"),
+    (
+        "es-419",
+        "Latin American Spanish",
+        "Español (Latinoamérica)"
+    ),
+    ("et", "Estonian", "Eesti"),
+    ("eu", "Basque", "Euskara"),
+    ("fa", "Persian", "فارسی"),
+    ("fi", "Finnish", "Suomi"),
+    ("fil", "Filipino", "Filipino"),
+    ("fr", "French", "Français"),
+    ("fr-CA", "Canadian French", "Français (Canada)"),
+    ("ga", "Irish", "Gaeilge"),
+    ("gl", "Galician", "Galego"),
+    ("gu", "Gujarati", "ગુજરાતી"),
+    ("ha", "Hausa", "Hausa"),
+    ("he", "Hebrew", "עברית"),
+    ("hi", "Hindi", "हिन्दी"),
+    ("hr", "Croatian", "Hrvatski"),
+    ("hu", "Hungarian", "Magyar"),
+    ("hy", "Armenian", "Հայերեն"),
+    ("id", "Indonesian", "Bahasa Indonesia"),
+    ("ig", "Igbo", "Igbo"),
+    ("is", "Icelandic", "Íslenska"),
+    ("it", "Italian", "Italiano"),
+    ("ja", "Japanese", "日本語"),
+    ("ka", "Georgian", "ქართული"),
+    ("kk", "Kazakh", "Қазақ тілі"),
+    ("km", "Khmer", "ខ្មែរ"),
+    ("kn", "Kannada", "ಕನ್ನಡ"),
+    ("ko", "Korean", "한국어"),
+    ("ku", "Kurdish", "Kurdî"),
+    ("ky", "Kyrgyz", "Кыргызча"),
+    ("lo", "Lao", "ລາວ"),
+    ("lt", "Lithuanian", "Lietuvių"),
+    ("lv", "Latvian", "Latviešu"),
+    ("mk", "Macedonian", "Македонски"),
+    ("ml", "Malayalam", "മലയാളം"),
+    ("mn", "Mongolian", "Монгол"),
+    ("mr", "Marathi", "मराठी"),
+    ("ms", "Malay", "Bahasa Melayu"),
+    ("mt", "Maltese", "Malti"),
+    ("my", "Burmese", "မြန်မာ"),
+    ("nb", "Norwegian Bokmål", "Norsk bokmål"),
+    ("ne", "Nepali", "नेपाली"),
+    ("nl", "Dutch", "Nederlands"),
+    ("nn", "Norwegian Nynorsk", "Norsk nynorsk"),
+    ("no", "Norwegian", "Norsk"),
+    ("pa", "Punjabi", "ਪੰਜਾਬੀ"),
+    ("pl", "Polish", "Polski"),
+    ("ps", "Pashto", "پښتو"),
+    ("pt", "Portuguese", "Português"),
+    ("pt-BR", "Brazilian Portuguese", "Português (Brasil)"),
+    ("pt-PT", "European Portuguese", "Português (Portugal)"),
+    ("ro", "Romanian", "Română"),
+    ("ru", "Russian", "Русский"),
+    ("sd", "Sindhi", "سنڌي"),
+    ("si", "Sinhala", "සිංහල"),
+    ("sk", "Slovak", "Slovenčina"),
+    ("sl", "Slovenian", "Slovenščina"),
+    ("so", "Somali", "Soomaali"),
+    ("sq", "Albanian", "Shqip"),
+    ("sr", "Serbian", "Српски"),
+    ("sv", "Swedish", "Svenska"),
+    ("sw", "Swahili", "Kiswahili"),
+    ("ta", "Tamil", "தமிழ்"),
+    ("te", "Telugu", "తెలుగు"),
+    ("th", "Thai", "ไทย"),
+    ("tr", "Turkish", "Türkçe"),
+    ("uk", "Ukrainian", "Українська"),
+    ("ur", "Urdu", "اردو"),
+    ("uz", "Uzbek", "Oʻzbek"),
+    ("vi", "Vietnamese", "Tiếng Việt"),
+    ("yo", "Yoruba", "Èdè Yorùbá"),
+    ("zh", "Chinese", "中文"),
+    ("zh-Hans", "Simplified Chinese", "简体中文"),
+    ("zh-Hant", "Traditional Chinese", "繁體中文"),
+    ("zu", "Zulu", "isiZulu"),
+];
+
+/// Looks up the metadata of a language.
+///
+/// The full code is tried first, so regional variants with their own entry keep their
+/// name, then its primary subtag (`"de-AT"` resolves to German). Codes are matched
+/// case-insensitively, with `_` or `-` as the separator.
+///
+/// # Arguments
+/// - `language`: The language code (e.g., `"ar"` or `"pt_BR"`).
+///
+/// # Returns
+/// - `Some(&LanguageMetadata)` for a known language.
+/// - `None` if the crate has no metadata for it.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::languages::language_metadata;
+///
+/// assert_eq!(language_metadata("ar").unwrap().native_name, "العربية");
+/// assert_eq!(language_metadata("de-AT").unwrap().native_name, "Deutsch");
+/// assert_eq!(language_metadata("pt_br").unwrap().english_name, "Brazilian Portuguese");
+/// assert!(language_metadata("tlh").is_none());
+/// ```
+pub fn language_metadata(language: &str) -> Option<&'static LanguageMetadata> {
+    let language = language.replace('_', "-");
+    let find = |code: &str| {
+        LANGUAGES
+            .iter()
+            .find(|metadata| metadata.code.eq_ignore_ascii_case(code))
+    };
+    find(&language).or_else(|| find(language.split('-').next().unwrap_or_default()))
+}
+
+/// Returns the name of a language in itself (e.g., `"Deutsch"` for `"de"`).
+///
+/// # Arguments
+/// - `language`: The language code; see `language_metadata` for the matching rules.
+///
+/// # Returns
+/// - `Some(&str)` with the autonym of a known language, `None` otherwise.
+pub fn autonym(language: &str) -> Option<&'static str> {
+    language_metadata(language).map(|metadata| metadata.native_name)
+}
+
+/// Regions whose flag stands for a language written without a region subtag.
+///
+/// Only languages with one clearly associated country are listed; flags are a poor proxy
+/// for languages, so the others get none.
+const FLAG_REGIONS: &[(&str, &str)] = &[
+    ("af", "ZA"),
+    ("am", "ET"),
+    ("ar", "SA"),
+    ("az", "AZ"),
+    ("be", "BY"),
+    ("bg", "BG"),
+    ("bn", "BD"),
+    ("bs", "BA"),
+    ("cs", "CZ"),
+    ("da", "DK"),
+    ("de", "DE"),
+    ("el", "GR"),
+    ("en", "US"),
+    ("es", "ES"),
+    ("et", "EE"),
+    ("fa", "IR"),
+    ("fi", "FI"),
+    ("fil", "PH"),
+    ("fr", "FR"),
+    ("ga", "IE"),
+    ("he", "IL"),
+    ("hi", "IN"),
+    ("hr", "HR"),
+    ("hu", "HU"),
+    ("hy", "AM"),
+    ("id", "ID"),
+    ("is", "IS"),
+    ("it", "IT"),
+    ("ja", "JP"),
+    ("ka", "GE"),
+    ("kk", "KZ"),
+    ("km", "KH"),
+    ("ko", "KR"),
+    ("ky", "KG"),
+    ("lo", "LA"),
+    ("lt", "LT"),
+    ("lv", "LV"),
+    ("mk", "MK"),
+    ("mn", "MN"),
+    ("ms", "MY"),
+    ("mt", "MT"),
+    ("my", "MM"),
+    ("nb", "NO"),
+    ("ne", "NP"),
+    ("nl", "NL"),
+    ("nn", "NO"),
+    ("no", "NO"),
+    ("pl", "PL"),
+    ("ps", "AF"),
+    ("pt", "PT"),
+    ("ro", "RO"),
+    ("ru", "RU"),
+    ("si", "LK"),
+    ("sk", "SK"),
+    ("sl", "SI"),
+    ("so", "SO"),
+    ("sq", "AL"),
+    ("sr", "RS"),
+    ("sv", "SE"),
+    ("sw", "KE"),
+    ("th", "TH"),
+    ("tr", "TR"),
+    ("uk", "UA"),
+    ("ur", "PK"),
+    ("uz", "UZ"),
+    ("vi", "VN"),
+    ("zh", "CN"),
+    ("zh-Hant", "TW"),
+];
+
+/// Returns the country most associated with a language without a region subtag.
+pub(crate) fn default_region(language: &str) -> Option<&'static str> {
+    let find = |code: &str| {
+        FLAG_REGIONS
+            .iter()
+            .find(|(language, _)| language.eq_ignore_ascii_case(code))
+            .map(|(_, region)| *region)
+    };
+    let language = language.replace('_', "-");
+    find(&language).or_else(|| find(language.split('-').next().unwrap_or_default()))
+}
+
+/// Returns the flag emoji of a region (e.g., `"GB"` gets 🇬🇧).
+///
+/// # Returns
+/// - `Some(String)` with the regional indicator pair for a two-letter region code.
+/// - `None` for anything else, such as numeric regions like `"419"`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::languages::region_flag;
+///
+/// assert_eq!(region_flag("gb").as_deref(), Some("🇬🇧"));
+/// assert_eq!(region_flag("419"), None);
+/// ```
+pub fn region_flag(region: &str) -> Option<String> {
+    if region.len() != 2 || !region.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    region
+        .to_ascii_uppercase()
+        .chars()
+        .map(|c| char::from_u32(0x1F1E6 + (c as u32 - 'A' as u32)))
+        .collect()
+}
+
+/// Returns the flag emoji commonly used for a language in language pickers.
+///
+/// The region subtag of the code is used when present (`"pt-BR"` gets 🇧🇷), otherwise
+/// the country most associated with the language (`"de"` gets 🇩🇪). Languages spoken
+/// across many countries without a dominant one, and macro-regions like `"es-419"`, get
+/// none. Use `Flags` to pick other countries or custom icons.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::languages::flag;
+///
+/// assert_eq!(flag("pt-BR").as_deref(), Some("🇧🇷"));
+/// assert_eq!(flag("ar").as_deref(), Some("🇸🇦"));
+/// assert_eq!(flag("en_GB").as_deref(), Some("🇬🇧"));
+/// assert_eq!(flag("es-419"), None);
+/// assert_eq!(flag("eo"), None);
+/// ```
+pub fn flag(language: &str) -> Option<String> {
+    Flags::default().flag(language)
+}
+
+/// The icon to show for a language in a language picker.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LanguageIcon {
+    /// A flag emoji.
+    Flag(String),
+    /// An app-provided icon, such as an image URL or an icon class name.
+    Custom(String),
+}
+
+/// Resolves the flags of languages, with app-specific choices.
+///
+/// Flags stand for countries, not languages, so apps disagree on e.g. whether `"en"`
+/// gets 🇺🇸 or 🇬🇧. The defaults are those of `flag`; `with_region` picks another country
+/// for a language and `with_icon` replaces its flag with a custom icon.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::languages::{Flags, LanguageIcon};
+///
+/// let flags = Flags::default()
+///     .with_region("en", "GB")
+///     .with_icon("eo", "/icons/esperanto.svg");
+///
+/// assert_eq!(flags.flag("en").as_deref(), Some("🇬🇧"));
+/// assert_eq!(flags.flag("en-US").as_deref(), Some("🇺🇸"));
+/// assert_eq!(
+///     flags.icon("eo"),
+///     Some(LanguageIcon::Custom("/icons/esperanto.svg".to_string()))
+/// );
+/// assert_eq!(flags.icon("de"), Some(LanguageIcon::Flag("🇩🇪".to_string())));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Flags {
+    regions: HashMap<String, String>,
+    icons: HashMap<String, String>,
+}
+
+impl Flags {
+    /// Shows the flag of a region for a language.
+    ///
+    /// An override for a primary subtag (e.g., `"en"`) also applies to codes of the
+    /// language without a region subtag, such as `"en-Latn"`; codes with one keep it.
+    ///
+    /// # Arguments
+    /// - `language`: The language code (e.g., `"en"` or `"es-419"`).
+    /// - `region`: The two-letter region code whose flag to show (e.g., `"GB"`).
+    pub fn with_region(mut self, language: &str, region: &str) -> Self {
+        self.regions.insert(
+            language.replace('_', "-").to_ascii_lowercase(),
+            region.to_string(),
+        );
+        self
+    }
+
+    /// Shows a custom icon for a language instead of a flag.
+    ///
+    /// # Arguments
+    /// - `language`: The language code, matched in full (e.g., `"eo"`).
+    /// - `icon`: The icon, such as an image URL or an icon class name.
+    pub fn with_icon(mut self, language: &str, icon: &str) -> Self {
+        self.icons.insert(
+            language.replace('_', "-").to_ascii_lowercase(),
+            icon.to_string(),
+        );
+        self
+    }
+
+    /// Returns the flag emoji of a language, ignoring custom icons.
+    ///
+    /// An override for the full code wins, then the region subtag of the code, then an
+    /// override for its primary subtag, then the defaults of `flag`.
+    pub fn flag(&self, language: &str) -> Option<String> {
+        let language = language.replace('_', "-");
+        let code = language.to_ascii_lowercase();
+        if let Some(region) = self.regions.get(&code) {
+            return region_flag(region);
+        }
+
+        let subtags: Vec<&str> = language.split('-').collect();
+        if subtags[1..]
+            .iter()
+            .any(|subtag| subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+        {
+            return None;
+        }
+        if let Some(region) = subtags[1..].iter().find_map(|subtag| region_flag(subtag)) {
+            return Some(region);
+        }
+        if let Some(region) = self.regions.get(&subtags[0].to_ascii_lowercase()) {
+            return region_flag(region);
+        }
+
+        default_region(&language).and_then(region_flag)
+    }
+
+    /// Returns the icon of a language: its custom icon if any, otherwise its flag.
+    pub fn icon(&self, language: &str) -> Option<LanguageIcon> {
+        self.icons
+            .get(&language.replace('_', "-").to_ascii_lowercase())
+            .map(|icon| LanguageIcon::Custom(icon.clone()))
+            .or_else(|| self.flag(language).map(LanguageIcon::Flag))
+    }
+}
+
+/// Everything a language picker needs to display a language.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LanguageInfo {
+    /// The language code as used by the translations (e.g., `"pt-BR"`).
+    pub code: String,
+    /// The name of the language in English, if the crate knows the language.
+    pub english_name: Option<&'static str>,
+    /// The name of the language in itself, if the crate knows the language.
+    pub native_name: Option<&'static str>,
+    /// The writing direction of the language.
+    pub direction: TextDirection,
+    /// The flag emoji commonly used for the language; see `flag`, or `Flags` to resolve
+    /// it differently.
+    pub flag: Option<String>,
+}
+
+impl LanguageInfo {
+    /// Collects the metadata of a language.
+    ///
+    /// The direction follows `TextDirection::resolve` without overrides; use
+    /// `I18n::available_languages` to honor the overrides of an instance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::TextDirection;
+    /// use i18nrs::languages::LanguageInfo;
+    ///
+    /// let arabic = LanguageInfo::new("ar");
+    /// assert_eq!(arabic.native_name, Some("العربية"));
+    /// assert_eq!(arabic.direction, TextDirection::Rtl);
+    /// assert_eq!(arabic.flag.as_deref(), Some("🇸🇦"));
+    /// assert_eq!(LanguageInfo::new("tlh").label(), "tlh");
+    /// ```
+    pub fn new(language: &str) -> Self {
+        let metadata = language_metadata(language);
+        Self {
+            code: language.to_string(),
+            english_name: metadata.map(|metadata| metadata.english_name),
+            native_name: metadata.map(|metadata| metadata.native_name),
+            direction: TextDirection::resolve(language, &HashMap::new()),
+            flag: flag(language),
+        }
+    }
+
+    /// Returns the name to show for the language: its autonym, or its code if unknown.
+    pub fn label(&self) -> &str {
+        self.native_name.unwrap_or(&self.code)
+    }
+}
@@ -11,6 +11,86 @@ pub mod dioxus;
 #[cfg(feature = "yew")]
 pub mod yew;
 
+#[cfg(feature = "axum")]
+pub mod axum;
+
+#[cfg(feature = "casemap")]
+pub mod casemap;
+#[cfg(feature = "cli")]
+pub mod check;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(any(
+    feature = "casemap",
+    feature = "collation",
+    feature = "currency",
+    feature = "datetime",
+    feature = "displaynames",
+    feature = "measurement",
+    feature = "number"
+))]
+mod cldr;
+#[cfg(feature = "collation")]
+pub mod collation;
+pub mod compression;
 pub mod config;
+pub mod cookie;
+#[cfg(feature = "currency")]
+pub mod currency;
+#[cfg(feature = "datetime")]
+pub mod datetime;
+pub mod detect;
+#[cfg(feature = "displaynames")]
+pub mod displaynames;
+#[cfg(feature = "editor")]
+pub mod editor;
+pub mod error;
+#[cfg(feature = "cli")]
+pub mod extract;
+pub mod global;
+pub mod guard;
+#[cfg(feature = "html")]
+pub mod html;
+#[cfg(feature = "jiff")]
+mod jiff;
+pub mod languages;
+pub mod lifecycle;
+pub mod loader;
+mod macros;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+#[cfg(feature = "measurement")]
+pub mod measurement;
+pub mod negotiate;
+#[cfg(feature = "number")]
+pub mod number;
+pub mod remote;
+pub mod report;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+pub mod schema;
+pub mod seo;
+pub mod shared;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod ssg;
+#[cfg(any(feature = "dio-ssr", feature = "yew-ssr"))]
+pub mod ssr;
+#[cfg(feature = "tower")]
+pub mod tower;
+pub mod trans;
+pub mod url;
+#[cfg(feature = "validator")]
+pub mod validator;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod watch;
+
+pub use config::{
+    Coverage, I18n, I18nArgs, I18nConfig, I18nKey, I18nNamespace, I18nScope, KeyConflict,
+    LocaleDiff, LocalizedDisplay, MissingKeyHandler, PlaceholderMismatch, StaticTranslations,
+    StorageType, TextDirection, UsageReport,
+};
+pub use error::{LocalizeResult, LocalizedError};
+pub use shared::{I18nView, SharedI18n};
 
-pub use config::{I18n, I18nConfig, StorageType};
+#[cfg(feature = "macros")]
+pub use i18nrs_macros::{LocalizedDisplay, include_i18n, translation_keys};
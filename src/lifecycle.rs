@@ -0,0 +1,66 @@
+/// What triggered a language change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeSource {
+    /// The app requested it through `set_language`, e.g. from a language picker.
+    User,
+    /// The provider restored the language saved in browser storage or a cookie.
+    Storage,
+    /// The provider resolved the language from the request being server-side rendered,
+    /// e.g. from its `Accept-Language` header.
+    Detection,
+    /// The provider started in its `default_language`, as nothing was saved.
+    Default,
+    /// The provider was pinned to the language through its `language` prop.
+    Pinned,
+    /// The provider read the language from the page URL, e.g. its `#lang=` fragment.
+    Url,
+    /// The current language was removed and the provider fell back to the default one.
+    Fallback,
+}
+
+/// Whether a lifecycle event precedes or follows a language change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LifecyclePhase {
+    /// The change is about to be applied; an `onbeforechange` guard may still cancel it.
+    Before,
+    /// The change was applied.
+    After,
+}
+
+/// A structured language change event, emitted through a provider's `onlifecycle`.
+///
+/// Each change emits a `Before` event, then an `After` event once it is applied, so
+/// analytics and side effects can tell user choices from restored preferences without
+/// hooking into `onchange`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LifecycleEvent {
+    /// Whether the event precedes or follows the change.
+    pub phase: LifecyclePhase,
+    /// The previous language code, or `None` when the provider picks its first language.
+    pub from: Option<String>,
+    /// The new language code.
+    pub to: String,
+    /// What triggered the change.
+    pub source: ChangeSource,
+}
+
+#[cfg(any(feature = "yew", feature = "dio"))]
+impl LifecycleEvent {
+    /// Creates the `Before` event of a change.
+    pub(crate) fn before(from: Option<&str>, to: &str, source: ChangeSource) -> Self {
+        Self {
+            phase: LifecyclePhase::Before,
+            from: from.map(str::to_string),
+            to: to.to_string(),
+            source,
+        }
+    }
+
+    /// Turns a `Before` event into the `After` event of the same change.
+    pub(crate) fn after(self) -> Self {
+        Self {
+            phase: LifecyclePhase::After,
+            ..self
+        }
+    }
+}
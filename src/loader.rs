@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// The future returned by a `NamespaceLoader`, resolving to the raw JSON of a namespace bundle.
+pub type LoaderFuture = Pin<Box<dyn Future<Output = Result<String, String>>>>;
+
+/// A lazily invoked loader for a namespace bundle.
+///
+/// The loader receives the language code being loaded and resolves to the raw JSON content
+/// of the namespace for that language. It is only invoked the first time a component uses
+/// the namespace, which keeps the initial bundle small for apps with many screens.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::loader::NamespaceLoader;
+///
+/// let checkout = NamespaceLoader::new(|language: String| async move {
+///     match language.as_str() {
+///         "fr" => Ok(r#"{"title": "Paiement"}"#.to_string()),
+///         _ => Ok(r#"{"title": "Checkout"}"#.to_string()),
+///     }
+/// });
+/// ```
+#[derive(Clone)]
+pub struct NamespaceLoader(Rc<dyn Fn(String) -> LoaderFuture>);
+
+impl NamespaceLoader {
+    /// Creates a loader from an async function taking the language code.
+    ///
+    /// # Arguments
+    /// - `loader`: A function returning a future that resolves to the namespace JSON.
+    pub fn new<F, Fut>(loader: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Result<String, String>> + 'static,
+    {
+        Self(Rc::new(move |language| Box::pin(loader(language))))
+    }
+
+    /// Invokes the loader for the given language.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to load the namespace for (e.g., `"en"`).
+    ///
+    /// # Returns
+    /// - `Ok(String)` containing the raw JSON of the namespace.
+    /// - `Err(String)` if the loader failed.
+    pub async fn load(&self, language: &str) -> Result<String, String> {
+        (self.0)(language.to_string()).await
+    }
+}
+
+impl PartialEq for NamespaceLoader {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for NamespaceLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NamespaceLoader").finish_non_exhaustive()
+    }
+}
+
+/// Mapping of namespace names to their lazy loaders.
+pub type NamespaceLoaders = HashMap<&'static str, NamespaceLoader>;
@@ -0,0 +1,46 @@
+/// Translates a key with format-like named arguments.
+///
+/// Expands to `t` when no arguments are given and to `t_with` otherwise, so it works with
+/// `I18n`, `I18nScope`, and `I18nNamespace`. Arguments are written `name = value`, or just
+/// `name` to capture a variable of the same name, as with `format!`. Argument names must
+/// be identifiers and may not repeat, both checked at compile time.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::{I18n, I18nConfig, t};
+/// use std::collections::HashMap;
+///
+/// let translations = HashMap::from([("en", r#"{"welcome": {"user": "Hi {name}, {count} items"}}"#)]);
+/// let i18n = I18n::new(
+///     I18nConfig { translations: translations.clone() },
+///     translations,
+/// )
+/// .unwrap();
+///
+/// let name = "Ada";
+/// let cart = vec![1, 2, 3];
+/// assert_eq!(t!(i18n, "welcome.user", name, count = cart.len()), "Hi Ada, 3 items");
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($i18n:expr, $key:expr $(,)?) => {
+        $i18n.t($key)
+    };
+    ($i18n:expr, $key:expr, $($name:ident $(= $value:expr)?),+ $(,)?) => {{
+        #[allow(non_camel_case_types, dead_code)]
+        enum __I18nArgs { $($name),+ }
+        $i18n.t_with(
+            $key,
+            &[$((
+                ::core::stringify!($name),
+                &$crate::t!(@value $name $(= $value)?) as &dyn ::core::fmt::Display,
+            )),+],
+        )
+    }};
+    (@value $name:ident = $value:expr) => {
+        $value
+    };
+    (@value $name:ident) => {
+        $name
+    };
+}
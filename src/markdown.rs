@@ -0,0 +1,84 @@
+//! Rendering of translation values written in Markdown.
+//!
+//! Long-form localized content such as help panels is easier to translate as a single
+//! Markdown value than as many small keys. Raw HTML inside the Markdown is escaped and
+//! links with unsafe schemes (e.g. `javascript:`) are neutralized, so the output can be
+//! inserted into the page as-is.
+
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, html};
+
+/// URL schemes allowed in links and images.
+const SAFE_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// Renders Markdown into sanitized HTML.
+///
+/// Supports the CommonMark syntax plus strikethrough and tables.
+///
+/// # Arguments
+/// - `markdown`: The Markdown source, usually a translation value.
+///
+/// # Returns
+/// - The rendered HTML, with raw HTML escaped and unsafe link URLs removed.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::markdown::to_html;
+///
+/// let html = to_html("Read the **[docs](https://docs.rs)** <script>");
+/// assert_eq!(
+///     html,
+///     "<p>Read the <strong><a href=\"https://docs.rs\">docs</a></strong> &lt;script&gt;</p>\n"
+/// );
+/// ```
+pub fn to_html(markdown: &str) -> String {
+    let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES;
+    let events = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        event => event,
+    });
+
+    let mut out = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut out, events);
+    out
+}
+
+/// Keeps relative URLs and URLs with a safe scheme, replacing any other URL with `""`.
+fn safe_url(url: CowStr<'_>) -> CowStr<'_> {
+    let scheme = url
+        .split_once(':')
+        .map(|(scheme, _)| scheme)
+        .filter(|scheme| !scheme.contains(['/', '?', '#']));
+
+    match scheme {
+        Some(scheme)
+            if !SAFE_SCHEMES
+                .iter()
+                .any(|safe| scheme.trim().eq_ignore_ascii_case(safe)) =>
+        {
+            CowStr::Borrowed("")
+        }
+        _ => url,
+    }
+}
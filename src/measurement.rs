@@ -0,0 +1,139 @@
+//! Measurement system preferences of a locale.
+//!
+//! Tells whether a locale customarily uses metric or US/UK units and which temperature
+//! scale it expects, so apps can pick sensible defaults and offer conversion. The data
+//! follows the CLDR supplemental measurement and unit preference tables, and the `-u-ms-`
+//! and `-u-mu-` locale extensions override it (e.g., `"en-US-u-ms-metric"`). Requires the
+//! `measurement` feature.
+
+use crate::cldr::{locale, region};
+
+/// Regions that use US customary units.
+const US_CUSTOMARY_REGIONS: &[&str] = &["LR", "US"];
+
+/// Regions that use imperial units alongside metric ones.
+const IMPERIAL_REGIONS: &[&str] = &["GB"];
+
+/// Regions that report temperatures in Fahrenheit.
+const FAHRENHEIT_REGIONS: &[&str] = &["BS", "BZ", "KY", "PR", "PW", "US"];
+
+/// A system of measurement units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MeasurementSystem {
+    /// The International System of Units.
+    #[default]
+    Metric,
+    /// US customary units (miles, pounds, gallons).
+    UsCustomary,
+    /// The UK mix of metric and imperial units (miles and pints alongside kilograms).
+    Imperial,
+}
+
+/// A temperature scale.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TemperatureScale {
+    /// Degrees Celsius.
+    #[default]
+    Celsius,
+    /// Degrees Fahrenheit.
+    Fahrenheit,
+}
+
+impl TemperatureScale {
+    /// Converts a temperature in degrees Celsius to this scale.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::measurement::TemperatureScale;
+    ///
+    /// assert_eq!(TemperatureScale::Fahrenheit.from_celsius(100.0), 212.0);
+    /// assert_eq!(TemperatureScale::Celsius.from_celsius(100.0), 100.0);
+    /// ```
+    pub fn from_celsius(self, celsius: f64) -> f64 {
+        match self {
+            TemperatureScale::Celsius => celsius,
+            TemperatureScale::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    /// Converts a temperature in this scale to degrees Celsius.
+    pub fn to_celsius(self, value: f64) -> f64 {
+        match self {
+            TemperatureScale::Celsius => value,
+            TemperatureScale::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+        }
+    }
+}
+
+/// The measurement conventions of a locale.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MeasurementPreferences {
+    /// The preferred system of units.
+    pub system: MeasurementSystem,
+    /// The preferred temperature scale.
+    pub temperature: TemperatureScale,
+}
+
+/// Resolves the measurement conventions of a language.
+///
+/// A language without a region uses the region it is most likely spoken in, so `"en"`
+/// prefers US customary units while `"en-AU"` and `"fr"` prefer metric ones.
+///
+/// # Arguments
+/// - `language`: The language code (e.g., `"en-GB"`).
+///
+/// # Returns
+/// - `Ok(MeasurementPreferences)` with the preferred system and temperature scale.
+/// - `Err(String)` if the language code is not a valid locale.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::measurement::{MeasurementSystem, TemperatureScale, measurement_preferences};
+///
+/// let us = measurement_preferences("en").unwrap();
+/// assert_eq!(us.system, MeasurementSystem::UsCustomary);
+/// assert_eq!(us.temperature, TemperatureScale::Fahrenheit);
+///
+/// let uk = measurement_preferences("en-GB").unwrap();
+/// assert_eq!(uk.system, MeasurementSystem::Imperial);
+/// assert_eq!(uk.temperature, TemperatureScale::Celsius);
+///
+/// let de = measurement_preferences("de").unwrap();
+/// assert_eq!(de.system, MeasurementSystem::Metric);
+///
+/// let metric_us = measurement_preferences("en-US-u-ms-metric-mu-celsius").unwrap();
+/// assert_eq!(metric_us.system, MeasurementSystem::Metric);
+/// assert_eq!(metric_us.temperature, TemperatureScale::Celsius);
+/// ```
+pub fn measurement_preferences(language: &str) -> Result<MeasurementPreferences, String> {
+    let locale = locale(language)?;
+    let region = region(&locale).unwrap_or_default();
+    let keyword = |name: &str| {
+        locale
+            .extensions
+            .unicode
+            .keywords
+            .get(&name.parse().ok()?)
+            .map(|value| value.to_string())
+    };
+
+    let system = match keyword("ms").as_deref() {
+        Some("metric") => MeasurementSystem::Metric,
+        Some("ussystem") => MeasurementSystem::UsCustomary,
+        Some("uksystem") => MeasurementSystem::Imperial,
+        _ if US_CUSTOMARY_REGIONS.contains(&region.as_str()) => MeasurementSystem::UsCustomary,
+        _ if IMPERIAL_REGIONS.contains(&region.as_str()) => MeasurementSystem::Imperial,
+        _ => MeasurementSystem::Metric,
+    };
+    let temperature = match keyword("mu").as_deref() {
+        Some("celsius") => TemperatureScale::Celsius,
+        Some("fahrenhe") => TemperatureScale::Fahrenheit,
+        _ if FAHRENHEIT_REGIONS.contains(&region.as_str()) => TemperatureScale::Fahrenheit,
+        _ => TemperatureScale::Celsius,
+    };
+
+    Ok(MeasurementPreferences {
+        system,
+        temperature,
+    })
+}
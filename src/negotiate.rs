@@ -0,0 +1,243 @@
+//! Negotiation of the request locale on the server.
+//!
+//! Resolves the language of a request from the persistence cookie written by the providers
+//! and the `Accept-Language` header, against the languages an app has translations for.
+
+/// The default name of the cookie holding the selected language.
+///
+/// Matches the default `storage_name` of the providers.
+pub const DEFAULT_COOKIE_NAME: &str = "i18nrs";
+
+/// Resolves the language of a request.
+///
+/// The cookie wins when it names an available language; otherwise the `Accept-Language`
+/// header is matched with `accept_language`.
+///
+/// # Arguments
+/// - `cookie`: The language stored in the persistence cookie, if any.
+/// - `accept_language`: The raw `Accept-Language` header, if any.
+/// - `available`: The language codes the app has translations for.
+///
+/// # Returns
+/// - `Some(&str)` with the matching available language.
+/// - `None` if neither source matches, so the caller can use its default language.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::negotiate::negotiate;
+///
+/// let available = ["en", "fr", "ar"];
+/// assert_eq!(negotiate(Some("ar"), Some("fr"), &available), Some("ar"));
+/// assert_eq!(negotiate(Some("de"), Some("fr-CH, en;q=0.5"), &available), Some("fr"));
+/// assert_eq!(negotiate(None, None, &available), None);
+/// ```
+pub fn negotiate<'a>(
+    cookie: Option<&str>,
+    accept_language: Option<&str>,
+    available: &[&'a str],
+) -> Option<&'a str> {
+    cookie
+        .and_then(|language| available.iter().find(|code| **code == language))
+        .copied()
+        .or_else(|| accept_language.and_then(|header| self::accept_language(header, available)))
+}
+
+/// Picks the best available language for an `Accept-Language` header.
+///
+/// Entries are tried by decreasing quality, in header order for equal qualities. Each
+/// entry matches an available language with the same code (case-insensitively), then one
+/// with the same primary subtag, so `"fr-CH"` matches `"fr"`. The `*` wildcard matches the
+/// first available language. Languages excluded with `q=0`, including regional variants of
+/// an excluded range (`"en-GB"` under `"en;q=0"`), are never picked.
+///
+/// # Arguments
+/// - `header`: The raw header value (e.g., `"fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5"`).
+/// - `available`: The language codes the app has translations for.
+///
+/// # Returns
+/// - `Some(&str)` with the best matching available language, `None` if nothing matches.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::negotiate::accept_language;
+///
+/// let available = ["en", "pt-BR"];
+/// assert_eq!(accept_language("pt-br;q=0.8, en;q=0.9", &available), Some("en"));
+/// assert_eq!(accept_language("pt", &available), Some("pt-BR"));
+/// assert_eq!(accept_language("de", &available), None);
+/// assert_eq!(accept_language("de, *;q=0.1", &available), Some("en"));
+/// assert_eq!(accept_language("de, en;q=0, *;q=0.1", &available), Some("pt-BR"));
+/// ```
+pub fn accept_language<'a>(header: &str, available: &[&'a str]) -> Option<&'a str> {
+    let entries = weighted_entries(header);
+    let excluded = |language: &str| {
+        entries
+            .iter()
+            .any(|(code, quality)| *quality <= 0.0 && language_matches(language, code))
+    };
+
+    entries
+        .iter()
+        .filter(|(_, quality)| *quality > 0.0)
+        .find_map(|(code, _)| {
+            if *code == "*" {
+                return available.iter().find(|language| !excluded(language));
+            }
+            let allowed = || available.iter().filter(|language| !excluded(language));
+            allowed()
+                .find(|language| language.eq_ignore_ascii_case(code))
+                .or_else(|| {
+                    allowed().find(|language| {
+                        primary_subtag(language).eq_ignore_ascii_case(primary_subtag(code))
+                    })
+                })
+        })
+        .copied()
+}
+
+/// Returns the most preferred language of an `Accept-Language` header, for when the
+/// available languages aren't known.
+///
+/// # Returns
+/// - `Some(&str)` with the code of the entry with the highest quality, skipping the `*`
+///   wildcard and entries with `q=0`; `None` if there is none.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::negotiate::preferred_language;
+///
+/// assert_eq!(preferred_language("en;q=0.8, fr-CH, *;q=0.5"), Some("fr-CH"));
+/// assert_eq!(preferred_language("*, de;q=0"), None);
+/// ```
+pub fn preferred_language(header: &str) -> Option<&str> {
+    weighted_entries(header)
+        .into_iter()
+        .find(|(code, quality)| *quality > 0.0 && *code != "*")
+        .map(|(code, _)| code)
+}
+
+/// Parses the entries of an `Accept-Language` header with their quality, by decreasing
+/// quality. Malformed entries, such as a quality that isn't a number between 0 and 1, are
+/// skipped.
+fn weighted_entries(header: &str) -> Vec<(&str, f32)> {
+    let mut entries: Vec<(&str, f32)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';').map(str::trim);
+            let code = parts.next().filter(|code| !code.is_empty())?;
+            let quality = parts
+                .find_map(|param| param.strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.parse::<f32>().ok())
+                .filter(|quality| (0.0..=1.0).contains(quality))?;
+            Some((code, quality))
+        })
+        .collect();
+    entries.sort_by(|a, b| b.1.total_cmp(&a.1));
+    entries
+}
+
+/// Checks whether a language falls under a language range, e.g. `"en-US"` under `"en"`.
+fn language_matches(language: &str, range: &str) -> bool {
+    language.eq_ignore_ascii_case(range)
+        || language
+            .get(..range.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(range))
+            && language[range.len()..].starts_with(['-', '_'])
+}
+
+/// The language resolved for a request, stored in its extensions by `LocaleLayer`.
+///
+/// Server integrations prefer it over negotiating again, so every part of a backend
+/// agrees on the language of a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestLocale(pub String);
+
+/// Returns the primary subtag of a language code (e.g., `"pt"` for `"pt-BR"`).
+fn primary_subtag(code: &str) -> &str {
+    code.split(['-', '_']).next().unwrap_or(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_qualities_are_skipped() {
+        let available = ["en", "fr", "de"];
+        for header in [
+            "fr;q=abc, en;q=0.5",
+            "fr;q=, en;q=0.5",
+            "fr;q=1.5, en;q=0.5",
+            "fr;q=-1, en;q=0.5",
+            "fr;q=NaN, en;q=0.5",
+            ", ;q=0.9, en;q=0.5",
+        ] {
+            assert_eq!(
+                accept_language(header, &available),
+                Some("en"),
+                "{}",
+                header
+            );
+        }
+        assert_eq!(accept_language("", &available), None);
+        assert_eq!(accept_language(";;;", &available), None);
+    }
+
+    #[test]
+    fn parameters_other_than_quality_are_ignored() {
+        let available = ["en", "fr"];
+        assert_eq!(
+            accept_language("fr;level=1;q=0.4, en;q=0.6", &available),
+            Some("en")
+        );
+        assert_eq!(accept_language(" en ; q=0.4 , fr ", &available), Some("fr"));
+    }
+
+    #[test]
+    fn equal_qualities_keep_header_order() {
+        let available = ["en", "fr"];
+        assert_eq!(
+            accept_language("fr;q=0.8, en;q=0.8", &available),
+            Some("fr")
+        );
+        assert_eq!(accept_language("en, fr", &available), Some("en"));
+    }
+
+    #[test]
+    fn zero_quality_excludes_regional_variants() {
+        assert_eq!(accept_language("en;q=0, *", &["en-GB", "de"]), Some("de"));
+        assert_eq!(accept_language("fr-CH, fr;q=0", &["fr"]), None);
+        assert_eq!(accept_language("en-US;q=0, en", &["en-US"]), None);
+        assert_eq!(
+            accept_language("en-US;q=0, en", &["en-US", "en-GB"]),
+            Some("en-GB")
+        );
+        assert_eq!(
+            accept_language("en-US;q=0, *", &["en", "en-US"]),
+            Some("en")
+        );
+        assert_eq!(accept_language("*;q=0", &["en"]), None);
+    }
+
+    #[test]
+    fn ranges_match_whole_subtags() {
+        assert!(language_matches("en-US", "en"));
+        assert!(language_matches("EN_us", "en"));
+        assert!(!language_matches("eng", "en"));
+        assert!(!language_matches("en", "en-US"));
+    }
+
+    #[test]
+    fn cookie_must_be_available() {
+        let available = ["en", "fr"];
+        assert_eq!(negotiate(Some("xx"), Some("fr"), &available), Some("fr"));
+        assert_eq!(negotiate(Some("xx"), None, &available), None);
+        assert_eq!(negotiate(Some("FR"), None, &available), None);
+    }
+
+    #[test]
+    fn preferred_language_skips_wildcards_and_exclusions() {
+        assert_eq!(preferred_language("*;q=0.9, de;q=0, it;q=0.1"), Some("it"));
+        assert_eq!(preferred_language("de;q=oops"), None);
+    }
+}
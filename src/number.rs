@@ -0,0 +1,61 @@
+//! Locale-aware number formatting.
+//!
+//! Formats numbers with the CLDR data compiled into ICU4X, so digits, separators and the
+//! words of compact notation follow the conventions of the current language. Requires the
+//! `number` feature.
+
+use crate::cldr::locale;
+use fixed_decimal::{Decimal, FloatPrecision};
+use icu_decimal::CompactDecimalFormatter;
+
+/// The notation of a compact number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CompactStyle {
+    /// An abbreviated suffix (e.g., `1.2K`, `3,4 Mio.`).
+    #[default]
+    Short,
+    /// The spelled-out magnitude (e.g., `1.2 thousand`, `3,4 Millionen`).
+    Long,
+}
+
+/// Formats a number in compact notation for a language.
+///
+/// The value is rounded to the precision customary for the locale, and the magnitudes that
+/// get a suffix are locale-dependent too (e.g., Japanese groups by ten thousand).
+///
+/// # Arguments
+/// - `language`: The language code to format for (e.g., `"de"`).
+/// - `value`: The number to format.
+/// - `style`: Whether to abbreviate or spell out the magnitude.
+///
+/// # Returns
+/// - `Ok(String)` with the formatted number.
+/// - `Err(String)` if the language code is not a valid locale or the value is not finite.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::number::{CompactStyle, format_compact};
+///
+/// assert_eq!(format_compact("en", 1234.0, CompactStyle::Short).unwrap(), "1.2K");
+/// assert_eq!(format_compact("en", 1234.0, CompactStyle::Long).unwrap(), "1.2 thousand");
+/// assert_eq!(
+///     format_compact("de", 3_400_000.0, CompactStyle::Short).unwrap(),
+///     "3,4\u{a0}Mio."
+/// );
+/// ```
+pub fn format_compact(language: &str, value: f64, style: CompactStyle) -> Result<String, String> {
+    let locale = locale(language)?;
+    let value = Decimal::try_from_f64(value, FloatPrecision::RoundTrip)
+        .map_err(|_| format!("Cannot format '{}' as a number", value))?;
+
+    let formatter = match style {
+        CompactStyle::Short => {
+            CompactDecimalFormatter::try_new_short(locale.into(), Default::default())
+        }
+        CompactStyle::Long => {
+            CompactDecimalFormatter::try_new_long(locale.into(), Default::default())
+        }
+    }
+    .map_err(|err| format!("No number formats for '{}': {}", language, err))?;
+    Ok(formatter.format(&value).to_string())
+}
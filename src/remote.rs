@@ -0,0 +1,374 @@
+//! Fetching of remote translation bundles.
+//!
+//! Lets the providers load translations from URLs instead of embedding every locale in the
+//! binary. In the browser, bundles are fetched with `fetch` and cached in `localStorage`.
+//! On native targets, such as desktop apps or server-side rendering, they are fetched with
+//! `reqwest` behind the `reqwest` feature, from within a Tokio runtime; without it, every
+//! request fails and the providers fall back to the embedded translations.
+
+#[cfg(any(target_arch = "wasm32", feature = "reqwest"))]
+use crate::compression::{Compression, decompress};
+#[cfg(target_arch = "wasm32")]
+use serde_json::{Value, json};
+#[cfg(target_arch = "wasm32")]
+use web_sys::{Headers, Request, RequestInit, Response, wasm_bindgen::JsCast, window};
+
+/// Prefix of the `localStorage` keys holding cached remote bundles.
+pub const CACHE_PREFIX: &str = "i18nrs:cache:";
+
+/// Fetches a remote translation bundle and returns its raw content.
+///
+/// On `wasm32` targets this uses the browser's `fetch` API, and `reqwest` on other targets
+/// with the `reqwest` feature. Without it, native targets have no HTTP client available
+/// and always return an error, which makes the providers fall back to the embedded
+/// translations.
+///
+/// # Arguments
+/// - `url`: The URL of the translation JSON (e.g., `"/i18n/fr.json"`).
+///
+/// # Returns
+/// - `Ok(String)` containing the response body.
+/// - `Err(String)` if the request fails or the server responds with a non-success status.
+pub async fn fetch_text(url: &str) -> Result<String, String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let window = window().ok_or("No window available")?;
+        let response = wasm_bindgen_futures::JsFuture::from(window.fetch_with_str(url))
+            .await
+            .map_err(|_| format!("Failed to fetch translations from '{}'", url))?
+            .dyn_into::<Response>()
+            .map_err(|_| format!("Invalid response for '{}'", url))?;
+
+        if !response.ok() {
+            return Err(format!(
+                "Failed to fetch translations from '{}': HTTP {}",
+                url,
+                response.status()
+            ));
+        }
+
+        read_body(response, url).await
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "reqwest"))]
+    {
+        let response = reqwest::get(url)
+            .await
+            .map_err(|err| format!("Failed to fetch translations from '{}': {}", url, err))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Failed to fetch translations from '{}': HTTP {}",
+                url,
+                response.status().as_u16()
+            ));
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|err| format!("Failed to read response body from '{}': {}", url, err))?;
+        if Compression::from_path(url).is_some() {
+            return decompress(&body);
+        }
+        String::from_utf8(body.to_vec())
+            .map_err(|_| format!("Response body from '{}' is not text", url))
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), not(feature = "reqwest")))]
+    {
+        Err(format!(
+            "Remote translations from '{}' can only be fetched on wasm32 targets or with the \
+             `reqwest` feature",
+            url
+        ))
+    }
+}
+
+/// Reads the locally cached body of a remote translation bundle.
+///
+/// Used to serve a previously fetched bundle immediately while `fetch_cached` revalidates it
+/// in the background. Always returns `None` on targets other than `wasm32`.
+///
+/// # Arguments
+/// - `url`: The URL the bundle was fetched from.
+pub fn cached_bundle(_url: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        window()
+            .and_then(|win| win.local_storage().ok().flatten())
+            .and_then(|storage| {
+                storage
+                    .get_item(&format!("{}{}", CACHE_PREFIX, _url))
+                    .ok()
+                    .flatten()
+            })
+            .and_then(|entry| serde_json::from_str::<Value>(&entry).ok())
+            .and_then(|entry| {
+                entry
+                    .get("body")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Fetches a remote translation bundle, revalidating a locally cached copy.
+///
+/// The response body is stored in `localStorage` together with its `ETag` and
+/// `Last-Modified` headers. Subsequent calls send `If-None-Match`/`If-Modified-Since`, and a
+/// `304 Not Modified` response is served from the cached body, so repeat visits don't
+/// re-download unchanged locale data. If the request fails entirely, the cached body is used
+/// when available.
+///
+/// On targets other than `wasm32` nothing is cached and this behaves like `fetch_text`.
+///
+/// # Arguments
+/// - `url`: The URL of the translation JSON (e.g., `"/i18n/fr.json"`).
+///
+/// # Returns
+/// - `Ok(String)` containing the fresh or cached response body.
+/// - `Err(String)` if the request fails and nothing is cached.
+pub async fn fetch_cached(url: &str) -> Result<String, String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let storage = window().and_then(|win| win.local_storage().ok().flatten());
+        let cache_key = format!("{}{}", CACHE_PREFIX, url);
+        let cached: Option<Value> = storage
+            .as_ref()
+            .and_then(|storage| storage.get_item(&cache_key).ok().flatten())
+            .and_then(|entry| serde_json::from_str(&entry).ok());
+        let cached_field = |field: &str| {
+            cached
+                .as_ref()
+                .and_then(|entry| entry.get(field))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+        let cached_body = cached_field("body");
+
+        let headers = Headers::new().map_err(|_| "Failed to create request headers")?;
+        if cached_body.is_some() {
+            if let Some(etag) = cached_field("etag") {
+                let _ = headers.set("If-None-Match", &etag);
+            }
+            if let Some(last_modified) = cached_field("last_modified") {
+                let _ = headers.set("If-Modified-Since", &last_modified);
+            }
+        }
+
+        let init = RequestInit::new();
+        init.set_method("GET");
+        init.set_headers(&headers);
+        let request = Request::new_with_str_and_init(url, &init)
+            .map_err(|_| format!("Invalid request for '{}'", url))?;
+
+        let window = window().ok_or("No window available")?;
+        let response =
+            match wasm_bindgen_futures::JsFuture::from(window.fetch_with_request(&request))
+                .await
+                .ok()
+                .and_then(|response| response.dyn_into::<Response>().ok())
+            {
+                Some(response) => response,
+                None => {
+                    return cached_body
+                        .ok_or_else(|| format!("Failed to fetch translations from '{}'", url));
+                }
+            };
+
+        if response.status() == 304
+            && let Some(body) = cached_body
+        {
+            return Ok(body);
+        }
+
+        if !response.ok() {
+            return cached_body.ok_or_else(|| {
+                format!(
+                    "Failed to fetch translations from '{}': HTTP {}",
+                    url,
+                    response.status()
+                )
+            });
+        }
+
+        let etag = response.headers().get("ETag").ok().flatten();
+        let last_modified = response.headers().get("Last-Modified").ok().flatten();
+        let body = read_body(response, url).await?;
+
+        if let Some(storage) = storage
+            && (etag.is_some() || last_modified.is_some())
+        {
+            let entry = json!({
+                "etag": etag,
+                "last_modified": last_modified,
+                "body": body,
+            });
+            let _ = storage.set_item(&cache_key, &entry.to_string());
+        }
+
+        Ok(body)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        fetch_text(url).await
+    }
+}
+
+/// Sends a JSON body to an endpoint with a `POST` request.
+///
+/// Used for reporting, e.g. by `MissingKeyReporter`. Like `fetch_text`, this uses the
+/// browser's `fetch` API, or `reqwest` on other targets with the `reqwest` feature, and
+/// always returns an error on native targets without it.
+///
+/// # Arguments
+/// - `url`: The endpoint to post to.
+/// - `body`: The JSON payload.
+///
+/// # Returns
+/// - `Ok(())` if the server responded with a success status.
+/// - `Err(String)` if the request fails or the server responds with an error status.
+pub async fn post_json(url: &str, _body: &str) -> Result<(), String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let headers = Headers::new().map_err(|_| "Failed to create request headers")?;
+        let _ = headers.set("Content-Type", "application/json");
+
+        let init = RequestInit::new();
+        init.set_method("POST");
+        init.set_headers(&headers);
+        init.set_body(&web_sys::wasm_bindgen::JsValue::from_str(_body));
+        let request = Request::new_with_str_and_init(url, &init)
+            .map_err(|_| format!("Invalid request for '{}'", url))?;
+
+        let window = window().ok_or("No window available")?;
+        let response = wasm_bindgen_futures::JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .map_err(|_| format!("Failed to post to '{}'", url))?
+            .dyn_into::<Response>()
+            .map_err(|_| format!("Invalid response for '{}'", url))?;
+
+        if !response.ok() {
+            return Err(format!(
+                "Failed to post to '{}': HTTP {}",
+                url,
+                response.status()
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "reqwest"))]
+    {
+        let response = reqwest::Client::new()
+            .post(url)
+            .header("Content-Type", "application/json")
+            .body(_body.to_string())
+            .send()
+            .await
+            .map_err(|err| format!("Failed to post to '{}': {}", url, err))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Failed to post to '{}': HTTP {}",
+                url,
+                response.status().as_u16()
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), not(feature = "reqwest")))]
+    {
+        Err(format!(
+            "Posting to '{}' is only supported on wasm32 targets or with the `reqwest` feature",
+            url
+        ))
+    }
+}
+
+/// Reads a response body as text, decompressing `.gz`/`.br` payloads.
+#[cfg(target_arch = "wasm32")]
+async fn read_body(response: Response, url: &str) -> Result<String, String> {
+    let read_error = || format!("Failed to read response body from '{}'", url);
+
+    if Compression::from_path(url).is_some() {
+        let buffer = response.array_buffer().map_err(|_| read_error())?;
+        let buffer = wasm_bindgen_futures::JsFuture::from(buffer)
+            .await
+            .map_err(|_| read_error())?;
+        return decompress(&web_sys::js_sys::Uint8Array::new(&buffer).to_vec());
+    }
+
+    let text = response.text().map_err(|_| read_error())?;
+    wasm_bindgen_futures::JsFuture::from(text)
+        .await
+        .map_err(|_| read_error())?
+        .as_string()
+        .ok_or_else(|| format!("Response body from '{}' is not text", url))
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "reqwest"))]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves one response on a local port and returns the URL of `path` on it.
+    fn serve(path: &str, status: &str, body: &'static [u8]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}{}", listener.local_addr().unwrap(), path);
+        let status = status.to_string();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request);
+            let head = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                body.len()
+            );
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(body).unwrap();
+        });
+        url
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn fetches_bundles_natively() {
+        let url = serve("/fr.json", "200 OK", br#"{"greeting": "Bonjour"}"#);
+        assert_eq!(
+            block_on(fetch_cached(&url)).unwrap(),
+            r#"{"greeting": "Bonjour"}"#
+        );
+    }
+
+    #[test]
+    fn reports_error_statuses() {
+        let url = serve("/de.json", "404 Not Found", b"");
+        assert_eq!(
+            block_on(fetch_text(&url)).unwrap_err(),
+            format!("Failed to fetch translations from '{}': HTTP 404", url)
+        );
+        let url = serve("/missing", "500 Internal Server Error", b"");
+        assert!(block_on(post_json(&url, "[]")).is_err());
+    }
+}
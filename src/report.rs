@@ -0,0 +1,272 @@
+//! Reporting of missing translation keys to a remote endpoint.
+//!
+//! A `MissingKeyReporter` collects the keys that were missing for the current language,
+//! together with the route they were requested on, and `POST`s them in batches, so misses
+//! seen in production feed directly into the translation backlog.
+
+use crate::config::MissingKeyHandler;
+use serde_json::json;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// The default number of events sent per batch.
+pub const DEFAULT_BATCH_SIZE: usize = 20;
+
+/// The number of distinct events remembered before they may be reported again.
+const MAX_SEEN: usize = 10_000;
+
+/// A key that was missing for a language.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MissingKeyEvent {
+    /// The missing key (e.g., `"checkout.title"`).
+    pub key: String,
+    /// The language the key was missing for.
+    pub language: String,
+    /// The path of the page the key was requested on; empty outside the browser.
+    pub route: String,
+}
+
+/// Events waiting to be sent, and every event seen so far.
+#[derive(Default)]
+struct ReporterState {
+    pending: Vec<MissingKeyEvent>,
+    seen: HashSet<MissingKeyEvent>,
+}
+
+/// Batches missing-key events and posts them to an endpoint.
+///
+/// Each distinct `(key, language, route)` is reported once per session. A batch is sent
+/// when `batch_size` events are pending, or when `flush` is called. The endpoint receives
+/// a JSON array of `{"key", "language", "route"}` objects. Sending requires the browser's
+/// `fetch` API, so batches are only posted on `wasm32` targets; elsewhere they are dropped
+/// when full, so long-running servers and native apps don't accumulate events.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::report::MissingKeyReporter;
+/// use i18nrs::{I18n, I18nConfig};
+/// use std::collections::HashMap;
+///
+/// let translations = HashMap::from([("en", r#"{"greeting": "Hello!"}"#)]);
+/// let mut i18n = I18n::new(
+///     I18nConfig { translations: translations.clone() },
+///     translations,
+/// )
+/// .unwrap();
+///
+/// let reporter = MissingKeyReporter::new("/api/i18n/missing").with_batch_size(50);
+/// i18n.set_missing_key_handler(Some(reporter.handler()));
+///
+/// i18n.t("farewell");
+/// assert_eq!(reporter.pending()[0].key, "farewell");
+/// ```
+#[derive(Clone)]
+pub struct MissingKeyReporter {
+    /// The endpoint batches are posted to.
+    endpoint: String,
+    /// The number of pending events that triggers a send.
+    batch_size: usize,
+    /// Shared state, so clones of the reporter feed the same batch.
+    state: Arc<Mutex<ReporterState>>,
+}
+
+impl MissingKeyReporter {
+    /// Creates a reporter posting to the given endpoint.
+    ///
+    /// # Arguments
+    /// - `endpoint`: The URL batches are posted to (e.g., `"/api/i18n/missing"`).
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            state: Arc::default(),
+        }
+    }
+
+    /// Sets the number of pending events that triggers a send.
+    ///
+    /// # Arguments
+    /// - `batch_size`: The batch size; `0` is treated as `1`.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Retrieves the endpoint batches are posted to.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Records a missing key for the current route, sending the batch once it is full.
+    ///
+    /// # Arguments
+    /// - `key`: The missing key.
+    /// - `language`: The language the key was missing for.
+    pub fn record(&self, key: &str, language: &str) {
+        let event = MissingKeyEvent {
+            key: key.to_string(),
+            language: language.to_string(),
+            route: current_route(),
+        };
+
+        let full = {
+            let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+            if state.seen.len() >= MAX_SEEN && !state.seen.contains(&event) {
+                state.seen.clear();
+            }
+            if !state.seen.insert(event.clone()) {
+                return;
+            }
+            state.pending.push(event);
+            state.pending.len() >= self.batch_size
+        };
+
+        if full {
+            self.flush();
+        }
+    }
+
+    /// Retrieves the events that have not been sent yet.
+    pub fn pending(&self) -> Vec<MissingKeyEvent> {
+        self.state
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .pending
+            .clone()
+    }
+
+    /// Sends every pending event now, e.g. before the page is unloaded.
+    ///
+    /// Does nothing if no events are pending. On targets other than `wasm32`, where nothing
+    /// can be sent, the pending events are dropped.
+    pub fn flush(&self) {
+        let events = std::mem::take(
+            &mut self
+                .state
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .pending,
+        );
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            if events.is_empty() {
+                return;
+            }
+
+            let endpoint = self.endpoint.clone();
+            let body = Self::payload(&events);
+            wasm_bindgen_futures::spawn_local(async move {
+                let _ = crate::remote::post_json(&endpoint, &body).await;
+            });
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        drop(events);
+    }
+
+    /// Creates a handler to install with `I18n::set_missing_key_handler`.
+    pub fn handler(&self) -> MissingKeyHandler {
+        let reporter = self.clone();
+        MissingKeyHandler::new(move |key, language| reporter.record(key, language))
+    }
+
+    /// Serializes events into the JSON payload posted to the endpoint.
+    pub fn payload(events: &[MissingKeyEvent]) -> String {
+        let events: Vec<_> = events
+            .iter()
+            .map(|event| {
+                json!({
+                    "key": event.key,
+                    "language": event.language,
+                    "route": event.route,
+                })
+            })
+            .collect();
+        serde_json::Value::Array(events).to_string()
+    }
+}
+
+impl PartialEq for MissingKeyReporter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl fmt::Debug for MissingKeyReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MissingKeyReporter")
+            .field("endpoint", &self.endpoint)
+            .field("batch_size", &self.batch_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the path of the current page, or an empty string outside the browser.
+fn current_route() -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|win| win.location().pathname().ok())
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_are_reported_once() {
+        let reporter = MissingKeyReporter::new("/missing").with_batch_size(10);
+        reporter.record("a", "en");
+        reporter.record("a", "en");
+        reporter.record("a", "fr");
+        assert_eq!(reporter.pending().len(), 2);
+    }
+
+    #[test]
+    fn full_batches_are_dropped_off_wasm() {
+        let reporter = MissingKeyReporter::new("/missing").with_batch_size(0);
+        reporter.record("a", "en");
+        assert!(reporter.pending().is_empty());
+
+        let reporter = MissingKeyReporter::new("/missing").with_batch_size(3);
+        for key in ["a", "b", "c", "d"] {
+            reporter.record(key, "en");
+        }
+        assert_eq!(reporter.pending().len(), 1);
+        assert_eq!(reporter.pending()[0].key, "d");
+    }
+
+    #[test]
+    fn seen_events_are_bounded() {
+        let reporter = MissingKeyReporter::new("/missing");
+        for n in 0..=MAX_SEEN {
+            reporter.record(&n.to_string(), "en");
+        }
+        let state = reporter.state.lock().unwrap();
+        assert!(state.seen.len() <= MAX_SEEN);
+        assert!(state.pending.len() < DEFAULT_BATCH_SIZE);
+    }
+
+    #[test]
+    fn payload_is_a_json_array() {
+        let event = MissingKeyEvent {
+            key: "a".to_string(),
+            language: "en".to_string(),
+            route: "/\"".to_string(),
+        };
+        assert_eq!(
+            MissingKeyReporter::payload(&[event]),
+            r#"[{"key":"a","language":"en","route":"/\""}]"#
+        );
+        assert_eq!(MissingKeyReporter::payload(&[]), "[]");
+    }
+}
@@ -0,0 +1,27 @@
+//! Conversion from `rust_decimal::Decimal` to the input of the currency formatter.
+//!
+//! Lets financial amounts be formatted by `format_currency` without going through `f64`,
+//! so no digit is lost. Requires the `rust_decimal` feature.
+//!
+//! # Example
+//! ```rust
+//! use i18nrs::currency::{CurrencyStyle, format_currency};
+//! use rust_decimal::Decimal;
+//!
+//! let amount: Decimal = "12345678901234567.89".parse().unwrap();
+//! assert_eq!(
+//!     format_currency("en", &amount, "USD", CurrencyStyle::Symbol).unwrap(),
+//!     "$12,345,678,901,234,567.89"
+//! );
+//! ```
+
+use crate::currency::ToDecimal;
+use fixed_decimal::Decimal;
+
+impl ToDecimal for ::rust_decimal::Decimal {
+    fn to_fixed_decimal(&self) -> Result<Decimal, String> {
+        self.to_string()
+            .parse()
+            .map_err(|err| format!("Cannot format '{}' as a number: {}", self, err))
+    }
+}
@@ -0,0 +1,299 @@
+//! Structural validation of translation files.
+//!
+//! Validates locale bundles against a JSON Schema, either written by hand or generated
+//! from the base locale with `from_locale`, so structural drift such as a string turned
+//! into an object or a key nested at the wrong level is reported with its path instead of
+//! surfacing as missing translations. Used by `I18n::with_schema`.
+//!
+//! The structural subset of JSON Schema is supported: `type` (a name or a list of names),
+//! `properties`, `required`, `additionalProperties` (a boolean or a schema), `items` and
+//! `enum`. Other keywords are ignored.
+
+use serde_json::{Map, Value};
+use std::fmt::{self, Display};
+
+/// A violation of a schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaError {
+    /// The JSON Pointer of the offending value (e.g., `"/nav/home"`), empty for the root.
+    pub path: String,
+    /// What is wrong with the value.
+    pub message: String,
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        write!(f, "{}: {}", path, self.message)
+    }
+}
+
+/// The CLDR plural categories, which languages use different subsets of.
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Generates a schema from a locale, usually the base language.
+///
+/// Every string of the locale must stay a string and every object an object, and no key
+/// may be added; keys may be missing, as they fall back to the default language. Plural
+/// objects, whose keys are CLDR plural categories including `other`, accept every
+/// category, since e.g. Arabic needs `few` and `many` where English only has `one`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::schema::{from_locale, validate};
+/// use serde_json::json;
+///
+/// let schema = from_locale(&json!({"nav": {"home": "Home"}, "title": "Shop"}));
+/// assert!(validate(&schema, &json!({"nav": {"home": "Accueil"}})).is_empty());
+///
+/// let errors = validate(&schema, &json!({"nav": {"home": {"label": "Accueil"}}, "titel": "Boutique"}));
+/// assert_eq!(errors[0].to_string(), "/nav/home: expected string, found object");
+/// assert_eq!(errors[1].to_string(), "/titel: unexpected key");
+///
+/// let schema = from_locale(&json!({"items": {"one": "{count} item", "other": "{count} items"}}));
+/// let arabic = json!({"items": {"zero": "لا عناصر", "few": "{count} عناصر", "many": "{count} عنصرًا", "other": "{count} عنصر"}});
+/// assert!(validate(&schema, &arabic).is_empty());
+/// ```
+pub fn from_locale(locale: &Value) -> Value {
+    match locale {
+        Value::Object(map) => {
+            let mut properties: Map<String, Value> = map
+                .iter()
+                .map(|(key, value)| (key.clone(), from_locale(value)))
+                .collect();
+            if is_plural(map) {
+                for category in PLURAL_CATEGORIES {
+                    properties
+                        .entry(category)
+                        .or_insert_with(|| serde_json::json!({ "type": "string" }));
+                }
+            }
+            serde_json::json!({
+                "type": "object",
+                "properties": properties,
+                "additionalProperties": false,
+            })
+        }
+        other => serde_json::json!({ "type": type_name(other) }),
+    }
+}
+
+/// Checks whether an object holds the plural forms of a message.
+fn is_plural(map: &Map<String, Value>) -> bool {
+    map.contains_key("other")
+        && map
+            .iter()
+            .all(|(key, value)| PLURAL_CATEGORIES.contains(&key.as_str()) && value.is_string())
+}
+
+/// Validates a locale against a schema.
+///
+/// # Arguments
+/// - `schema`: The JSON Schema to validate against.
+/// - `locale`: The parsed locale bundle.
+///
+/// # Returns
+/// - Every violation, depth first with the keys of each object in sorted order; empty if
+///   the locale is valid.
+pub fn validate(schema: &Value, locale: &Value) -> Vec<SchemaError> {
+    let mut errors = Vec::new();
+    validate_at(schema, locale, "", &mut errors);
+    errors
+}
+
+/// Validates a value at a path, collecting violations.
+fn validate_at(schema: &Value, value: &Value, path: &str, errors: &mut Vec<SchemaError>) {
+    let Value::Object(schema) = schema else {
+        if *schema == Value::Bool(false) {
+            push(errors, path, "no value is allowed here".to_string());
+        }
+        return;
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let names: Vec<&str> = match expected {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !names.is_empty() && !names.iter().any(|name| has_type(value, name)) {
+            push(
+                errors,
+                path,
+                format!(
+                    "expected {}, found {}",
+                    names.join(" or "),
+                    type_name(value)
+                ),
+            );
+            return;
+        }
+    }
+
+    if let Some(Value::Array(allowed)) = schema.get("enum")
+        && !allowed.contains(value)
+    {
+        push(errors, path, format!("{} is not an allowed value", value));
+    }
+
+    if let Value::Object(map) = value {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !map.contains_key(key) {
+                    push(
+                        errors,
+                        &child(path, key),
+                        "missing required key".to_string(),
+                    );
+                }
+            }
+        }
+        for (key, child_value) in map {
+            let child_path = child(path, key);
+            match properties.and_then(|properties| properties.get(key)) {
+                Some(child_schema) => validate_at(child_schema, child_value, &child_path, errors),
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        push(errors, &child_path, "unexpected key".to_string())
+                    }
+                    Some(additional) => validate_at(additional, child_value, &child_path, errors),
+                    None => {}
+                },
+            }
+        }
+    }
+
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (index, item) in items.iter().enumerate() {
+            validate_at(item_schema, item, &child(path, &index.to_string()), errors);
+        }
+    }
+}
+
+/// Appends a violation.
+fn push(errors: &mut Vec<SchemaError>, path: &str, message: String) {
+    errors.push(SchemaError {
+        path: path.to_string(),
+        message,
+    });
+}
+
+/// Extends a JSON Pointer with a key, escaping `~` and `/`.
+fn child(path: &str, key: &str) -> String {
+    format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Checks whether a value has a JSON Schema type.
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "integer" => value.as_i64().is_some() || value.as_u64().is_some(),
+        "number" => value.is_number(),
+        other => type_name(value) == other,
+    }
+}
+
+/// The JSON Schema type name of a value.
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn paths(schema: &Value, locale: &Value) -> Vec<String> {
+        validate(schema, locale)
+            .into_iter()
+            .map(|error| error.path)
+            .collect()
+    }
+
+    #[test]
+    fn pointers_escape_tilde_and_slash() {
+        let schema = json!({"type": "object", "additionalProperties": false});
+        let locale = json!({"a/b": "x", "c~d": "y", "~1": "z"});
+        let mut found = paths(&schema, &locale);
+        found.sort();
+        assert_eq!(found, ["/a~1b", "/c~0d", "/~01"]);
+        assert_eq!(
+            validate(&json!({"type": "string"}), &json!(1))[0].to_string(),
+            "/: expected string, found number"
+        );
+    }
+
+    #[test]
+    fn plural_objects_accept_every_category() {
+        let schema = from_locale(&json!({"cart": {"one": "1 item", "other": "{n} items"}}));
+        assert!(
+            paths(
+                &schema,
+                &json!({"cart": {"few": "a", "many": "b", "zero": "c"}})
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            paths(&schema, &json!({"cart": {"several": "a"}})),
+            ["/cart/several"]
+        );
+        assert_eq!(paths(&schema, &json!({"cart": {"few": 3}})), ["/cart/few"]);
+    }
+
+    #[test]
+    fn objects_with_other_keys_stay_closed() {
+        let schema =
+            from_locale(&json!({"menu": {"one": "One", "other": "Other", "more": "More"}}));
+        assert_eq!(
+            paths(&schema, &json!({"menu": {"few": "a"}})),
+            ["/menu/few"]
+        );
+
+        let schema = from_locale(&json!({"size": {"one": "S", "two": "M"}}));
+        assert_eq!(
+            paths(&schema, &json!({"size": {"few": "L"}})),
+            ["/size/few"]
+        );
+    }
+
+    #[test]
+    fn structural_keywords() {
+        let schema = json!({
+            "type": "object",
+            "required": ["title", "tags"],
+            "properties": {
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "level": {"type": ["integer", "null"], "enum": [1, 2, null]},
+            },
+            "additionalProperties": {"type": "string"},
+        });
+        let locale = json!({"tags": ["a", 1], "level": 3, "extra": true});
+        assert_eq!(
+            validate(&schema, &locale)
+                .into_iter()
+                .map(|error| error.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "/title: missing required key",
+                "/extra: expected string, found boolean",
+                "/level: 3 is not an allowed value",
+                "/tags/1: expected string, found number",
+            ]
+        );
+        assert_eq!(paths(&json!({"type": "integer"}), &json!(1.5)), [""]);
+        assert_eq!(paths(&json!(false), &json!("x")), [""]);
+        assert!(paths(&json!(true), &json!("x")).is_empty());
+        assert!(paths(&json!({"type": 5}), &json!("x")).is_empty());
+    }
+}
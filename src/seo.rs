@@ -0,0 +1,329 @@
+//! Localized SEO tags for the document head.
+//!
+//! Builds the `description` and OpenGraph meta tags of a page from translation keys, so
+//! search engines and link previews see the page in its language, and the `hreflang`
+//! links to its translations. The `I18nMeta` and `I18nAlternateLinks` components of the
+//! Yew and Dioxus integrations keep the tags in the document head; server-rendered apps
+//! can also write them out with `to_html`, and generate a sitemap with the same URLs.
+
+use crate::config::I18n;
+use crate::languages::default_region;
+
+/// A `<meta>` tag of the document head.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetaTag {
+    /// The attribute naming the tag: `"name"`, or `"property"` for OpenGraph tags.
+    pub attribute: &'static str,
+    /// The name of the tag (e.g., `"og:locale"`).
+    pub name: &'static str,
+    /// The content of the tag.
+    pub content: String,
+}
+
+impl MetaTag {
+    /// Renders the tag as HTML, escaping its content.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::seo::MetaTag;
+    ///
+    /// let tag = MetaTag {
+    ///     attribute: "name",
+    ///     name: "description",
+    ///     content: r#"Tips & "tricks""#.to_string(),
+    /// };
+    /// assert_eq!(
+    ///     tag.to_html(),
+    ///     r#"<meta name="description" content="Tips &amp; &quot;tricks&quot;">"#
+    /// );
+    /// ```
+    pub fn to_html(&self) -> String {
+        format!(
+            r#"<meta {}="{}" content="{}">"#,
+            self.attribute,
+            self.name,
+            escape_attribute(&self.content)
+        )
+    }
+}
+
+/// Escapes a value for use in a double-quoted HTML attribute.
+pub(crate) fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Returns the OpenGraph locale of a language, in the `language_TERRITORY` form.
+///
+/// The region subtag of the code is used when present, otherwise the country most
+/// associated with the language; see `languages::flag`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::seo::og_locale;
+///
+/// assert_eq!(og_locale("pt-BR"), "pt_BR");
+/// assert_eq!(og_locale("de"), "de_DE");
+/// assert_eq!(og_locale("eo"), "eo");
+/// ```
+pub fn og_locale(language: &str) -> String {
+    let language = language.replace('_', "-");
+    let mut subtags = language.split('-');
+    let primary = subtags.next().unwrap_or_default().to_ascii_lowercase();
+    let region = subtags
+        .find(|subtag| {
+            subtag.len() == 2 || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(str::to_ascii_uppercase)
+        .or_else(|| default_region(&language).map(str::to_string));
+    match region {
+        Some(region) => format!("{}_{}", primary, region),
+        None => primary,
+    }
+}
+
+/// Builds the localized meta tags of a page in the current language.
+///
+/// # Arguments
+/// - `i18n`: The instance to translate with.
+/// - `title_key`: The translation key of the page title, set as `og:title`.
+/// - `description_key`: The translation key of the page description, set as the
+///   `description` and `og:description` tags.
+///
+/// # Returns
+/// - The title and description tags, then `og:locale` for the current language and an
+///   `og:locale:alternate` tag for each other selectable language.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::seo::meta_tags;
+/// use i18nrs::{I18n, I18nConfig, StorageType};
+/// use std::collections::HashMap;
+///
+/// let translations = HashMap::from([
+///     ("en", r#"{"home": {"title": "Home", "description": "Welcome"}}"#),
+///     ("de", r#"{"home": {"title": "Startseite", "description": "Willkommen"}}"#),
+/// ]);
+/// let mut i18n = I18n::new(
+///     I18nConfig { translations: translations.clone() },
+///     translations,
+/// )
+/// .unwrap();
+/// i18n.set_supported_languages(&["de", "en"]).unwrap();
+/// i18n.set_translation_language("de", &StorageType::LocalStorage, "i18nrs").unwrap();
+///
+/// let html: Vec<String> = meta_tags(&i18n, Some("home.title"), Some("home.description"))
+///     .iter()
+///     .map(|tag| tag.to_html())
+///     .collect();
+/// assert_eq!(
+///     html,
+///     [
+///         r#"<meta property="og:title" content="Startseite">"#,
+///         r#"<meta name="description" content="Willkommen">"#,
+///         r#"<meta property="og:description" content="Willkommen">"#,
+///         r#"<meta property="og:locale" content="de_DE">"#,
+///         r#"<meta property="og:locale:alternate" content="en_US">"#,
+///     ]
+/// );
+/// ```
+pub fn meta_tags(
+    i18n: &I18n,
+    title_key: Option<&str>,
+    description_key: Option<&str>,
+) -> Vec<MetaTag> {
+    let tag = |attribute, name, content| MetaTag {
+        attribute,
+        name,
+        content,
+    };
+    let mut tags = Vec::new();
+    if let Some(key) = title_key {
+        tags.push(tag("property", "og:title", i18n.t(key)));
+    }
+    if let Some(key) = description_key {
+        let description = i18n.t(key);
+        tags.push(tag("name", "description", description.clone()));
+        tags.push(tag("property", "og:description", description));
+    }
+
+    let current = i18n.get_current_language();
+    tags.push(tag("property", "og:locale", og_locale(current)));
+    for language in i18n.languages() {
+        if language != current {
+            tags.push(tag("property", "og:locale:alternate", og_locale(language)));
+        }
+    }
+    tags
+}
+
+/// A `<link rel="alternate" hreflang="…">` tag pointing to a translation of the page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlternateLink {
+    /// The language of the linked page (e.g., `"pt-BR"`), or `"x-default"`.
+    pub hreflang: String,
+    /// The URL of the linked page.
+    pub href: String,
+}
+
+impl AlternateLink {
+    /// Renders the link as HTML, escaping its URL.
+    pub fn to_html(&self) -> String {
+        format!(
+            r#"<link rel="alternate" hreflang="{}" href="{}">"#,
+            escape_attribute(&self.hreflang),
+            escape_attribute(&self.href)
+        )
+    }
+}
+
+/// Builds the alternate links of a page for each of its languages.
+///
+/// Search engines use them to show users the page in their language. Each URL is built
+/// from a pattern with a `{lang}` placeholder, so it fits path prefixes
+/// (`"/{lang}/about"`), subdomains (`"https://{lang}.example.com/"`) or query parameters
+/// alike, whatever the router uses for per-locale URLs.
+///
+/// # Arguments
+/// - `languages`: The language codes the page is available in, e.g. `I18n::languages`.
+/// - `pattern`: The URL of the page with a `{lang}` placeholder for the language code;
+///   see `sitemap` to build the URLs of several routes from one pattern.
+/// - `x_default`: The language whose URL also serves as the `x-default` link, for users
+///   matching none of the languages.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::seo::alternate_links;
+///
+/// let links = alternate_links(&["en", "pt_BR"], "https://example.com/{lang}/about", Some("en"));
+/// let html: Vec<String> = links.iter().map(|link| link.to_html()).collect();
+/// assert_eq!(
+///     html,
+///     [
+///         r#"<link rel="alternate" hreflang="en" href="https://example.com/en/about">"#,
+///         r#"<link rel="alternate" hreflang="pt-BR" href="https://example.com/pt_BR/about">"#,
+///         r#"<link rel="alternate" hreflang="x-default" href="https://example.com/en/about">"#,
+///     ]
+/// );
+/// ```
+pub fn alternate_links(
+    languages: &[&str],
+    pattern: &str,
+    x_default: Option<&str>,
+) -> Vec<AlternateLink> {
+    let link = |hreflang: String, language: &str| AlternateLink {
+        hreflang,
+        href: pattern.replace("{lang}", language),
+    };
+    languages
+        .iter()
+        .map(|language| link(language.replace('_', "-"), language))
+        .chain(x_default.map(|language| link("x-default".to_string(), language)))
+        .collect()
+}
+
+/// A `<url>` entry of a sitemap, with `xhtml:link` alternates to its translations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SitemapEntry {
+    /// The URL of the page.
+    pub loc: String,
+    /// The links to every translation of the page, the page itself included.
+    pub alternates: Vec<AlternateLink>,
+}
+
+impl SitemapEntry {
+    /// Renders the entry as a sitemap `<url>` element.
+    pub fn to_xml(&self) -> String {
+        let mut xml = format!("<url><loc>{}</loc>", escape_attribute(&self.loc));
+        for link in &self.alternates {
+            xml.push_str(&format!(
+                r#"<xhtml:link rel="alternate" hreflang="{}" href="{}"/>"#,
+                escape_attribute(&link.hreflang),
+                escape_attribute(&link.href)
+            ));
+        }
+        xml.push_str("</url>");
+        xml
+    }
+}
+
+/// Builds the sitemap entries of every route in every language.
+///
+/// URLs are built like `alternate_links`, from a pattern whose `{path}` placeholder is
+/// replaced by each route, so the sitemap and the `hreflang` links of the pages agree.
+///
+/// # Arguments
+/// - `paths`: The route paths of the site (e.g., `"/about"`).
+/// - `languages`: The language codes each route is available in.
+/// - `pattern`: The URL of a page with `{lang}` and `{path}` placeholders (e.g.,
+///   `"https://example.com/{lang}{path}"`).
+/// - `x_default`: The language whose URLs also serve as the `x-default` alternates.
+///
+/// # Returns
+/// - An entry per route and language, grouped by route.
+pub fn sitemap_entries(
+    paths: &[&str],
+    languages: &[&str],
+    pattern: &str,
+    x_default: Option<&str>,
+) -> Vec<SitemapEntry> {
+    paths
+        .iter()
+        .flat_map(|path| {
+            let pattern = pattern.replace("{path}", path);
+            let alternates = alternate_links(languages, &pattern, x_default);
+            languages.iter().map(move |language| SitemapEntry {
+                loc: pattern.replace("{lang}", language),
+                alternates: alternates.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Renders a sitemap of every route in every language, with `xhtml:link` alternates.
+///
+/// See `sitemap_entries` for the arguments.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::seo::sitemap;
+///
+/// let xml = sitemap(&["/about"], &["en", "de"], "https://example.com/{lang}{path}", None);
+/// assert_eq!(
+///     xml,
+///     concat!(
+///         r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+///         r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" "#,
+///         r#"xmlns:xhtml="http://www.w3.org/1999/xhtml">"#,
+///         r#"<url><loc>https://example.com/en/about</loc>"#,
+///         r#"<xhtml:link rel="alternate" hreflang="en" href="https://example.com/en/about"/>"#,
+///         r#"<xhtml:link rel="alternate" hreflang="de" href="https://example.com/de/about"/>"#,
+///         r#"</url>"#,
+///         r#"<url><loc>https://example.com/de/about</loc>"#,
+///         r#"<xhtml:link rel="alternate" hreflang="en" href="https://example.com/en/about"/>"#,
+///         r#"<xhtml:link rel="alternate" hreflang="de" href="https://example.com/de/about"/>"#,
+///         r#"</url>"#,
+///         r#"</urlset>"#,
+///     )
+/// );
+/// ```
+pub fn sitemap(
+    paths: &[&str],
+    languages: &[&str],
+    pattern: &str,
+    x_default: Option<&str>,
+) -> String {
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" "#,
+        r#"xmlns:xhtml="http://www.w3.org/1999/xhtml">"#,
+    ));
+    for entry in sitemap_entries(paths, languages, pattern, x_default) {
+        xml.push_str(&entry.to_xml());
+    }
+    xml.push_str("</urlset>");
+    xml
+}
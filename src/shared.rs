@@ -0,0 +1,115 @@
+//! Thread-safe i18n for long-lived servers.
+//!
+//! A `SharedI18n` holds the catalog once behind an `Arc`, and hands out cheap per-request
+//! `I18nView`s bound to a language, instead of cloning every translation each time the
+//! language changes.
+
+use crate::config::{I18n, I18nArgs, TextDirection};
+use std::sync::{Arc, RwLock};
+
+/// A catalog shared between threads, e.g. in the state of a web server.
+///
+/// Cloning is cheap and every clone sees the same catalog. Updates replace the catalog
+/// atomically; views created before an update keep reading their snapshot.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::{I18n, I18nConfig, SharedI18n};
+/// use std::collections::HashMap;
+///
+/// let translations = HashMap::from([
+///     ("en", r#"{"greeting": "Hello {name}"}"#),
+///     ("fr", r#"{"greeting": "Bonjour {name}"}"#),
+/// ]);
+/// let i18n = I18n::new(I18nConfig { translations: translations.clone() }, translations).unwrap();
+/// let shared = SharedI18n::new(i18n);
+///
+/// let view = shared.with_locale("fr").unwrap();
+/// assert_eq!(view.t_with("greeting", &[("name", &"Ada")]), "Bonjour Ada");
+/// assert_eq!(shared.with_locale("en").unwrap().t("greeting"), "Hello {name}");
+/// ```
+#[derive(Clone)]
+pub struct SharedI18n {
+    catalog: Arc<RwLock<Arc<I18n>>>,
+}
+
+impl SharedI18n {
+    /// Wraps a loaded catalog for sharing.
+    pub fn new(i18n: I18n) -> Self {
+        Self {
+            catalog: Arc::new(RwLock::new(Arc::new(i18n))),
+        }
+    }
+
+    /// Creates a view translating into a language.
+    ///
+    /// # Arguments
+    /// - `language`: The language code of the view (e.g., `"fr"`).
+    ///
+    /// # Returns
+    /// - `Ok(I18nView)` sharing the current catalog.
+    /// - `Err(String)` if the language is not supported.
+    pub fn with_locale(&self, language: &str) -> Result<I18nView, String> {
+        let catalog = self.snapshot();
+        if !catalog.languages().contains(&language) {
+            return Err(format!("Language '{}' is not supported", language));
+        }
+
+        Ok(I18nView {
+            catalog,
+            language: language.to_string(),
+        })
+    }
+
+    /// Returns the current catalog.
+    pub fn snapshot(&self) -> Arc<I18n> {
+        self.catalog
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// Modifies the catalog, e.g. to add a namespace or reload a language.
+    ///
+    /// The modification is applied to a copy that then replaces the catalog, so concurrent
+    /// readers are never blocked by it and existing views are unaffected.
+    ///
+    /// # Returns
+    /// - The value returned by `update`.
+    pub fn update<R>(&self, update: impl FnOnce(&mut I18n) -> R) -> R {
+        let mut catalog = self.catalog.write().unwrap_or_else(|err| err.into_inner());
+        let mut next = I18n::clone(&catalog);
+        let result = update(&mut next);
+        *catalog = Arc::new(next);
+        result
+    }
+}
+
+/// A cheap, read-only view of a shared catalog in one language.
+#[derive(Clone)]
+pub struct I18nView {
+    catalog: Arc<I18n>,
+    language: String,
+}
+
+impl I18nView {
+    /// Retrieves the language code of the view.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Retrieves the writing direction of the view's language.
+    pub fn direction(&self) -> TextDirection {
+        self.catalog.direction_of(&self.language)
+    }
+
+    /// Translates a key into the view's language; see `I18n::t`.
+    pub fn t(&self, key: &str) -> String {
+        self.catalog.translate(&self.language, key)
+    }
+
+    /// Translates a key and substitutes its `{name}` placeholders; see `I18n::t_with`.
+    pub fn t_with(&self, key: &str, args: I18nArgs<'_>) -> String {
+        self.catalog.translate_with(&self.language, key, args)
+    }
+}
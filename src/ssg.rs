@@ -0,0 +1,74 @@
+//! Static site generation of every locale.
+//!
+//! Renders an app once per language and writes each page to a per-locale path, so
+//! multilingual sites can be fully pre-rendered. The renderer is up to the framework:
+//! pin the `I18nProvider` to the language with its `language` prop and render to a
+//! string, e.g. with `yew::ServerRenderer`, or use `dioxus::render_locales` for Dioxus
+//! apps. Not available on wasm.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Renders a page for each language and writes it under a directory.
+///
+/// # Arguments
+/// - `languages`: The language codes to render, e.g. `I18n::languages`.
+/// - `out_dir`: The directory receiving the pages (e.g., `"dist"`).
+/// - `pattern`: The path of each page relative to `out_dir`, with a `{lang}` placeholder
+///   for the language code (e.g., `"{lang}/index.html"`).
+/// - `render`: Renders the HTML of the page in a language.
+///
+/// # Returns
+/// - `Ok(Vec<PathBuf>)` with the path of each written page, in the order of `languages`.
+/// - `Err(String)` if rendering fails or a page can't be written.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::ssg::render_locales;
+/// use i18nrs::{I18n, I18nConfig, StorageType};
+/// use std::collections::HashMap;
+///
+/// let translations = HashMap::from([
+///     ("en", r#"{"greeting": "Hello"}"#),
+///     ("de", r#"{"greeting": "Hallo"}"#),
+/// ]);
+/// let i18n = I18n::new(
+///     I18nConfig { translations: translations.clone() },
+///     translations,
+/// )
+/// .unwrap();
+///
+/// let out_dir = std::env::temp_dir().join("i18nrs-ssg-doctest");
+/// let paths = render_locales(&["en", "de"], &out_dir, "{lang}/index.html", |language| {
+///     let mut i18n = i18n.clone();
+///     i18n.set_translation_language(language, &StorageType::LocalStorage, "i18nrs")?;
+///     Ok(format!("<h1>{}</h1>", i18n.t("greeting")))
+/// })
+/// .unwrap();
+///
+/// assert_eq!(paths, [out_dir.join("en/index.html"), out_dir.join("de/index.html")]);
+/// assert_eq!(std::fs::read_to_string(&paths[1]).unwrap(), "<h1>Hallo</h1>");
+/// # std::fs::remove_dir_all(&out_dir).unwrap();
+/// ```
+pub fn render_locales(
+    languages: &[&str],
+    out_dir: impl AsRef<Path>,
+    pattern: &str,
+    mut render: impl FnMut(&str) -> Result<String, String>,
+) -> Result<Vec<PathBuf>, String> {
+    let out_dir = out_dir.as_ref();
+    languages
+        .iter()
+        .map(|language| {
+            let html = render(language)?;
+            let path = out_dir.join(pattern.replace("{lang}", language));
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|err| format!("Failed to create '{}': {}", parent.display(), err))?;
+            }
+            fs::write(&path, html)
+                .map_err(|err| format!("Failed to write '{}': {}", path.display(), err))?;
+            Ok(path)
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+//! Cookie persistence for server-side rendering.
+//!
+//! Reads and writes the cookie holding the selected language on the request and response
+//! headers, independently of the web framework, so Yew and Dioxus fullstack apps render
+//! in the language the client persisted. Requires the `yew-ssr` or `dio-ssr` feature.
+
+use crate::cookie::Cookie;
+use crate::negotiate::negotiate;
+use http::header::{ACCEPT_LANGUAGE, COOKIE, SET_COOKIE};
+use http::{HeaderMap, HeaderValue};
+
+/// Reads a cookie from the `Cookie` headers of a request.
+///
+/// # Arguments
+/// - `headers`: The request headers.
+/// - `name`: The cookie name, usually the provider's `storage_name`.
+///
+/// # Returns
+/// - `Some(String)` with the cookie value, `None` if the cookie is absent.
+///
+/// # Example
+/// ```rust
+/// use http::{HeaderMap, HeaderValue, header::COOKIE};
+/// use i18nrs::ssr::get_cookie;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(COOKIE, HeaderValue::from_static("theme=dark; i18nrs=fr"));
+/// assert_eq!(get_cookie(&headers, "i18nrs").as_deref(), Some("fr"));
+/// assert_eq!(get_cookie(&headers, "lang"), None);
+/// ```
+pub fn get_cookie(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|raw| Cookie::get(raw, name))
+        .map(str::to_owned)
+}
+
+/// Appends a `Set-Cookie` header persisting the selected language to a response.
+///
+/// # Arguments
+/// - `headers`: The response headers.
+/// - `name`: The cookie name, usually the provider's `storage_name`.
+/// - `language`: The language code to persist (e.g., `"fr"`).
+///
+/// # Returns
+/// - `Ok(())` if the header was appended.
+/// - `Err(String)` if the name or language can't be used in a header.
+///
+/// # Example
+/// ```rust
+/// use http::{HeaderMap, header::SET_COOKIE};
+/// use i18nrs::ssr::set_cookie;
+///
+/// let mut headers = HeaderMap::new();
+/// set_cookie(&mut headers, "i18nrs", "fr").unwrap();
+/// assert_eq!(
+///     headers[SET_COOKIE],
+///     "i18nrs=fr; Path=/; Max-Age=31536000; SameSite=Lax"
+/// );
+/// assert!(set_cookie(&mut headers, "i18nrs", "fr\n").is_err());
+/// ```
+pub fn set_cookie(headers: &mut HeaderMap, name: &str, language: &str) -> Result<(), String> {
+    let value = HeaderValue::from_str(&Cookie::language(name, language).to_string())
+        .map_err(|err| format!("Invalid cookie for '{}': {}", language, err))?;
+    headers.append(SET_COOKIE, value);
+    Ok(())
+}
+
+/// Resolves the language to render a request in.
+///
+/// The persistence cookie wins when it names an available language; otherwise the
+/// `Accept-Language` header is negotiated, like `negotiate::negotiate`. Render the app
+/// with the provider pinned to the result through its `language` prop, and persist it
+/// with `set_cookie`, so the client hydrates in the same language.
+///
+/// # Arguments
+/// - `headers`: The request headers.
+/// - `name`: The cookie name, usually the provider's `storage_name`.
+/// - `available`: The language codes the app has translations for.
+///
+/// # Returns
+/// - `Some(&str)` with the matching available language.
+/// - `None` if neither source matches, so the caller can use its default language.
+///
+/// # Example
+/// ```rust
+/// use http::{HeaderMap, HeaderValue, header::{ACCEPT_LANGUAGE, COOKIE}};
+/// use i18nrs::ssr::request_language;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("fr-CH, en;q=0.5"));
+/// assert_eq!(request_language(&headers, "i18nrs", &["en", "fr"]), Some("fr"));
+///
+/// headers.insert(COOKIE, HeaderValue::from_static("i18nrs=en"));
+/// assert_eq!(request_language(&headers, "i18nrs", &["en", "fr"]), Some("en"));
+/// ```
+pub fn request_language<'a>(
+    headers: &HeaderMap,
+    name: &str,
+    available: &[&'a str],
+) -> Option<&'a str> {
+    let cookie = get_cookie(headers, name);
+    let accept_language = headers
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok());
+    negotiate(cookie.as_deref(), accept_language, available)
+}
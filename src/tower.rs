@@ -0,0 +1,287 @@
+//! Tower middleware for locale negotiation.
+//!
+//! `LocaleLayer` resolves the language of each request with the rules of
+//! `negotiate::negotiate`, stores it in the request extensions as a `RequestLocale`, and can
+//! persist it in the cookie read by the providers, so Axum handlers, Dioxus fullstack
+//! rendering and the client agree on the language.
+
+use crate::cookie::Cookie;
+use crate::negotiate::{DEFAULT_COOKIE_NAME, RequestLocale, negotiate};
+use http::header::{ACCEPT_LANGUAGE, COOKIE, SET_COOKIE};
+use http::{HeaderValue, Request, Response};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// The negotiation settings shared by a layer and its services.
+#[derive(Clone, Debug)]
+struct LocaleSettings {
+    languages: Vec<String>,
+    default_language: String,
+    cookie_name: String,
+    set_cookie: bool,
+}
+
+/// A `Layer` resolving the locale of each request.
+///
+/// # Example
+/// ```rust
+/// use axum::{Extension, Router, routing::get};
+/// use i18nrs::negotiate::RequestLocale;
+/// use i18nrs::tower::LocaleLayer;
+///
+/// async fn language(Extension(RequestLocale(language)): Extension<RequestLocale>) -> String {
+///     language
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/", get(language))
+///     .layer(LocaleLayer::new(["en", "fr", "ar"]).with_set_cookie(true));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LocaleLayer {
+    settings: Arc<LocaleSettings>,
+}
+
+impl LocaleLayer {
+    /// Creates a layer negotiating against the given languages.
+    ///
+    /// The first language is the default, used when neither the cookie nor
+    /// `Accept-Language` match.
+    ///
+    /// # Arguments
+    /// - `languages`: The language codes the app has translations for.
+    pub fn new(languages: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let languages: Vec<String> = languages.into_iter().map(Into::into).collect();
+        Self {
+            settings: Arc::new(LocaleSettings {
+                default_language: languages.first().cloned().unwrap_or_else(|| "en".into()),
+                languages,
+                cookie_name: DEFAULT_COOKIE_NAME.to_string(),
+                set_cookie: false,
+            }),
+        }
+    }
+
+    /// Sets the language used when negotiation finds no match.
+    pub fn with_default_language(self, language: impl Into<String>) -> Self {
+        self.update(|settings| settings.default_language = language.into())
+    }
+
+    /// Sets the name of the cookie holding the selected language.
+    ///
+    /// Should match the `storage_name` of the providers. Defaults to `"i18nrs"`.
+    pub fn with_cookie_name(self, name: impl Into<String>) -> Self {
+        self.update(|settings| settings.cookie_name = name.into())
+    }
+
+    /// Sets whether responses persist the resolved language in the cookie.
+    ///
+    /// The cookie is only sent when it does not already hold the resolved language.
+    pub fn with_set_cookie(self, set_cookie: bool) -> Self {
+        self.update(|settings| settings.set_cookie = set_cookie)
+    }
+
+    /// Resolves the language of a request from its headers.
+    fn resolve<B>(&self, request: &Request<B>) -> (String, Option<String>) {
+        let header = |name| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|value: &HeaderValue| value.to_str().ok())
+        };
+        // HTTP/2 clients may split cookies over several `Cookie` headers.
+        let cookie = request
+            .headers()
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .find_map(|raw| Cookie::get(raw, &self.settings.cookie_name));
+        let languages: Vec<&str> = self.settings.languages.iter().map(String::as_str).collect();
+
+        let language = negotiate(cookie, header(ACCEPT_LANGUAGE), &languages)
+            .unwrap_or(&self.settings.default_language)
+            .to_string();
+        (language, cookie.map(str::to_owned))
+    }
+
+    /// Returns a layer with modified settings.
+    fn update(mut self, modify: impl FnOnce(&mut LocaleSettings)) -> Self {
+        let settings = Arc::make_mut(&mut self.settings);
+        modify(settings);
+        self
+    }
+}
+
+impl<S> Layer<S> for LocaleLayer {
+    type Service = LocaleService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LocaleService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// The service created by `LocaleLayer`.
+#[derive(Clone, Debug)]
+pub struct LocaleService<S> {
+    inner: S,
+    layer: LocaleLayer,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for LocaleService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let (language, cookie) = self.layer.resolve(&request);
+        let set_cookie = (self.layer.settings.set_cookie
+            && cookie.as_deref() != Some(language.as_str()))
+        .then(|| {
+            let cookie = Cookie::language(&self.layer.settings.cookie_name, &language);
+            HeaderValue::from_str(&cookie.to_string()).ok()
+        })
+        .flatten();
+
+        request.extensions_mut().insert(RequestLocale(language));
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let mut response = response.await?;
+            if let Some(value) = set_cookie {
+                response.headers_mut().append(SET_COOKIE, value);
+            }
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use std::future::{Ready, ready};
+
+    /// Echoes the resolved locale of each request in its response body.
+    struct Echo;
+
+    impl Service<Request<()>> for Echo {
+        type Response = Response<String>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<()>) -> Self::Future {
+            let language = request
+                .extensions()
+                .get::<RequestLocale>()
+                .map(|RequestLocale(language)| language.clone())
+                .unwrap_or_default();
+            ready(Ok(Response::new(language)))
+        }
+    }
+
+    fn call(layer: &LocaleLayer, headers: &[(&str, &str)]) -> Response<String> {
+        let mut request = Request::builder();
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let Ok(response) = runtime.block_on(layer.layer(Echo).call(request.body(()).unwrap()));
+        response
+    }
+
+    #[test]
+    fn cookie_wins_over_accept_language() {
+        let layer = LocaleLayer::new(["en", "fr", "de"]);
+        assert_eq!(
+            call(
+                &layer,
+                &[("cookie", "i18nrs=de"), ("accept-language", "fr")]
+            )
+            .body(),
+            "de"
+        );
+        assert_eq!(
+            call(
+                &layer,
+                &[("cookie", "i18nrs=xx"), ("accept-language", "fr")]
+            )
+            .body(),
+            "fr"
+        );
+
+        let layer = layer.with_cookie_name("lang");
+        assert_eq!(
+            call(
+                &layer,
+                &[("cookie", "i18nrs=de"), ("accept-language", "fr")]
+            )
+            .body(),
+            "fr"
+        );
+    }
+
+    #[test]
+    fn split_cookie_headers_are_read() {
+        let layer = LocaleLayer::new(["en", "fr", "de"]);
+        let response = call(
+            &layer,
+            &[
+                ("cookie", "session=1"),
+                ("cookie", "i18nrs=de"),
+                ("accept-language", "fr"),
+            ],
+        );
+        assert_eq!(response.body(), "de");
+    }
+
+    #[test]
+    fn falls_back_to_the_default_language() {
+        let layer = LocaleLayer::new(["en", "fr"]);
+        assert_eq!(call(&layer, &[("accept-language", "ja")]).body(), "en");
+        assert_eq!(call(&layer, &[]).body(), "en");
+
+        let layer = layer.with_default_language("fr");
+        assert_eq!(call(&layer, &[("accept-language", "ja")]).body(), "fr");
+    }
+
+    #[test]
+    fn set_cookie_is_sent_only_when_it_differs() {
+        let set_cookie = |layer: &LocaleLayer, headers: &[(&str, &str)]| {
+            call(layer, headers)
+                .headers()
+                .get(SET_COOKIE)
+                .map(|value| value.to_str().unwrap().to_string())
+        };
+
+        let layer = LocaleLayer::new(["en", "fr"]);
+        assert_eq!(set_cookie(&layer, &[("accept-language", "fr")]), None);
+
+        let layer = layer.with_set_cookie(true);
+        let cookie = set_cookie(&layer, &[("accept-language", "fr")]).unwrap();
+        assert!(cookie.starts_with("i18nrs=fr"), "{}", cookie);
+        assert_eq!(set_cookie(&layer, &[("cookie", "i18nrs=fr")]), None);
+        let cookie = set_cookie(&layer, &[("cookie", "i18nrs=xx")]).unwrap();
+        assert!(cookie.starts_with("i18nrs=en"), "{}", cookie);
+    }
+}
@@ -0,0 +1,111 @@
+//! Parsing of translated strings that contain lightweight markup.
+//!
+//! Translations such as `"Read the <link>docs</link>"` are split into text and tag nodes so
+//! components can wrap the inner content of each tag with real elements, without splitting
+//! sentences into several keys or injecting raw HTML.
+
+/// A node of a translated string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransNode {
+    /// Plain text, rendered as a text node.
+    Text(String),
+    /// A named tag (e.g., `<link>...</link>` or `<br/>`) and its inner content.
+    Tag {
+        /// The tag name, used to look up the slot that renders it.
+        name: String,
+        /// The nodes between the opening and closing tag.
+        children: Vec<TransNode>,
+    },
+}
+
+/// Parses a translated string into text and tag nodes.
+///
+/// Tag names may contain ASCII letters, digits, `_`, and `-`. Anything that is not a
+/// well-formed tag, such as `a < b` or a stray closing tag, is kept as text, and a tag
+/// left open runs until the end of the string.
+///
+/// # Arguments
+/// - `template`: The translated string (e.g., `"Read the <link>docs</link>"`).
+///
+/// # Example
+/// ```rust
+/// use i18nrs::trans::{TransNode, parse};
+///
+/// let nodes = parse("Read the <link>docs</link>");
+/// assert_eq!(nodes[0], TransNode::Text("Read the ".to_string()));
+/// assert_eq!(
+///     nodes[1],
+///     TransNode::Tag {
+///         name: "link".to_string(),
+///         children: vec![TransNode::Text("docs".to_string())],
+///     }
+/// );
+/// ```
+pub fn parse(template: &str) -> Vec<TransNode> {
+    let mut rest = template;
+    parse_nodes(&mut rest, None)
+}
+
+/// Parses nodes until the closing tag of `parent`, or the end of the input.
+fn parse_nodes(rest: &mut &str, parent: Option<&str>) -> Vec<TransNode> {
+    let mut nodes = Vec::new();
+    let mut text = String::new();
+
+    while let Some(ch) = rest.chars().next() {
+        if let Some(tag) = rest.strip_prefix('<') {
+            if let Some((name, after)) = closing_tag(tag) {
+                if parent == Some(name) {
+                    *rest = after;
+                    break;
+                }
+            } else if let Some((name, self_closing, after)) = opening_tag(tag) {
+                if !text.is_empty() {
+                    nodes.push(TransNode::Text(std::mem::take(&mut text)));
+                }
+                *rest = after;
+                let children = if self_closing {
+                    Vec::new()
+                } else {
+                    parse_nodes(rest, Some(name))
+                };
+                nodes.push(TransNode::Tag {
+                    name: name.to_string(),
+                    children,
+                });
+                continue;
+            }
+        }
+
+        text.push(ch);
+        *rest = &rest[ch.len_utf8()..];
+    }
+
+    if !text.is_empty() {
+        nodes.push(TransNode::Text(text));
+    }
+    nodes
+}
+
+/// Matches `name>` after a `<`, returning the tag name, whether it is self-closing
+/// (`name/>`), and the remaining input.
+fn opening_tag(input: &str) -> Option<(&str, bool, &str)> {
+    let (name, rest) = tag_name(input)?;
+    match rest.strip_prefix("/>") {
+        Some(after) => Some((name, true, after)),
+        None => rest.strip_prefix('>').map(|after| (name, false, after)),
+    }
+}
+
+/// Matches `/name>` after a `<`, returning the tag name and the remaining input.
+fn closing_tag(input: &str) -> Option<(&str, &str)> {
+    let (name, rest) = tag_name(input.strip_prefix('/')?)?;
+    rest.strip_prefix('>').map(|after| (name, after))
+}
+
+/// Splits a non-empty tag name off the start of the input.
+fn tag_name(input: &str) -> Option<(&str, &str)> {
+    let end = input
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+        .unwrap_or(input.len());
+    (end > 0).then(|| input.split_at(end))
+}
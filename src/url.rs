@@ -0,0 +1,172 @@
+//! The language in the page URL.
+//!
+//! Keeps a query parameter such as `?lang=fr`, or a fragment parameter such as
+//! `#lang=fr`, in sync with the current language, so the URL of a page can be shared in
+//! the language it is displayed in. Fragment parameters survive static hosting and
+//! caching layers that strip query strings, as they never reach the server.
+
+#[cfg(target_arch = "wasm32")]
+use web_sys::{wasm_bindgen::JsValue, window};
+
+/// Reads a parameter from `&`-separated `name=value` pairs.
+fn get_param(params: &str, name: &str) -> Option<String> {
+    params
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.to_string())
+}
+
+/// Sets a parameter in `&`-separated `name=value` pairs, keeping the other pairs.
+fn set_param(params: &str, name: &str, value: &str) -> String {
+    let parameter = format!("{}={}", name, value);
+    let mut replaced = false;
+    let mut pairs: Vec<&str> = params
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            if pair.split('=').next() == Some(name) {
+                replaced = true;
+                parameter.as_str()
+            } else {
+                pair
+            }
+        })
+        .collect();
+    if !replaced {
+        pairs.push(&parameter);
+    }
+    pairs.join("&")
+}
+
+/// Splits a URL into its part before the fragment, its query and its fragment.
+fn split_url(url: &str) -> (&str, &str, &str) {
+    let (url, fragment) = url.split_once('#').unwrap_or((url, ""));
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    (path, query, fragment)
+}
+
+/// Reads a query parameter of a URL.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::url::query_param;
+///
+/// assert_eq!(query_param("/about?lang=fr#top", "lang").as_deref(), Some("fr"));
+/// assert_eq!(query_param("/about#lang=fr", "lang"), None);
+/// ```
+pub fn query_param(url: &str, name: &str) -> Option<String> {
+    get_param(split_url(url).1, name)
+}
+
+/// Sets a query parameter of a URL, keeping the other parameters and the fragment.
+///
+/// # Arguments
+/// - `url`: The URL to modify (e.g., `"https://example.com/?page=2#top"`).
+/// - `name`: The name of the parameter (e.g., `"lang"`).
+/// - `value`: The new value of the parameter.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::url::with_query_param;
+///
+/// assert_eq!(
+///     with_query_param("https://example.com/?page=2&lang=en#top", "lang", "fr"),
+///     "https://example.com/?page=2&lang=fr#top"
+/// );
+/// assert_eq!(with_query_param("/about", "lang", "fr"), "/about?lang=fr");
+/// ```
+pub fn with_query_param(url: &str, name: &str, value: &str) -> String {
+    let (url, fragment) = url.split_at(url.find('#').unwrap_or(url.len()));
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    format!("{}?{}{}", path, set_param(query, name, value), fragment)
+}
+
+/// Reads a parameter of the fragment of a URL, written as `#name=value`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::url::hash_param;
+///
+/// assert_eq!(hash_param("/about#lang=fr", "lang").as_deref(), Some("fr"));
+/// assert_eq!(hash_param("/about#top&lang=fr", "lang").as_deref(), Some("fr"));
+/// assert_eq!(hash_param("/about?lang=fr", "lang"), None);
+/// ```
+pub fn hash_param(url: &str, name: &str) -> Option<String> {
+    get_param(split_url(url).2, name)
+}
+
+/// Sets a parameter of the fragment of a URL, keeping the other fragment parts.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::url::with_hash_param;
+///
+/// assert_eq!(with_hash_param("/about?page=2", "lang", "fr"), "/about?page=2#lang=fr");
+/// assert_eq!(with_hash_param("/about#top&lang=en", "lang", "fr"), "/about#top&lang=fr");
+/// ```
+pub fn with_hash_param(url: &str, name: &str, value: &str) -> String {
+    let (url, fragment) = url.split_once('#').unwrap_or((url, ""));
+    format!("{}#{}", url, set_param(fragment, name, value))
+}
+
+/// Reads the current URL of the page, in the browser.
+#[cfg(target_arch = "wasm32")]
+fn current_url() -> Option<String> {
+    window()?.location().href().ok()
+}
+
+/// Replaces the current URL through the History API, without a reload or a new history
+/// entry.
+#[cfg(target_arch = "wasm32")]
+fn replace_url(url: &str) {
+    if let Some(history) = window().and_then(|win| win.history().ok()) {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(url));
+    }
+}
+
+/// Sets a query parameter of the current URL. Does nothing outside the browser.
+#[cfg(any(feature = "yew", feature = "dio", target_arch = "wasm32"))]
+pub(crate) fn write_query_param(_name: &str, _value: &str) {
+    #[cfg(target_arch = "wasm32")]
+    if let Some(url) = current_url() {
+        replace_url(&with_query_param(&url, _name, _value));
+    }
+}
+
+/// Reads a query parameter of the current URL. Returns `None` outside the browser.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn read_query_param(_name: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        current_url().and_then(|url| query_param(&url, _name))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Reads a fragment parameter of the current URL. Returns `None` outside the browser.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn read_hash_param(_name: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        current_url().and_then(|url| hash_param(&url, _name))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Sets a fragment parameter of the current URL. Does nothing outside the browser.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn write_hash_param(_name: &str, _value: &str) {
+    #[cfg(target_arch = "wasm32")]
+    if let Some(url) = current_url() {
+        replace_url(&with_hash_param(&url, _name, _value));
+    }
+}
@@ -0,0 +1,137 @@
+//! Localization of `validator` errors.
+//!
+//! Maps the codes of `ValidationErrors` to translation keys and interpolates their
+//! parameters (e.g. `{min}` and `{max}` of a `length` check), so form validation messages
+//! come out of the same catalog as the rest of the UI. Requires the `validator` feature.
+
+use crate::config::I18n;
+use ::validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// The default prefix of validation keys.
+pub const DEFAULT_PREFIX: &str = "validation";
+
+/// Translates a single validation error.
+///
+/// The key `<prefix>.<field>.<code>` is tried first, so a field can customize its message,
+/// then `<prefix>.<code>`. Every parameter of the error, and the `{field}` name, can be
+/// used as a placeholder. If neither key exists, the error's own message is used, or its
+/// code.
+///
+/// # Arguments
+/// - `i18n`: The i18n instance to translate with.
+/// - `prefix`: The prefix of validation keys (e.g., `DEFAULT_PREFIX`).
+/// - `field`: The name of the field that failed validation.
+/// - `error`: The validation error.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::validator::{DEFAULT_PREFIX, localize_error};
+/// use i18nrs::{I18n, I18nConfig};
+/// use std::collections::HashMap;
+/// use validator::ValidationError;
+///
+/// let translations = HashMap::from([(
+///     "en",
+///     r#"{"validation": {"length": "{field} must have {min} to {max} characters"}}"#,
+/// )]);
+/// let i18n = I18n::new(I18nConfig { translations: translations.clone() }, translations).unwrap();
+///
+/// let mut error = ValidationError::new("length");
+/// error.add_param("min".into(), &3);
+/// error.add_param("max".into(), &20);
+///
+/// assert_eq!(
+///     localize_error(&i18n, DEFAULT_PREFIX, "username", &error),
+///     "username must have 3 to 20 characters"
+/// );
+/// ```
+pub fn localize_error(i18n: &I18n, prefix: &str, field: &str, error: &ValidationError) -> String {
+    let params: Vec<(&str, String)> = error
+        .params
+        .iter()
+        .map(|(name, value)| (name.as_ref(), param_value(value)))
+        .chain(std::iter::once(("field", field.to_string())))
+        .collect();
+    let args: Vec<(&str, &dyn Display)> = params
+        .iter()
+        .map(|(name, value)| (*name, value as &dyn Display))
+        .collect();
+
+    [
+        format!("{}.{}.{}", prefix, field, error.code),
+        format!("{}.{}", prefix, error.code),
+    ]
+    .into_iter()
+    .find(|key| i18n.has_key(key))
+    .map(|key| i18n.t_with(&key, &args))
+    .or_else(|| error.message.as_ref().map(|message| message.to_string()))
+    .unwrap_or_else(|| error.code.to_string())
+}
+
+/// Translates every error of a validated value.
+///
+/// Errors of nested structs and lists are included with dotted and indexed paths (e.g.,
+/// `"address.city"` or `"items[0].name"`); the last segment is used as the field name.
+///
+/// # Arguments
+/// - `i18n`: The i18n instance to translate with.
+/// - `prefix`: The prefix of validation keys (e.g., `DEFAULT_PREFIX`).
+/// - `errors`: The errors returned by `Validate::validate`.
+///
+/// # Returns
+/// - The localized messages by field path, in path order.
+pub fn localize_errors(
+    i18n: &I18n,
+    prefix: &str,
+    errors: &ValidationErrors,
+) -> BTreeMap<String, Vec<String>> {
+    let mut messages = BTreeMap::new();
+    collect_errors(i18n, prefix, "", errors, &mut messages);
+    messages
+}
+
+/// Adds the messages of `errors`, nested under `path`, to `messages`.
+fn collect_errors(
+    i18n: &I18n,
+    prefix: &str,
+    path: &str,
+    errors: &ValidationErrors,
+    messages: &mut BTreeMap<String, Vec<String>>,
+) {
+    for (field, kind) in errors.errors() {
+        let field_path = if path.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", path, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                let localized = field_errors
+                    .iter()
+                    .map(|error| localize_error(i18n, prefix, field, error));
+                messages.entry(field_path).or_default().extend(localized);
+            }
+            ValidationErrorsKind::Struct(nested) => {
+                collect_errors(i18n, prefix, &field_path, nested, messages);
+            }
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    let item_path = format!("{}[{}]", field_path, index);
+                    collect_errors(i18n, prefix, &item_path, nested, messages);
+                }
+            }
+        }
+    }
+}
+
+/// Formats a parameter value for interpolation, without quoting strings.
+fn param_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
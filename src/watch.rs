@@ -0,0 +1,225 @@
+//! Hot reload of translation files on native targets.
+//!
+//! Watches a locale directory in the layouts read by `I18n::from_dir` (e.g.,
+//! `assets/locales/de.json` or `assets/locales/de/base.json`) by polling the modification
+//! times of its files, so desktop apps can pick up edited translations without a rebuild.
+//! The Dioxus `I18nProvider` uses it for its `locale_dir` prop; other integrations can
+//! poll a `LocaleWatcher` themselves. Not available on wasm.
+
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+#[cfg(feature = "dio")]
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        OnceLock,
+        mpsc::{self, RecvTimeoutError, Sender},
+    },
+    task::{Context, Poll, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Polls a locale directory for new, modified or removed translation files.
+#[derive(Clone, Debug)]
+pub struct LocaleWatcher {
+    dir: PathBuf,
+    modified: HashMap<PathBuf, SystemTime>,
+}
+
+impl LocaleWatcher {
+    /// Creates a watcher for a locale directory.
+    ///
+    /// The first `poll` reports every language in the directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            modified: HashMap::new(),
+        }
+    }
+
+    /// The watched directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Reads the languages whose files were created, modified or removed since the last
+    /// poll.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(String, Value)>)` with the language code and the merged translations of
+    ///   each changed language, as `I18n::from_dir` would load them.
+    /// - `Err(String)` if the directory or a file can't be read, or a file is invalid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use i18nrs::watch::LocaleWatcher;
+    /// use serde_json::json;
+    /// use std::fs;
+    ///
+    /// let dir = std::env::temp_dir().join("i18nrs-watch-doctest");
+    /// fs::create_dir_all(dir.join("de")).unwrap();
+    /// fs::write(dir.join("de").join("base.json"), r#"{"greeting": "Hallo"}"#).unwrap();
+    /// fs::write(dir.join("fr.json"), r#"{"greeting": "Bonjour"}"#).unwrap();
+    ///
+    /// let mut watcher = LocaleWatcher::new(&dir);
+    /// let changed = watcher.poll().unwrap();
+    /// assert_eq!(
+    ///     changed,
+    ///     [
+    ///         ("de".to_string(), json!({"greeting": "Hallo"})),
+    ///         ("fr".to_string(), json!({"greeting": "Bonjour"})),
+    ///     ]
+    /// );
+    /// assert!(watcher.poll().unwrap().is_empty());
+    /// # fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn poll(&mut self) -> Result<Vec<(String, Value)>, String> {
+        let mut modified = HashMap::new();
+        for path in i18nrs_build::locale_files(&self.dir)? {
+            if let Ok(time) = fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+                modified.insert(path, time);
+            }
+        }
+
+        let changed: BTreeSet<String> = modified
+            .iter()
+            .filter(|(path, time)| self.modified.get(*path) != Some(*time))
+            .map(|(path, _)| path)
+            .chain(
+                self.modified
+                    .keys()
+                    .filter(|path| !modified.contains_key(*path)),
+            )
+            .filter_map(|path| self.language_of(path))
+            .collect();
+        if changed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut translations = i18nrs_build::load_dir(&self.dir)?;
+        self.modified = modified;
+        Ok(changed
+            .into_iter()
+            .filter_map(|language| {
+                let json = translations.remove(&language)?;
+                Some((language, json))
+            })
+            .collect())
+    }
+
+    /// The language a translation file belongs to: the stem of a `<language>.json`
+    /// bundle, or the name of the `<language>/` directory holding it.
+    fn language_of(&self, path: &Path) -> Option<String> {
+        let parent = path.parent()?;
+        let name = if parent == self.dir {
+            path.file_stem()
+        } else {
+            parent.file_name()
+        };
+        name.and_then(|name| name.to_str()).map(str::to_string)
+    }
+}
+
+/// Waits for a duration without depending on an async runtime.
+///
+/// Every pending delay is woken from a single timer thread, started on first use, which
+/// suits the coarse intervals of polling for file changes.
+#[cfg(feature = "dio")]
+pub(crate) fn sleep(duration: Duration) -> impl Future<Output = ()> {
+    struct Delay {
+        deadline: Instant,
+        scheduled: bool,
+    }
+
+    impl Future for Delay {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if Instant::now() >= self.deadline {
+                return Poll::Ready(());
+            }
+            if !self.scheduled {
+                self.scheduled = true;
+                let _ = timer().send((self.deadline, cx.waker().clone()));
+            }
+            Poll::Pending
+        }
+    }
+
+    Delay {
+        deadline: Instant::now() + duration,
+        scheduled: false,
+    }
+}
+
+/// A pending wake-up of the timer thread.
+#[cfg(feature = "dio")]
+struct Wakeup(Instant, Waker);
+
+#[cfg(feature = "dio")]
+impl PartialEq for Wakeup {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(feature = "dio")]
+impl Eq for Wakeup {}
+
+#[cfg(feature = "dio")]
+impl PartialOrd for Wakeup {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "dio")]
+impl Ord for Wakeup {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// Returns the channel of the timer thread, starting the thread on first use.
+///
+/// The thread wakes each waker once its deadline has passed, earliest first.
+#[cfg(feature = "dio")]
+fn timer() -> &'static Sender<(Instant, Waker)> {
+    static TIMER: OnceLock<Sender<(Instant, Waker)>> = OnceLock::new();
+    TIMER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<(Instant, Waker)>();
+        thread::spawn(move || {
+            let mut pending = BinaryHeap::new();
+            loop {
+                let now = Instant::now();
+                while pending
+                    .peek()
+                    .is_some_and(|Reverse(Wakeup(deadline, _))| *deadline <= now)
+                {
+                    if let Some(Reverse(Wakeup(_, waker))) = pending.pop() {
+                        waker.wake();
+                    }
+                }
+                let received = match pending.peek() {
+                    Some(Reverse(Wakeup(deadline, _))) => {
+                        receiver.recv_timeout(deadline.saturating_duration_since(now))
+                    }
+                    None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                };
+                match received {
+                    Ok((deadline, waker)) => pending.push(Reverse(Wakeup(deadline, waker))),
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+        });
+        sender
+    })
+}
@@ -1,10 +1,36 @@
 #![doc = include_str!("../YEW.md")]
 
-use crate::config::{I18n, I18nConfig, StorageType};
-use std::collections::HashMap;
+use crate::compression::decompress;
+use crate::config::{
+    I18n, I18nArgs, I18nConfig, I18nNamespace, StorageType, TextDirection, check_bundle_schema,
+    interpolate, parse_bundle, with_placeholders,
+};
+#[cfg(all(feature = "yew-ssr", target_arch = "wasm32"))]
+use crate::cookie::{Cookie, write_document_cookie};
+use crate::detect::{DetectionOrder, provider_languages, read_browser};
+#[cfg(feature = "editor")]
+use crate::editor::{EDITOR_LAYER, EDITOR_STYLE, TranslationEdits};
+use crate::global::set_global;
+use crate::guard::{LanguageChange, LanguageGuard};
+use crate::lifecycle::{ChangeSource, LifecycleEvent};
+use crate::loader::NamespaceLoaders;
+use crate::remote::{cached_bundle, fetch_cached};
+use crate::report::MissingKeyReporter;
+#[cfg(target_arch = "wasm32")]
+use crate::seo::{AlternateLink, MetaTag};
+use crate::seo::{alternate_links, meta_tags};
+use crate::trans::{TransNode, parse};
+use crate::url::{write_hash_param, write_query_param};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::ops::Deref;
+use std::rc::Rc;
 #[cfg(target_arch = "wasm32")]
 use web_sys::window;
 use yew::prelude::*;
+use yew::suspense::{Suspension, SuspensionHandle, SuspensionResult};
 
 /// Properties for the `I18nProvider` component.
 ///
@@ -29,6 +55,9 @@ pub struct I18nProviderConfig {
     /// Determines where the selected language is stored in the browser. Options include:
     /// - `StorageType::LocalStorage`: Use the browser's local storage.
     /// - `StorageType::SessionStorage`: Use the browser's session storage.
+    /// - `StorageType::Cookie`: Use a cookie, which the server can read when rendering.
+    /// - `StorageType::UrlQuery`: Keep the language only in the `storage_name` query
+    ///   parameter of the page URL.
     ///
     /// Defaults to `StorageType::LocalStorage`.
     #[prop_or_default]
@@ -50,6 +79,48 @@ pub struct I18nProviderConfig {
     #[prop_or("en".to_string())]
     pub default_language: String,
 
+    /// Pins the provider to a language, ignoring storage, cookies and detection.
+    ///
+    /// Meant for pre-rendering each locale of a static site, where the language comes
+    /// from the page being generated (e.g., `/de/`) rather than from the visitor; see
+    /// `ssg::render_locales`. `set_language` still switches languages afterwards.
+    #[prop_or_default]
+    pub language: Option<String>,
+
+    /// Query parameter kept in sync with the current language (e.g., `"lang"`).
+    ///
+    /// Each language change rewrites the parameter in the address bar through the History
+    /// API, without a reload or a new history entry, so shared URLs open in the language
+    /// they were shared in. Defaults to `None`, leaving the URL untouched.
+    #[prop_or_default]
+    pub url_param: Option<String>,
+
+    /// Fragment parameter holding the language (e.g., `"lang"` for `#lang=fr`).
+    ///
+    /// A language in the fragment takes precedence over the stored one when the provider
+    /// starts, and each language change rewrites the fragment. Unlike query parameters,
+    /// fragments survive static hosts and caches that strip query strings. Defaults to
+    /// `None`, leaving the fragment untouched.
+    #[prop_or_default]
+    pub hash_param: Option<String>,
+
+    /// Where the initial language is read from, by priority; see `DetectionOrder`.
+    ///
+    /// The first source naming an available language wins, and `default_language` is
+    /// used when none does. Defaults to the URL (`url_param`, then `hash_param`), the
+    /// cookie, browser storage, then the browser's preferred languages.
+    #[prop_or_default]
+    pub detection: DetectionOrder,
+
+    /// Selectable languages, in order (e.g., `vec!["en", "de", "fr"]`).
+    ///
+    /// Restricts the languages that can be selected to these bundles, lists them in this
+    /// order in `available_languages`, and makes the first one the fallback for missing
+    /// keys, instead of relying on the iteration order of `translations`. Defaults to all
+    /// provided bundles.
+    #[prop_or_default]
+    pub supported_languages: Vec<&'static str>,
+
     /// Callback when the language changes.
     ///
     /// This callback is triggered whenever the language is changed. It receives the new language code as a `String`.
@@ -62,6 +133,379 @@ pub struct I18nProviderConfig {
     /// It receives an error message as a `String`.
     #[prop_or_default]
     pub onerror: Callback<String>,
+
+    /// Renders the content shown when initialization fails.
+    ///
+    /// Receives the error message, e.g. malformed translation JSON, and is rendered in
+    /// place of `children`; `onerror` is still triggered. Without it, nothing is rendered.
+    #[prop_or_default]
+    pub error_fallback: Option<Callback<String, Html>>,
+
+    /// Content rendered instead of `children` while translations load.
+    ///
+    /// Shown while the remote bundle of the current language is fetched without a cached
+    /// copy, so apps can display a spinner instead of untranslated keys.
+    #[prop_or_default]
+    pub loading: Option<Html>,
+
+    /// Lazy loaders for namespace bundles.
+    ///
+    /// Maps namespace names to loaders invoked the first time a component calls
+    /// `use_translation_ns` with that namespace for the current language.
+    /// Defaults to an empty `HashMap`.
+    #[prop_or_default]
+    pub namespaces: NamespaceLoaders,
+
+    /// URLs of remotely hosted translation bundles.
+    ///
+    /// Maps language codes to the URL of their JSON bundle. The bundle of the current language
+    /// is fetched when it is first selected and replaces the embedded `translations` entry.
+    /// Responses are cached in `localStorage` and revalidated with `ETag`/`Last-Modified`.
+    /// If the fetch fails, the embedded translations are kept and `onerror` is triggered.
+    /// Defaults to an empty `HashMap`.
+    #[prop_or_default]
+    pub urls: HashMap<&'static str, &'static str>,
+
+    /// Gzip or Brotli compressed translation bundles.
+    ///
+    /// Maps language codes to compressed JSON (e.g. `include_bytes!("../i18n/en.json.gz")`),
+    /// decompressed once when the provider initializes. Requires the `compression` feature.
+    /// Defaults to an empty `HashMap`.
+    #[prop_or_default]
+    pub compressed_translations: HashMap<&'static str, &'static [u8]>,
+
+    /// Callback when a remote bundle replaces the translations being displayed.
+    ///
+    /// Cached remote bundles are served immediately and revalidated in the background.
+    /// This callback is triggered with the language code when the revalidation returns
+    /// newer translations than the cached ones.
+    #[prop_or_default]
+    pub onupdated: Callback<String>,
+
+    /// Async guard awaited before a language switch requested through `set_language`
+    /// completes.
+    ///
+    /// Resolving to `false` cancels the switch, e.g. after the user declines to leave a
+    /// form with unsaved changes. Switches forced by removing the current language skip it.
+    #[prop_or_default]
+    pub onbeforechange: Option<LanguageGuard>,
+
+    /// Callback receiving a `Before` and an `After` event for each language change,
+    /// including the initial one, with the previous and new language and what triggered
+    /// the change (the user, storage, ...).
+    #[prop_or_default]
+    pub onlifecycle: Callback<LifecycleEvent>,
+
+    /// Overrides of the writing direction, by language code.
+    ///
+    /// Extends or overrides the built-in list of RTL languages, e.g. to treat `"dv"` or a
+    /// custom locale as RTL. Codes are matched in full first, then by primary subtag.
+    #[prop_or_default]
+    pub directions: HashMap<&'static str, TextDirection>,
+
+    /// Whether to also set `xml:lang` on the `<html>` element.
+    ///
+    /// The `lang` and `dir` attributes are always kept in sync with the current language;
+    /// enable this for XHTML documents that rely on `xml:lang`.
+    #[prop_or_default]
+    pub xml_lang: bool,
+
+    /// Whether to render translation keys instead of their values.
+    ///
+    /// A debug mode for QA and translators to identify which key drives which piece of UI.
+    /// Can be toggled at runtime.
+    #[prop_or_default]
+    pub show_keys: bool,
+
+    /// Override catalogs by tenant name, layered over `translations`; see `I18n::add_tenant`.
+    ///
+    /// Lets white-label products serve many brands from one bundle, e.g.
+    /// `HashMap::from([("acme", HashMap::from([("en", r#"{"brand": "Acme"}"#)]))])`.
+    #[prop_or_default]
+    pub tenants: HashMap<&'static str, HashMap<&'static str, &'static str>>,
+
+    /// The tenant whose overrides apply, from `tenants`. Can be switched at runtime.
+    ///
+    /// Defaults to `None`, resolving every key from the shared `translations`.
+    #[prop_or_default]
+    pub tenant: Option<String>,
+
+    /// Reporter that batches missing-key events and posts them to an endpoint.
+    ///
+    /// Lets keys missing in production feed directly into the translation backlog.
+    #[prop_or_default]
+    pub missing_key_reporter: Option<MissingKeyReporter>,
+
+    /// Whether to track which keys are resolved, for `I18n::usage_report`.
+    #[prop_or_default]
+    pub track_usage: bool,
+
+    /// Whether to panic on keys missing for the current language, in debug builds only.
+    ///
+    /// Catches untranslated strings during development; release builds fall back
+    /// gracefully regardless. See `I18n::set_strict`.
+    #[prop_or_default]
+    pub strict: bool,
+
+    /// Whether to compare the placeholders of every language with `default_language` when
+    /// the provider starts, reporting each mismatch through `onerror`.
+    ///
+    /// See `I18n::check_placeholders`.
+    #[prop_or_default]
+    pub check_placeholders: bool,
+
+    /// A JSON Schema every language must satisfy, e.g. generated from the base locale with
+    /// `schema::from_locale`.
+    ///
+    /// Invalid `translations` fail initialization like malformed JSON, rendering
+    /// `error_fallback`; invalid compressed and remote bundles are rejected and reported
+    /// through `onerror`. See `I18n::with_schema`.
+    #[prop_or_default]
+    pub schema: Option<Value>,
+
+    /// Whether to report keys that loaded namespaces or translation layers redefine with a
+    /// different value through `onerror`, instead of letting the last write win silently.
+    ///
+    /// See `I18n::set_detect_conflicts`.
+    #[prop_or_default]
+    pub detect_conflicts: bool,
+
+    /// Whether to install the provider's instance as `i18nrs::global::global()`.
+    ///
+    /// Keeps the global handle in sync with the active language, for error types,
+    /// validators and other code outside the component tree.
+    #[prop_or_default]
+    pub global: bool,
+
+    /// The key of an independent i18n context.
+    ///
+    /// A named provider registers its context under this key instead of providing the
+    /// default one, so it can coexist with another provider (e.g. app UI vs. embedded CMS
+    /// content) without shadowing it. The document's `lang` and `dir` attributes, and the
+    /// namespace and loading hooks, are left to the default provider.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+}
+
+/// The remote loading state provided to children by the `I18nProvider`.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct I18nLoading {
+    /// Whether the remote bundle of the current language is still being fetched.
+    pub loading: bool,
+    /// Whether the translations of the current language are available.
+    pub is_ready: bool,
+    /// Resumed once the remote bundle of the current language is loaded.
+    suspension: Option<Suspension>,
+}
+
+/// The named i18n contexts provided to children by `I18nProvider`s with a `name`.
+///
+/// Nested named providers extend the registry of their parent, so every named context
+/// above a component is reachable with `use_translation_named`.
+#[derive(Clone, PartialEq, Default)]
+pub struct I18nRegistry {
+    /// Contexts by provider name.
+    contexts: Rc<HashMap<String, (I18n, Callback<String>)>>,
+}
+
+impl I18nRegistry {
+    /// Retrieves the i18n instance and language setter registered under a name.
+    pub fn get(&self, name: &str) -> Option<(I18n, Callback<String>)> {
+        self.contexts.get(name).cloned()
+    }
+
+    /// Returns a registry with the given context added, replacing any with the same name.
+    fn with(self, name: &str, i18n: I18n, set_language: Callback<String>) -> Self {
+        let mut contexts = (*self.contexts).clone();
+        contexts.insert(name.to_string(), (i18n, set_language));
+        Self {
+            contexts: Rc::new(contexts),
+        }
+    }
+}
+
+/// The namespace loading state provided to children by the `I18nProvider`.
+///
+/// Tracks which lazily loaded namespaces are still in flight for the current language.
+#[derive(Clone, PartialEq)]
+pub struct I18nNamespaces {
+    /// The current language code.
+    language: String,
+    /// `(language, namespace)` pairs currently being loaded.
+    loading: Rc<HashSet<(String, String)>>,
+    /// Requests a namespace to be loaded for the current language.
+    request: Callback<String>,
+}
+
+impl I18nNamespaces {
+    /// Checks whether a namespace is still loading for the current language.
+    pub fn is_loading(&self, namespace: &str) -> bool {
+        self.loading
+            .contains(&(self.language.clone(), namespace.to_string()))
+    }
+
+    /// Requests a namespace to be loaded for the current language.
+    ///
+    /// Does nothing if the namespace has no registered loader, is already loaded, or is loading.
+    pub fn load(&self, namespace: &str) {
+        self.request.emit(namespace.to_string());
+    }
+}
+
+/// The state managed by the `I18nProvider`.
+#[derive(Clone, PartialEq)]
+struct I18nState {
+    i18n: I18n,
+    error: Option<String>,
+    loading: Rc<HashSet<(String, String)>>,
+    requested: Rc<HashSet<String>>,
+    fetching: Rc<HashSet<String>>,
+    suspensions: Rc<RefCell<HashMap<String, (Suspension, SuspensionHandle)>>>,
+}
+
+enum I18nAction {
+    SetLanguage(String),
+    Preload {
+        language: String,
+        onerror: Callback<String>,
+    },
+    AddLanguage {
+        language: String,
+        json: Value,
+    },
+    RemoveLanguage(String),
+    SetLayer {
+        name: String,
+        translations: HashMap<String, String>,
+        onerror: Callback<String>,
+    },
+    ShowKeys(bool),
+    SetTenant(Option<String>),
+    NamespaceRequested {
+        language: String,
+        namespace: String,
+    },
+    NamespaceLoaded {
+        language: String,
+        namespace: String,
+        result: Result<String, String>,
+        onerror: Callback<String>,
+    },
+    RemoteRequested {
+        language: String,
+        cached: Option<String>,
+    },
+    RemoteLoaded {
+        language: String,
+        cached: Option<String>,
+        result: Result<String, String>,
+        onerror: Callback<String>,
+        onupdated: Callback<String>,
+    },
+}
+
+impl Reducible for I18nState {
+    type Action = I18nAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            I18nAction::SetLanguage(language) => {
+                let _ = state.i18n.select_language(&language);
+            }
+            I18nAction::Preload { language, onerror } => {
+                if let Err(err) = state.i18n.preload_language(&language) {
+                    onerror.emit(err);
+                }
+            }
+            I18nAction::AddLanguage { language, json } => {
+                state.i18n.add_language_value(&language, json);
+            }
+            I18nAction::RemoveLanguage(language) => {
+                let _ = state.i18n.remove_language(&language);
+            }
+            I18nAction::SetLayer {
+                name,
+                translations,
+                onerror,
+            } => {
+                let translations = translations
+                    .iter()
+                    .map(|(language, json)| (language.as_str(), json.as_str()))
+                    .collect();
+                if let Err(err) = state.i18n.set_layer(&name, translations) {
+                    onerror.emit(err);
+                }
+                for conflict in state.i18n.take_conflicts() {
+                    onerror.emit(conflict.to_string());
+                }
+            }
+            I18nAction::ShowKeys(show_keys) => {
+                state.i18n.set_show_keys(show_keys);
+            }
+            I18nAction::SetTenant(Some(name)) => {
+                let _ = state.i18n.set_tenant(&name);
+            }
+            I18nAction::SetTenant(None) => {
+                state.i18n.clear_tenant();
+            }
+            I18nAction::NamespaceRequested {
+                language,
+                namespace,
+            } => {
+                Rc::make_mut(&mut state.loading).insert((language, namespace));
+            }
+            I18nAction::NamespaceLoaded {
+                language,
+                namespace,
+                result,
+                onerror,
+            } => {
+                let result =
+                    result.and_then(|json| state.i18n.add_namespace(&language, &namespace, &json));
+                if let Err(err) = result {
+                    onerror.emit(err);
+                }
+                for conflict in state.i18n.take_conflicts() {
+                    onerror.emit(conflict.to_string());
+                }
+                Rc::make_mut(&mut state.loading).remove(&(language, namespace));
+            }
+            I18nAction::RemoteRequested { language, cached } => {
+                Rc::make_mut(&mut state.requested).insert(language.clone());
+                let served = cached
+                    .map(|json| state.i18n.add_language(&language, &json).is_ok())
+                    .unwrap_or(false);
+                if !served {
+                    state
+                        .suspensions
+                        .borrow_mut()
+                        .insert(language.clone(), Suspension::new());
+                    Rc::make_mut(&mut state.fetching).insert(language);
+                }
+            }
+            I18nAction::RemoteLoaded {
+                language,
+                cached,
+                result,
+                onerror,
+                onupdated,
+            } => {
+                match result {
+                    Ok(json) if cached.as_ref() == Some(&json) => {}
+                    Ok(json) => match state.i18n.add_language(&language, &json) {
+                        Ok(()) if cached.is_some() => onupdated.emit(language.clone()),
+                        Ok(()) => {}
+                        Err(err) => onerror.emit(err),
+                    },
+                    Err(err) => onerror.emit(err),
+                }
+                state.suspensions.borrow_mut().remove(&language);
+                Rc::make_mut(&mut state.fetching).remove(&language);
+            }
+        }
+        Rc::new(state)
+    }
 }
 
 /// I18nProvider Component
@@ -179,11 +623,13 @@ pub struct I18nProviderConfig {
 /// ```
 ///
 /// # Behavior
-/// - Retrieves the selected language from browser storage based on the `storage_type` and `storage_name`.
-/// - Uses the `default_language` if no language is found in storage.
+/// - Detects the initial language from the `detection` sources (URL, cookie, browser storage, browser languages).
+/// - Uses the `default_language` if no source names an available language.
 /// - Initializes and provides the i18n context with translations and language selection capabilities.
 /// - Emits the `onchange` callback when the language changes, passing the new language code.
 /// - Emits the `onerror` callback in case of initialization or runtime errors.
+/// - Renders `error_fallback` instead of the children if initialization fails.
+/// - Renders `loading` instead of the children while the current language is fetched.
 ///
 /// # Notes
 /// - The `children` property wraps the components that will have access to the i18n context.
@@ -191,108 +637,1276 @@ pub struct I18nProviderConfig {
 /// - The `set_language` callback is available via context to dynamically change the selected language.
 #[function_component(I18nProvider)]
 pub fn i18n_provider(props: &I18nProviderConfig) -> Html {
-    let initial_language = get_initial_language(&props.storage_type, &props.storage_name)
-        .unwrap_or_else(|| Some(props.default_language.clone()));
-
-    #[cfg(target_arch = "wasm32")]
-    let is_rtl_language =
-        |lang: &str| matches!(lang, "ar" | "he" | "fa" | "ur" | "ps" | "ku" | "sd");
+    let detected = use_memo((), |_| {
+        let available = provider_languages(
+            &props.translations,
+            &props.urls,
+            &props.compressed_translations,
+            &props.supported_languages,
+        );
+        props
+            .detection
+            .resolve(&available, |source| {
+                read_browser(
+                    source,
+                    &props.storage_type,
+                    &props.storage_name,
+                    props.url_param.as_deref(),
+                    props.hash_param.as_deref(),
+                )
+            })
+            .map(|(language, source)| (language.to_string(), source))
+    });
+    let initial_source = match (&props.language, &*detected) {
+        (Some(_), _) => ChangeSource::Pinned,
+        (None, Some((_, source))) => source.change_source(),
+        (None, None) => ChangeSource::Default,
+    };
+    let initial_language = props
+        .language
+        .clone()
+        .or_else(|| {
+            detected
+                .as_ref()
+                .as_ref()
+                .map(|(language, _)| language.clone())
+        })
+        .or_else(|| Some(props.default_language.clone()));
+    let registry = use_context::<I18nRegistry>();
 
-    let update_text_direction = move |_lang: &str| {
+    let directions = props.directions.clone();
+    let _xml_lang = props.xml_lang;
+    let _named = props.name.is_some();
+    let update_document_language = move |lang: &str| {
+        let _dir = TextDirection::resolve(lang, &directions);
         #[cfg(target_arch = "wasm32")]
         {
-            if let Some(document) = window().and_then(|win| win.document()) {
-                let dir = if is_rtl_language(_lang) { "rtl" } else { "ltr" };
-                if let Some(html_element) = document.document_element() {
-                    let _ = html_element.set_attribute("dir", dir);
+            if _named {
+                return;
+            }
+            if let Some(html_element) = window()
+                .and_then(|win| win.document())
+                .and_then(|document| document.document_element())
+            {
+                let _ = html_element.set_attribute("dir", _dir.as_str());
+                let _ = html_element.set_attribute("lang", lang);
+                if _xml_lang {
+                    let _ = html_element.set_attribute("xml:lang", lang);
                 }
             }
         }
     };
 
-    update_text_direction(&initial_language.clone().unwrap_or_else(|| "en".to_string()));
+    use_memo((), |_| {
+        update_document_language(&initial_language.clone().unwrap_or_else(|| "en".to_string()))
+    });
+
+    let ctx = use_reducer(|| {
+        let translations = with_placeholders(
+            &props.translations,
+            props
+                .urls
+                .keys()
+                .chain(props.compressed_translations.keys()),
+        );
 
-    let i18n = I18n::new(
-        I18nConfig {
-            translations: props.translations.clone(),
-        },
-        props.translations.clone(),
-    )
-    .map(|mut instance| {
-        if let Err(err) = instance.set_translation_language(
-            &initial_language.clone().unwrap_or_default(),
-            &props.storage_type,
-            &props.storage_name,
-        ) {
-            props.onerror.emit(err);
+        let result = I18n::new(
+            I18nConfig {
+                translations: translations.clone(),
+            },
+            translations,
+        )
+        .and_then(|instance| match &props.schema {
+            Some(schema) => {
+                let bundled: Vec<&str> = props.translations.keys().copied().collect();
+                instance
+                    .validate_schema(&bundled, schema)
+                    .map(|()| instance)
+            }
+            None => Ok(instance),
+        })
+        .map(|mut instance| {
+            for (language, bytes) in &props.compressed_translations {
+                if let Err(err) = decompress(bytes).and_then(|json| {
+                    check_bundle_schema(language, &json, props.schema.as_ref())?;
+                    instance.add_language(language, &json)
+                }) {
+                    props.onerror.emit(err);
+                }
+            }
+            // Without a bundle for it, the alphabetically first language stays the fallback.
+            let _ = instance.set_default_language(&props.default_language);
+            if let Err(err) = instance.set_supported_languages(&props.supported_languages) {
+                props.onerror.emit(err);
+            }
+            let language = initial_language.clone().unwrap_or_default();
+            let event = LifecycleEvent::before(None, &language, initial_source);
+            props.onlifecycle.emit(event.clone());
+            match instance.set_translation_language(
+                &language,
+                &props.storage_type,
+                &props.storage_name,
+            ) {
+                Ok(()) => {
+                    if let Some(name) = &props.url_param {
+                        write_query_param(name, &language);
+                    }
+                    if let Some(name) = &props.hash_param {
+                        write_hash_param(name, &language);
+                    }
+                    props.onlifecycle.emit(event.after());
+                }
+                Err(err) => props.onerror.emit(err),
+            }
+            for (name, translations) in &props.tenants {
+                if let Err(err) = instance.add_tenant(name, translations.clone()) {
+                    props.onerror.emit(err);
+                }
+            }
+            if let Some(name) = &props.tenant
+                && let Err(err) = instance.set_tenant(name)
+            {
+                props.onerror.emit(err);
+            }
+            instance.set_directions(props.directions.clone());
+            instance.set_show_keys(props.show_keys);
+            instance.set_track_usage(props.track_usage);
+            instance.set_strict(props.strict);
+            instance.set_detect_conflicts(props.detect_conflicts);
+            if props.check_placeholders {
+                match instance.check_placeholders(&props.default_language) {
+                    Ok(mismatches) => mismatches
+                        .iter()
+                        .for_each(|mismatch| props.onerror.emit(mismatch.to_string())),
+                    Err(err) => props.onerror.emit(err),
+                }
+            }
+            instance.set_missing_key_handler(
+                props
+                    .missing_key_reporter
+                    .as_ref()
+                    .map(MissingKeyReporter::handler),
+            );
+            instance
+        });
+
+        let (i18n, error) = match result {
+            Ok(i18n) => (i18n, None),
+            Err(err) => {
+                props.onerror.emit(err.clone());
+                (I18n::empty(&props.default_language), Some(err))
+            }
+        };
+
+        I18nState {
+            i18n,
+            error,
+            loading: Rc::default(),
+            requested: Rc::default(),
+            fetching: Rc::default(),
+            suspensions: Rc::default(),
         }
-        instance
-    })
-    .unwrap_or_else(|err| {
-        props.onerror.emit(err.clone());
-        panic!("Failed to initialize I18n: {}", err);
     });
 
-    let ctx = use_state(|| i18n);
+    let request_bundle = {
+        let ctx = ctx.clone();
+        let urls = props.urls.clone();
+        let onerror = props.onerror.clone();
+        let onupdated = props.onupdated.clone();
+        let schema = props.schema.clone();
+        Callback::from(move |language: String| {
+            if let Some(url) = urls.get(language.as_str()).copied()
+                && !ctx.requested.contains(&language)
+            {
+                let cached = cached_bundle(url);
+                ctx.dispatch(I18nAction::RemoteRequested {
+                    language: language.clone(),
+                    cached: cached.clone(),
+                });
+
+                let dispatcher = ctx.dispatcher();
+                let onerror = onerror.clone();
+                let onupdated = onupdated.clone();
+                let schema = schema.clone();
+                yew::platform::spawn_local(async move {
+                    let result = fetch_cached(url).await.and_then(|json| {
+                        check_bundle_schema(&language, &json, schema.as_ref()).map(|()| json)
+                    });
+                    dispatcher.dispatch(I18nAction::RemoteLoaded {
+                        language,
+                        cached,
+                        result,
+                        onerror,
+                        onupdated,
+                    });
+                });
+            }
+        })
+    };
+
+    {
+        let request_bundle = request_bundle.clone();
+        let language = ctx.i18n.get_current_language().to_string();
+        use_effect_with(language, move |language| {
+            request_bundle.emit(language.clone())
+        });
+    }
+
+    let preload_language = {
+        let ctx = ctx.clone();
+        let onerror = props.onerror.clone();
+        Callback::from(move |language: String| {
+            if !ctx.i18n.is_loaded(&language) {
+                ctx.dispatch(I18nAction::Preload {
+                    language: language.clone(),
+                    onerror: onerror.clone(),
+                });
+            }
+            request_bundle.emit(language);
+        })
+    };
 
+    let add_language = {
+        let ctx = ctx.clone();
+        Callback::from(move |(language, json): (String, Value)| {
+            ctx.dispatch(I18nAction::AddLanguage { language, json });
+        })
+    };
     let onchange = props.onchange.clone();
     let storage_type = props.storage_type.clone();
     let storage_name = props.storage_name.clone();
 
-    let set_language = {
+    use_effect_with((props.global, ctx.i18n.clone()), |(global, i18n)| {
+        if *global {
+            set_global(i18n.clone());
+        }
+    });
+
+    {
         let ctx = ctx.clone();
-        Callback::from(move |language: String| {
-            let mut i18n = (*ctx).clone();
-            update_text_direction(&language);
+        use_effect_with(props.show_keys, move |show_keys| {
+            if ctx.i18n.show_keys() != *show_keys {
+                ctx.dispatch(I18nAction::ShowKeys(*show_keys));
+            }
+        });
+    }
+
+    {
+        let ctx = ctx.clone();
+        let onerror = props.onerror.clone();
+        use_effect_with(props.tenant.clone(), move |tenant| {
+            if ctx.i18n.tenant() != tenant.as_deref() {
+                match tenant {
+                    Some(name) if !ctx.i18n.tenants().contains(&name.as_str()) => {
+                        onerror.emit(format!("Tenant '{}' is not registered", name));
+                    }
+                    _ => ctx.dispatch(I18nAction::SetTenant(tenant.clone())),
+                }
+            }
+        });
+    }
+
+    let apply_language = {
+        let ctx = ctx.clone();
+        let onlifecycle = props.onlifecycle.clone();
+        let url_param = props.url_param.clone();
+        let hash_param = props.hash_param.clone();
+        Callback::from(move |event: LifecycleEvent| {
+            let language = event.to.clone();
+            let mut i18n = ctx.i18n.clone();
+            update_document_language(&language);
 
             if i18n
                 .set_translation_language(&language, &storage_type, &storage_name)
                 .is_ok()
             {
-                ctx.set(i18n);
+                if let Some(name) = &url_param {
+                    write_query_param(name, &language);
+                }
+                if let Some(name) = &hash_param {
+                    write_hash_param(name, &language);
+                }
+                #[cfg(all(feature = "yew-ssr", target_arch = "wasm32"))]
+                if !matches!(storage_type, StorageType::Cookie | StorageType::UrlQuery) {
+                    let _ = write_document_cookie(&Cookie::language(&storage_name, &language));
+                }
+                ctx.dispatch(I18nAction::SetLanguage(language.clone()));
                 onchange.emit(language);
+                onlifecycle.emit(event.after());
+            }
+        })
+    };
+
+    let set_language = {
+        let ctx = ctx.clone();
+        let apply_language = apply_language.clone();
+        let guard = props.onbeforechange.clone();
+        let onlifecycle = props.onlifecycle.clone();
+        Callback::from(move |language: String| {
+            let from = ctx.i18n.get_current_language().to_string();
+            let event = LifecycleEvent::before(Some(&from), &language, ChangeSource::User);
+            onlifecycle.emit(event.clone());
+
+            match guard.clone() {
+                Some(guard) => {
+                    let change = LanguageChange { from, to: language };
+                    let apply_language = apply_language.clone();
+                    yew::platform::spawn_local(async move {
+                        if guard.check(change).await {
+                            apply_language.emit(event);
+                        }
+                    });
+                }
+                None => apply_language.emit(event),
+            }
+        })
+    };
+
+    let remove_language = {
+        let ctx = ctx.clone();
+        let onlifecycle = props.onlifecycle.clone();
+        Callback::from(move |language: String| {
+            let mut i18n = ctx.i18n.clone();
+            if i18n.remove_language(&language).is_ok() {
+                ctx.dispatch(I18nAction::RemoveLanguage(language));
+                let previous = ctx.i18n.get_current_language();
+                let current = i18n.get_current_language();
+                if current != previous {
+                    let event =
+                        LifecycleEvent::before(Some(previous), current, ChangeSource::Fallback);
+                    onlifecycle.emit(event.clone());
+                    apply_language.emit(event);
+                }
+            }
+        })
+    };
+    let set_layer = {
+        let ctx = ctx.clone();
+        let onerror = props.onerror.clone();
+        Callback::from(move |(name, translations)| {
+            ctx.dispatch(I18nAction::SetLayer {
+                name,
+                translations,
+                onerror: onerror.clone(),
+            });
+        })
+    };
+    let commands = I18nCommands {
+        preload_language,
+        add_language,
+        remove_language,
+        set_layer,
+    };
+
+    let request_namespace = {
+        let ctx = ctx.clone();
+        let loaders = props.namespaces.clone();
+        let onerror = props.onerror.clone();
+        Callback::from(move |namespace: String| {
+            let language = ctx.i18n.get_current_language().to_string();
+            let Some(loader) = loaders.get(namespace.as_str()).cloned() else {
+                return;
+            };
+            let pending = (language.clone(), namespace.clone());
+            if ctx.i18n.has_namespace(&language, &namespace) || ctx.loading.contains(&pending) {
+                return;
             }
+
+            ctx.dispatch(I18nAction::NamespaceRequested {
+                language: language.clone(),
+                namespace: namespace.clone(),
+            });
+
+            let dispatcher = ctx.dispatcher();
+            let onerror = onerror.clone();
+            yew::platform::spawn_local(async move {
+                let result = loader.load(&language).await;
+                dispatcher.dispatch(I18nAction::NamespaceLoaded {
+                    language,
+                    namespace,
+                    result,
+                    onerror,
+                });
+            });
         })
     };
 
+    let namespaces = I18nNamespaces {
+        language: ctx.i18n.get_current_language().to_string(),
+        loading: ctx.loading.clone(),
+        request: request_namespace,
+    };
+
+    let fetching = ctx.fetching.contains(ctx.i18n.get_current_language());
+    let loading = I18nLoading {
+        loading: fetching,
+        is_ready: !fetching,
+        suspension: ctx
+            .suspensions
+            .borrow()
+            .get(ctx.i18n.get_current_language())
+            .map(|(suspension, _)| suspension.clone()),
+    };
+
+    if let Some(err) = &ctx.error {
+        return props
+            .error_fallback
+            .as_ref()
+            .map(|fallback| fallback.emit(err.clone()))
+            .unwrap_or_default();
+    }
+
+    let children = match &props.loading {
+        Some(placeholder) if fetching => placeholder.clone(),
+        _ => props.children.clone(),
+    };
+
+    if let Some(name) = &props.name {
+        let registry = registry
+            .unwrap_or_default()
+            .with(name, ctx.i18n.clone(), set_language);
+        return html! {
+            <ContextProvider<I18nRegistry> context={registry}>
+                { children }
+            </ContextProvider<I18nRegistry>>
+        };
+    }
+
+    let language = CurrentLanguage(ctx.i18n.get_current_language().to_string());
     html! {
-        <ContextProvider<I18n> context={(*ctx).clone()}>
-            <ContextProvider<Callback<String>> context={set_language}>
-                { props.children.clone() }
-            </ContextProvider<Callback<String>>>
+        <ContextProvider<I18n> context={ctx.i18n.clone()}>
+            <ContextProvider<CurrentLanguage> context={language}>
+                <ContextProvider<Callback<String>> context={set_language}>
+                    <ContextProvider<I18nCommands> context={commands}>
+                        <ContextProvider<I18nNamespaces> context={namespaces}>
+                            <ContextProvider<I18nLoading> context={loading}>
+                                { children }
+                            </ContextProvider<I18nLoading>>
+                        </ContextProvider<I18nNamespaces>>
+                    </ContextProvider<I18nCommands>>
+                </ContextProvider<Callback<String>>>
+            </ContextProvider<CurrentLanguage>>
         </ContextProvider<I18n>>
     }
 }
 
-#[hook]
-pub fn use_translation() -> (I18n, Callback<String>) {
-    let i18n = use_context::<I18n>().expect("No I18n context provided");
-    let set_language = use_context::<Callback<String>>().expect("No set_language context found");
-    (i18n, set_language)
+/// Properties for the `I18nOverride` component.
+#[derive(Clone, PartialEq, Properties)]
+pub struct I18nOverrideProps {
+    /// The language code forced for the subtree (e.g., `"fr"`).
+    pub language: AttrValue,
+
+    /// The child components rendered in the overridden language.
+    pub children: Html,
+
+    /// CSS class applied to the wrapping `div`.
+    #[prop_or_default]
+    pub class: AttrValue,
+
+    /// Callback when the language is not supported by the surrounding provider.
+    ///
+    /// The subtree keeps the provider's current language in that case.
+    #[prop_or_default]
+    pub onerror: Callback<String>,
 }
 
-fn get_initial_language(_storage_type: &StorageType, _key: &str) -> Option<Option<String>> {
-    #[cfg(target_arch = "wasm32")]
+/// Forces a different language for a subtree, e.g. an email preview rendered in the
+/// recipient's language while the rest of the UI stays in the user's language.
+///
+/// Must be placed inside an `I18nProvider`, whose translations it reuses. The override is
+/// not persisted, and the subtree is wrapped in a `div` carrying the matching `lang` and
+/// `dir` attributes.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::I18nOverride;
+/// use yew::prelude::*;
+///
+/// #[function_component(EmailPreview)]
+/// pub fn email_preview() -> Html {
+///     html! {
+///         <I18nOverride language="fr">
+///             <p>{ "Rendered in French" }</p>
+///         </I18nOverride>
+///     }
+/// }
+/// ```
+#[function_component(I18nOverride)]
+pub fn i18n_override(props: &I18nOverrideProps) -> Html {
+    let context = use_context::<I18n>().expect("No I18n context provided");
+    let overridden = use_memo((props.language.clone(), context), |(language, context)| {
+        let mut i18n = context.clone();
+        let error = i18n.select_language(language).err();
+        (i18n, error)
+    });
     {
-        let value: Option<String> = match _storage_type {
-            StorageType::LocalStorage => window()
-                .expect("No window object")
-                .local_storage()
-                .expect("Failed to access localStorage")
-                .and_then(|s| s.get_item(_key).ok())
-                .expect("Stored language not found in localStorage"),
-            StorageType::SessionStorage => window()
-                .expect("No window object")
-                .session_storage()
-                .expect("Failed to access sessionStorage")
-                .and_then(|s| s.get_item(_key).ok())
-                .expect("Stored language not found in sessionStorage"),
-        };
-        Some(value)
+        let onerror = props.onerror.clone();
+        use_effect_with(overridden.1.clone(), move |error| {
+            if let Some(err) = error {
+                onerror.emit(err.clone());
+            }
+        });
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        Some(Some("en".to_string()))
+    let i18n = &overridden.0;
+    let language = i18n.get_current_language().to_string();
+    html! {
+        <ContextProvider<I18n> context={i18n.clone()}>
+            <ContextProvider<CurrentLanguage> context={CurrentLanguage(language.clone())}>
+                <div
+                    class={props.class.clone()}
+                    lang={language}
+                    dir={i18n.direction().as_str()}
+                >
+                    { props.children.clone() }
+                </div>
+            </ContextProvider<CurrentLanguage>>
+        </ContextProvider<I18n>>
     }
 }
+
+/// Properties for the `Trans` component.
+#[derive(Clone, PartialEq, Properties)]
+pub struct TransProps {
+    /// The translation key to render (e.g., `"footer.docs"`).
+    pub i18n_key: AttrValue,
+
+    /// Renderers for the tags of the translated string, by tag name.
+    ///
+    /// Each slot receives the rendered inner content of its tag, e.g. `"link"` for
+    /// `"Read the <link>docs</link>"`. Tags without a slot render their content unwrapped.
+    #[prop_or_default]
+    pub slots: HashMap<&'static str, Callback<Html, Html>>,
+
+    /// Named values for the `{name}` placeholders of the translated string.
+    #[prop_or_default]
+    pub values: HashMap<&'static str, AttrValue>,
+}
+
+/// Renders a translation that contains markup, such as `"Read the <link>docs</link>"`.
+///
+/// Tags in the translated string are rendered through the matching `slots`, and everything
+/// else is rendered as text, so translators can move markup around within a sentence
+/// without any raw HTML being injected.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::Trans;
+/// use std::collections::HashMap;
+/// use yew::prelude::*;
+///
+/// #[function_component(Footer)]
+/// pub fn footer() -> Html {
+///     let slots = HashMap::from([(
+///         "link",
+///         Callback::from(|inner: Html| html! { <a href="/docs">{ inner }</a> }),
+///     )]);
+///
+///     html! { <p><Trans i18n_key="footer.docs" {slots} /></p> }
+/// }
+/// ```
+#[function_component(Trans)]
+pub fn trans(props: &TransProps) -> Html {
+    let (i18n, _) = use_translation();
+    let args: Vec<(&str, &dyn Display)> = props
+        .values
+        .iter()
+        .map(|(name, value)| (*name, value as &dyn Display))
+        .collect();
+
+    render_trans(
+        i18n.get_current_language(),
+        &parse(&i18n.t(&props.i18n_key)),
+        &props.slots,
+        &args,
+    )
+}
+
+/// Renders parsed translation nodes, wrapping tags with their slots.
+fn render_trans(
+    language: &str,
+    nodes: &[TransNode],
+    slots: &HashMap<&'static str, Callback<Html, Html>>,
+    args: I18nArgs<'_>,
+) -> Html {
+    nodes
+        .iter()
+        .map(|node| match node {
+            TransNode::Text(text) => Html::from(interpolate(language, text, args)),
+            TransNode::Tag { name, children } => {
+                let inner = render_trans(language, children, slots, args);
+                match slots.get(name.as_str()) {
+                    Some(slot) => slot.emit(inner),
+                    None => inner,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Properties for the `Markdown` component.
+#[cfg(feature = "markdown")]
+#[derive(Clone, PartialEq, Properties)]
+pub struct MarkdownProps {
+    /// The translation key whose value is Markdown (e.g., `"help.body"`).
+    pub i18n_key: AttrValue,
+
+    /// CSS class applied to the wrapping `div`.
+    #[prop_or_default]
+    pub class: AttrValue,
+}
+
+/// Renders a translation value written in Markdown, for long-form content like help panels.
+///
+/// Raw HTML inside the value is escaped and unsafe link URLs are removed before rendering.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::Markdown;
+/// use yew::prelude::*;
+///
+/// #[function_component(Help)]
+/// pub fn help() -> Html {
+///     html! { <Markdown i18n_key="help.body" class="prose" /> }
+/// }
+/// ```
+#[cfg(feature = "markdown")]
+#[function_component(Markdown)]
+pub fn markdown(props: &MarkdownProps) -> Html {
+    let (i18n, _) = use_translation();
+    let content = Html::from_html_unchecked(i18n.t_markdown(&props.i18n_key).into());
+
+    html! { <div class={props.class.clone()}>{ content }</div> }
+}
+
+/// Properties for the `TransHtml` component.
+#[cfg(feature = "html")]
+#[derive(Clone, PartialEq, Properties)]
+pub struct TransHtmlProps {
+    /// The translation key whose value is an HTML snippet (e.g., `"legal.notice"`).
+    pub i18n_key: AttrValue,
+
+    /// CSS class applied to the wrapping `span`.
+    #[prop_or_default]
+    pub class: AttrValue,
+}
+
+/// Renders a translation value containing HTML, after sanitizing it with `I18n::t_html`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::TransHtml;
+/// use yew::prelude::*;
+///
+/// #[function_component(Notice)]
+/// pub fn notice() -> Html {
+///     html! { <p><TransHtml i18n_key="legal.notice" /></p> }
+/// }
+/// ```
+#[cfg(feature = "html")]
+#[function_component(TransHtml)]
+pub fn trans_html(props: &TransHtmlProps) -> Html {
+    let (i18n, _) = use_translation();
+    let content = Html::from_html_unchecked(i18n.t_html(&props.i18n_key).into());
+
+    html! { <span class={props.class.clone()}>{ content }</span> }
+}
+
+/// Properties for the `I18nMeta` component.
+#[derive(Clone, PartialEq, Properties)]
+pub struct I18nMetaProps {
+    /// The translation key of the page title, set as `og:title` (e.g., `"home.title"`).
+    #[prop_or_default]
+    pub title_key: Option<AttrValue>,
+
+    /// The translation key of the page description, set as the `description` and
+    /// `og:description` meta tags (e.g., `"home.description"`).
+    #[prop_or_default]
+    pub description_key: Option<AttrValue>,
+}
+
+/// Keeps the localized meta tags of a page in the document head.
+///
+/// Sets the `description` and OpenGraph tags from translation keys, along with
+/// `og:locale` and `og:locale:alternate`, and updates them when the language changes; see
+/// `seo::meta_tags`. Renders nothing itself. Yew's server-side rendering doesn't reach
+/// the document head, so servers should write `seo::meta_tags` into their HTML template.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::I18nMeta;
+/// use yew::prelude::*;
+///
+/// #[function_component(Home)]
+/// pub fn home() -> Html {
+///     html! {
+///         <>
+///             <I18nMeta title_key="home.title" description_key="home.description" />
+///             <main />
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(I18nMeta)]
+pub fn i18n_meta(props: &I18nMetaProps) -> Html {
+    let (i18n, _) = use_translation();
+    let tags = meta_tags(
+        &i18n,
+        props.title_key.as_deref(),
+        props.description_key.as_deref(),
+    );
+    use_effect_with(tags, |_tags| {
+        #[cfg(target_arch = "wasm32")]
+        sync_meta_tags(_tags);
+    });
+
+    Html::default()
+}
+
+/// Writes meta tags into the document head, updating existing tags of the same name.
+#[cfg(target_arch = "wasm32")]
+fn sync_meta_tags(tags: &[MetaTag]) {
+    const ALTERNATE: &str = "og:locale:alternate";
+
+    let Some(document) = window().and_then(|win| win.document()) else {
+        return;
+    };
+    let Ok(Some(head)) = document.query_selector("head") else {
+        return;
+    };
+    let alternates = format!(r#"meta[property="{}"]"#, ALTERNATE);
+    while let Ok(Some(stale)) = document.query_selector(&alternates) {
+        stale.remove();
+    }
+
+    for tag in tags {
+        let selector = format!(r#"meta[{}="{}"]"#, tag.attribute, tag.name);
+        let existing = match tag.name {
+            ALTERNATE => None,
+            _ => document.query_selector(&selector).ok().flatten(),
+        };
+        let element = match existing {
+            Some(element) => element,
+            None => {
+                let Ok(element) = document.create_element("meta") else {
+                    continue;
+                };
+                let _ = element.set_attribute(tag.attribute, tag.name);
+                let _ = head.append_child(&element);
+                element
+            }
+        };
+        let _ = element.set_attribute("content", &tag.content);
+    }
+}
+
+/// Properties for the `I18nAlternateLinks` component.
+#[derive(Clone, PartialEq, Properties)]
+pub struct I18nAlternateLinksProps {
+    /// The URL of the page with a `{lang}` placeholder for the language code (e.g.,
+    /// `"https://example.com/{lang}/about"`).
+    pub pattern: AttrValue,
+
+    /// The language whose URL also serves as the `x-default` link.
+    #[prop_or_default]
+    pub x_default: Option<AttrValue>,
+}
+
+/// Keeps `hreflang` alternate links to every selectable language of a page in the
+/// document head.
+///
+/// Renders nothing itself; see `seo::alternate_links`. Like `I18nMeta`, servers rendering
+/// with Yew should write the links into their HTML template.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::I18nAlternateLinks;
+/// use yew::prelude::*;
+///
+/// #[function_component(About)]
+/// pub fn about() -> Html {
+///     html! {
+///         <I18nAlternateLinks pattern="https://example.com/{lang}/about" x_default="en" />
+///     }
+/// }
+/// ```
+#[function_component(I18nAlternateLinks)]
+pub fn i18n_alternate_links(props: &I18nAlternateLinksProps) -> Html {
+    let (i18n, _) = use_translation();
+    let links = alternate_links(
+        &i18n.languages(),
+        &props.pattern,
+        props.x_default.as_deref(),
+    );
+    use_effect_with(links, |_links| {
+        #[cfg(target_arch = "wasm32")]
+        sync_alternate_links(_links);
+    });
+
+    Html::default()
+}
+
+/// Replaces the `hreflang` alternate links of the document head.
+#[cfg(target_arch = "wasm32")]
+fn sync_alternate_links(links: &[AlternateLink]) {
+    let Some(document) = window().and_then(|win| win.document()) else {
+        return;
+    };
+    let Ok(Some(head)) = document.query_selector("head") else {
+        return;
+    };
+    while let Ok(Some(stale)) = document.query_selector(r#"link[rel="alternate"][hreflang]"#) {
+        stale.remove();
+    }
+
+    for link in links {
+        let Ok(element) = document.create_element("link") else {
+            continue;
+        };
+        let _ = element.set_attribute("rel", "alternate");
+        let _ = element.set_attribute("hreflang", &link.hreflang);
+        let _ = element.set_attribute("href", &link.href);
+        let _ = head.append_child(&element);
+    }
+}
+
+/// Properties for the `I18nEditor` component.
+#[cfg(feature = "editor")]
+#[derive(Clone, PartialEq, Properties)]
+pub struct I18nEditorProps {
+    /// Whether the editor is shown, e.g. `cfg!(debug_assertions)` to keep it out of
+    /// release builds. The children are rendered as is otherwise.
+    #[prop_or_default]
+    pub enabled: bool,
+
+    /// The part of the app whose strings can be picked by Alt-clicking them.
+    pub children: Html,
+
+    /// CSS class applied to the editor panel.
+    #[prop_or_default]
+    pub class: AttrValue,
+}
+
+/// A development tool to review and edit translations in place.
+///
+/// Alt-clicking a string inside the children, or searching by text or key, lists the
+/// matching keys of the current language with their translations. Edits show up live,
+/// as the topmost translation layer of the surrounding provider, and the panel exports
+/// them as JSON for the locale files. Must be placed inside an `I18nProvider`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::{I18nEditor, use_i18n};
+/// use yew::prelude::*;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let i18n = use_i18n();
+///     html! {
+///         <I18nEditor enabled={cfg!(debug_assertions)}>
+///             <h1>{ i18n.t("greeting") }</h1>
+///         </I18nEditor>
+///     }
+/// }
+/// ```
+#[cfg(feature = "editor")]
+#[function_component(I18nEditor)]
+pub fn i18n_editor(props: &I18nEditorProps) -> Html {
+    let i18n = use_i18n();
+    let query = use_state(String::new);
+    let edits = use_state(TranslationEdits::default);
+
+    if !props.enabled {
+        return props.children.clone();
+    }
+
+    let onpick = {
+        let query = query.clone();
+        Callback::from(move |event: MouseEvent| {
+            if !event.alt_key() {
+                return;
+            }
+            event.prevent_default();
+            if let Some(text) = event
+                .target_dyn_into::<web_sys::Element>()
+                .and_then(|element| element.text_content())
+            {
+                query.set(text.trim().to_string());
+            }
+        })
+    };
+    let onsearch = {
+        let query = query.clone();
+        Callback::from(move |event: InputEvent| {
+            if let Some(input) = event.target_dyn_into::<web_sys::HtmlInputElement>() {
+                query.set(input.value());
+            }
+        })
+    };
+
+    let language = i18n.get_current_language().to_string();
+    let keys = if query.trim().is_empty() {
+        Vec::new()
+    } else {
+        i18n.search_keys(&query)
+    };
+    let entries = keys.into_iter().map(|key| {
+        let oninput = {
+            let i18n = i18n.clone();
+            let edits = edits.clone();
+            let language = language.clone();
+            let key = key.clone();
+            Callback::from(move |event: InputEvent| {
+                let Some(input) = event.target_dyn_into::<web_sys::HtmlTextAreaElement>() else {
+                    return;
+                };
+                let mut next = (*edits).clone();
+                next.set(&language, &key, &input.value());
+                let bundles = next.export_all();
+                let _ = i18n.set_layer(
+                    EDITOR_LAYER,
+                    bundles
+                        .iter()
+                        .map(|(language, json)| (language.as_str(), json.as_str()))
+                        .collect(),
+                );
+                edits.set(next);
+            })
+        };
+        html! {
+            <li>
+                <code>{ key.clone() }</code>
+                <textarea value={i18n.t(&key)} {oninput} />
+            </li>
+        }
+    });
+
+    html! {
+        <>
+            <div onclick={onpick}>{ props.children.clone() }</div>
+            <aside class={props.class.clone()} style={EDITOR_STYLE}>
+                <input
+                    type="search"
+                    placeholder="Search text or keys, or Alt-click a string"
+                    value={(*query).clone()}
+                    oninput={onsearch}
+                />
+                <ul>{ for entries }</ul>
+                if !edits.is_empty() {
+                    <pre>{ edits.export(&language) }</pre>
+                }
+            </aside>
+        </>
+    }
+}
+
+/// A handle to the i18n state of the nearest `I18nProvider`, returned by `use_i18n`.
+///
+/// Dereferences to the `I18n` instance. The translations are shared between the provider
+/// and every handle, so passing a handle around copies pointers, not the catalog.
+#[derive(Clone, PartialEq)]
+pub struct UseI18nHandle {
+    i18n: I18n,
+    set_language: Callback<String>,
+    commands: Option<I18nCommands>,
+}
+
+impl UseI18nHandle {
+    /// Switches the language of the provider, like the callback of `use_translation`.
+    pub fn set_language(&self, language: impl Into<String>) {
+        self.set_language.emit(language.into());
+    }
+
+    /// Returns a callback switching the language, e.g. for a language picker.
+    pub fn set_language_callback(&self) -> Callback<String> {
+        self.set_language.clone()
+    }
+
+    /// Loads a language ahead of time without switching to it.
+    ///
+    /// Fetches the remote bundle of the language or parses it again if it was unloaded,
+    /// so a later `set_language` is instant, e.g. when the user hovers a language menu.
+    pub fn preload_language(&self, language: impl Into<String>) {
+        if let Some(commands) = &self.commands {
+            commands.preload_language.emit(language.into());
+        }
+    }
+
+    /// Registers a language at runtime, or replaces the full bundle of an existing one.
+    ///
+    /// See `I18n::add_language`. The bundle is parsed right away, and the provider
+    /// re-renders with the language once it is added.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the bundle was handed to the provider.
+    /// - `Err(String)` if the JSON is invalid.
+    pub fn add_language(&self, language: impl Into<String>, json: &str) -> Result<(), String> {
+        let language = language.into();
+        let json = parse_bundle(&language, json)?;
+        if let Some(commands) = &self.commands {
+            commands.add_language.emit((language, json));
+        }
+        Ok(())
+    }
+
+    /// Removes a language at runtime; see `I18n::remove_language`.
+    ///
+    /// Removing the current language switches to the default language like
+    /// `set_language`, so the provider's `onchange` callback is emitted.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language is being removed.
+    /// - `Err(String)` if the language can't be removed.
+    pub fn remove_language(&self, language: impl Into<String>) -> Result<(), String> {
+        let language = language.into();
+        self.i18n.clone().remove_language(&language)?;
+        if let Some(commands) = &self.commands {
+            commands.remove_language.emit(language);
+        }
+        Ok(())
+    }
+
+    /// Adds or replaces a named translation layer; see `I18n::set_layer`.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the layer was handed to the provider.
+    /// - `Err(String)` if the JSON of a language is invalid.
+    pub fn set_layer(&self, name: &str, translations: HashMap<&str, &str>) -> Result<(), String> {
+        for (language, json) in &translations {
+            parse_bundle(language, json)?;
+        }
+        if let Some(commands) = &self.commands {
+            let translations = translations
+                .into_iter()
+                .map(|(language, json)| (language.to_string(), json.to_string()))
+                .collect();
+            commands.set_layer.emit((name.to_string(), translations));
+        }
+        Ok(())
+    }
+}
+
+impl Deref for UseI18nHandle {
+    type Target = I18n;
+
+    fn deref(&self) -> &I18n {
+        &self.i18n
+    }
+}
+
+/// The current language code, provided on its own so `use_language` consumers only
+/// re-render when the language changes.
+#[derive(Clone, PartialEq)]
+struct CurrentLanguage(String);
+
+/// The callbacks behind the methods of `UseI18nHandle` that update the provider.
+#[derive(Clone, PartialEq)]
+struct I18nCommands {
+    preload_language: Callback<String>,
+    add_language: Callback<(String, Value)>,
+    remove_language: Callback<String>,
+    set_layer: Callback<(String, HashMap<String, String>)>,
+}
+
+/// Retrieves a handle to the i18n context.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::use_i18n;
+/// use yew::prelude::*;
+///
+/// #[function_component(Greeting)]
+/// pub fn greeting() -> Html {
+///     let i18n = use_i18n();
+///     let onclick = {
+///         let i18n = i18n.clone();
+///         Callback::from(move |_| i18n.set_language("fr"))
+///     };
+///
+///     html! { <button {onclick}>{ i18n.t("greeting") }</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_i18n() -> UseI18nHandle {
+    let (i18n, set_language) = use_translation();
+    let commands = use_context::<I18nCommands>();
+    UseI18nHandle {
+        i18n,
+        set_language,
+        commands,
+    }
+}
+
+/// Retrieves the current language code.
+///
+/// Unlike `use_translation` and `use_i18n`, the component does not re-render when
+/// bundles or namespaces finish loading, only when the language changes, which suits
+/// language pickers and `lang` attributes.
+#[hook]
+pub fn use_language() -> String {
+    use_context::<CurrentLanguage>()
+        .map(|language| language.0)
+        .expect("No I18n context provided")
+}
+
+/// Keeps `document.title` set to a translation, updated when the language changes.
+///
+/// # Arguments
+/// - `key`: The translation key of the title (e.g., `"pages.settings.title"`).
+/// - `args`: Named values for the placeholders of the title; see `I18n::t_with`.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::use_document_title;
+/// use yew::prelude::*;
+///
+/// #[function_component(Inbox)]
+/// pub fn inbox() -> Html {
+///     let unread = 3;
+///     use_document_title("inbox.title", &[("count", &unread)]);
+///
+///     html! { <main /> }
+/// }
+/// ```
+#[hook]
+pub fn use_document_title(key: &str, args: I18nArgs<'_>) {
+    let (i18n, _) = use_translation();
+    let title = i18n.t_with(key, args);
+    use_effect_with(title, |_title| {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(document) = window().and_then(|win| win.document()) {
+            document.set_title(_title);
+        }
+    });
+}
+
+#[hook]
+pub fn use_translation() -> (I18n, Callback<String>) {
+    let i18n = use_context::<I18n>().expect("No I18n context provided");
+    let set_language = use_context::<Callback<String>>().expect("No set_language context found");
+    (i18n, set_language)
+}
+
+/// Retrieves the i18n context of a named `I18nProvider`.
+///
+/// # Arguments
+/// - `name`: The `name` prop of the provider (e.g., `"cms"`).
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::use_translation_named;
+///
+/// #[function_component(Article)]
+/// pub fn article() -> Html {
+///     let (cms, _set_language) = use_translation_named("cms");
+///
+///     html! { <h1>{ cms.t("article.title") }</h1> }
+/// }
+/// ```
+#[hook]
+pub fn use_translation_named(name: &str) -> (I18n, Callback<String>) {
+    use_context::<I18nRegistry>()
+        .and_then(|registry| registry.get(name))
+        .unwrap_or_else(|| panic!("No I18n context named '{}' provided", name))
+}
+
+/// Retrieves the i18n context scoped to a namespace.
+///
+/// The returned `I18nNamespace` resolves `t("title")` as `"<namespace>.title"`, which keeps
+/// components of a feature area from repeating the same key prefix.
+///
+/// If the provider has a lazy loader registered for the namespace, the first use for the
+/// current language triggers the load. Use `use_namespace_loading` to render a placeholder
+/// while it is in flight.
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::use_translation_ns;
+///
+/// #[function_component(CheckoutTitle)]
+/// pub fn checkout_title() -> Html {
+///     let (checkout, _set_language) = use_translation_ns("checkout");
+///
+///     html! { <h1>{ checkout.t("title") }</h1> }
+/// }
+/// ```
+#[hook]
+pub fn use_translation_ns(namespace: &str) -> (I18nNamespace, Callback<String>) {
+    let (i18n, set_language) = use_translation();
+    let namespaces = use_context::<I18nNamespaces>();
+
+    {
+        let namespace = namespace.to_string();
+        let language = i18n.get_current_language().to_string();
+        use_effect_with((namespace, language), move |(namespace, _)| {
+            if let Some(namespaces) = namespaces {
+                namespaces.load(namespace);
+            }
+        });
+    }
+
+    (I18nNamespace::new(i18n, namespace), set_language)
+}
+
+/// Checks whether the remote bundle of the current language is still being fetched.
+#[hook]
+pub fn use_i18n_loading() -> bool {
+    use_context::<I18nLoading>()
+        .map(|state| state.loading)
+        .unwrap_or(false)
+}
+
+/// Retrieves the i18n instance and language setter, suspending while translations load.
+///
+/// Suspends the component until the remote bundle of the current language is loaded, so
+/// the nearest `Suspense` renders its fallback instead of untranslated keys.
+///
+/// # Example
+/// ```rust
+/// use i18nrs::yew::use_translation_suspense;
+/// use yew::prelude::*;
+///
+/// #[function_component(Greeting)]
+/// pub fn greeting() -> HtmlResult {
+///     let (i18n, _) = use_translation_suspense()?;
+///     Ok(html! { <h1>{ i18n.t("greeting") }</h1> })
+/// }
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Suspense fallback={html! { <p>{ "Loading…" }</p> }}>
+///             <Greeting />
+///         </Suspense>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_translation_suspense() -> SuspensionResult<(I18n, Callback<String>)> {
+    let translation = use_translation();
+    match use_context::<I18nLoading>().and_then(|state| state.suspension) {
+        Some(suspension) => Err(suspension),
+        None => Ok(translation),
+    }
+}
+
+/// Checks whether the translations of the current language are available.
+///
+/// Returns `false` while the remote bundle of the current language is fetched without a
+/// cached copy, and `true` outside of an `I18nProvider`.
+#[hook]
+pub fn use_i18n_ready() -> bool {
+    use_context::<I18nLoading>()
+        .map(|state| state.is_ready)
+        .unwrap_or(true)
+}
+
+/// Checks whether a lazily loaded namespace is still loading for the current language.
+#[hook]
+pub fn use_namespace_loading(namespace: &str) -> bool {
+    use_context::<I18nNamespaces>()
+        .map(|namespaces| namespaces.is_loading(namespace))
+        .unwrap_or(false)
+}